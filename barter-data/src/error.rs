@@ -14,6 +14,12 @@ pub enum DataError {
         sub_kind: SubKind,
     },
 
+    #[error("no running connection for exchange: {exchange}, kind: {sub_kind}")]
+    Unconnected {
+        exchange: ExchangeId,
+        sub_kind: SubKind,
+    },
+
     #[error(
         "\
         InvalidSequence: first_update_id {first_update_id} does not follow on from the \
@@ -35,6 +41,16 @@ impl DataError {
             _ => false,
         }
     }
+
+    /// Determine if an error was caused by an exchange message that could not be deserialised.
+    pub fn is_parse_error(&self) -> bool {
+        matches!(
+            self,
+            DataError::Socket(
+                SocketError::Deserialise { .. } | SocketError::DeserialiseBinary { .. }
+            )
+        )
+    }
 }
 
 #[cfg(test)]
@@ -69,4 +85,41 @@ mod tests {
             assert_eq!(actual, test.expected, "TC{} failed", index);
         }
     }
+
+    #[test]
+    fn test_data_error_is_parse_error() {
+        struct TestCase {
+            input: DataError,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: is parse error w/ SocketError::Deserialise
+                input: DataError::Socket(SocketError::Deserialise {
+                    error: serde_json::from_str::<u64>("invalid").unwrap_err(),
+                    payload: "invalid".to_string(),
+                }),
+                expected: true,
+            },
+            TestCase {
+                // TC1: is not parse error w/ SocketError::Sink
+                input: DataError::Socket(SocketError::Sink),
+                expected: false,
+            },
+            TestCase {
+                // TC2: is not parse error w/ DataError::InvalidSequence
+                input: DataError::InvalidSequence {
+                    prev_last_update_id: 0,
+                    first_update_id: 0,
+                },
+                expected: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.input.is_parse_error();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
 }
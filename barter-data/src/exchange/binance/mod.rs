@@ -69,35 +69,59 @@ where
     }
 
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        let stream_names = exchange_subs
-            .into_iter()
-            .map(|sub| {
-                // Note:
-                // Market must be lowercase when subscribing, but lowercase in general since
-                // Binance sends message with uppercase MARKET (eg/ BTCUSDT).
-                format!(
-                    "{}{}",
-                    sub.market.as_ref().to_lowercase(),
-                    sub.channel.as_ref()
-                )
-            })
-            .collect::<Vec<String>>();
-
         vec![WsMessage::Text(
             serde_json::json!({
                 "method": "SUBSCRIBE",
-                "params": stream_names,
+                "params": stream_names(exchange_subs),
                 "id": 1
             })
             .to_string(),
         )]
     }
 
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        vec![WsMessage::Text(
+            serde_json::json!({
+                "method": "UNSUBSCRIBE",
+                "params": stream_names(exchange_subs),
+                "id": 2
+            })
+            .to_string(),
+        )]
+    }
+
+    /// Binance spot & USD-M futures connections can each listen to a maximum of 1024 streams.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
+    fn subscription_limit() -> Option<usize> {
+        Some(1024)
+    }
+
     fn expected_responses<InstrumentId>(_: &Map<InstrumentId>) -> usize {
         1
     }
 }
 
+/// Translate a collection of [`ExchangeSub`]s into Binance stream names (eg/ "btcusdt@trade").
+fn stream_names(exchange_subs: Vec<ExchangeSub<BinanceChannel, BinanceMarket>>) -> Vec<String> {
+    exchange_subs
+        .into_iter()
+        .map(|sub| {
+            // Note:
+            // Market must be lowercase when subscribing, but lowercase in general since
+            // Binance sends message with uppercase MARKET (eg/ BTCUSDT).
+            format!(
+                "{}{}",
+                sub.market.as_ref().to_lowercase(),
+                sub.channel.as_ref()
+            )
+        })
+        .collect()
+}
+
 impl<Instrument, Server> StreamSelector<Instrument, PublicTrades> for Binance<Server>
 where
     Instrument: InstrumentData,
@@ -0,0 +1,236 @@
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{binance::market::binance_market, ExchangeId},
+    subscription::candle::Candle,
+};
+use barter_integration::{
+    de::{datetime_utc_from_epoch_duration, extract_next},
+    error::SocketError,
+    model::{instrument::Instrument, Exchange},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::time::Duration;
+
+/// [`BinanceSpot`](super::BinanceSpot) HTTP kline url, yielding historical candles of a symbol.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-data>
+pub const HTTP_KLINES_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/klines";
+
+/// Maximum number of klines [`BinanceSpot`](super::BinanceSpot) yields per HTTP request.
+pub const HTTP_KLINES_LIMIT_MAX_BINANCE_SPOT: u16 = 1000;
+
+/// Fetches up to `limit` of the most recently closed [`BinanceSpot`](super::BinanceSpot)
+/// [`Candle`]s of the provided [`Instrument`] & interval (eg/ "1m", "1h") via HTTP, ordered
+/// oldest first. Used to backfill strategy state (eg/ indicators) before trading live.
+///
+/// The currently open kline is excluded, since it's values are incomplete.
+pub async fn fetch_candles(
+    instrument: &Instrument,
+    interval: &str,
+    limit: u16,
+) -> Result<Vec<MarketEvent<Instrument, Candle>>, DataError> {
+    let symbol = binance_market(&instrument.base, &instrument.quote);
+    let url = format!(
+        "{HTTP_KLINES_URL_BINANCE_SPOT}?symbol={}&interval={interval}&limit={}",
+        symbol.as_ref(),
+        limit.min(HTTP_KLINES_LIMIT_MAX_BINANCE_SPOT),
+    );
+
+    let klines = reqwest::get(url)
+        .await
+        .map_err(SocketError::Http)?
+        .json::<Vec<BinanceKline>>()
+        .await
+        .map_err(SocketError::Http)?;
+
+    Ok(closed_candles(instrument, klines, Utc::now()))
+}
+
+/// Maps every [`BinanceKline`] closed by the provided time into a [`Candle`] [`MarketEvent`].
+fn closed_candles(
+    instrument: &Instrument,
+    klines: Vec<BinanceKline>,
+    now: DateTime<Utc>,
+) -> Vec<MarketEvent<Instrument, Candle>> {
+    klines
+        .into_iter()
+        .filter(|kline| kline.close_time <= now)
+        .map(|kline| MarketEvent {
+            exchange_time: kline.close_time,
+            received_time: now,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: instrument.clone(),
+            kind: Candle::from(kline),
+        })
+        .collect()
+}
+
+/// [`BinanceSpot`](super::BinanceSpot) HTTP kline.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-data>
+/// ```json
+/// [
+///     1499040000000,
+///     "0.01634790",
+///     "0.80000000",
+///     "0.01575800",
+///     "0.01577100",
+///     "148976.11427815",
+///     1499644799999,
+///     "2434.19055334",
+///     308,
+///     "1756.87402397",
+///     "28.46694368",
+///     "0"
+/// ]
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BinanceKline {
+    pub open_time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub close_time: DateTime<Utc>,
+    pub trade_count: u64,
+}
+
+impl From<BinanceKline> for Candle {
+    fn from(kline: BinanceKline) -> Self {
+        Self {
+            close_time: kline.close_time,
+            open: kline.open,
+            high: kline.high,
+            low: kline.low,
+            close: kline.close,
+            volume: kline.volume,
+            trade_count: kline.trade_count,
+        }
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for BinanceKline {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BinanceKline;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BinanceKline struct from the Binance HTTP API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // BinanceKline Sequence Format:
+                // [openTime, open, high, low, close, volume, closeTime, quoteVolume, trades, ...]
+                // <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-data>
+
+                // Extract u64 epoch milliseconds & map to DateTime<Utc>
+                let open_time = extract_next::<SeqAccessor, u64>(&mut seq, "openTime")
+                    .map(|time| datetime_utc_from_epoch_duration(Duration::from_millis(time)))?;
+
+                // Extract String OHLCV values & parse to f64
+                let mut next_f64 = |name| {
+                    extract_next::<SeqAccessor, String>(&mut seq, name)?
+                        .parse::<f64>()
+                        .map_err(serde::de::Error::custom)
+                };
+                let open = next_f64("open")?;
+                let high = next_f64("high")?;
+                let low = next_f64("low")?;
+                let close = next_f64("close")?;
+                let volume = next_f64("volume")?;
+
+                // Extract u64 epoch milliseconds & map to DateTime<Utc>
+                let close_time = extract_next::<SeqAccessor, u64>(&mut seq, "closeTime")
+                    .map(|time| datetime_utc_from_epoch_duration(Duration::from_millis(time)))?;
+
+                // Extract quote asset volume & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "quoteVolume")?;
+
+                // Extract number of trades
+                let trade_count = extract_next(&mut seq, "trades")?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(BinanceKline {
+                    open_time,
+                    open,
+                    high,
+                    low,
+                    close,
+                    volume,
+                    close_time,
+                    trade_count,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the BinanceKline
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_binance_klines() {
+        let input = r#"
+        [
+            [
+                1499040000000, "0.01634790", "0.80000000", "0.01575800", "0.01577100",
+                "148976.11427815", 1499644799999, "2434.19055334", 308, "1756.87402397",
+                "28.46694368", "0"
+            ],
+            [
+                1499644800000, "0.01577100", "0.01600000", "0.01500000", "0.01550000",
+                "1000.00000000", 1500249599999, "15.50000000", 10, "500.00000000",
+                "7.75000000", "0"
+            ]
+        ]
+        "#;
+
+        let klines = serde_json::from_str::<Vec<BinanceKline>>(input).unwrap();
+        let close_time = datetime_utc_from_epoch_duration(Duration::from_millis(1499644799999));
+
+        assert_eq!(
+            klines[0],
+            BinanceKline {
+                open_time: datetime_utc_from_epoch_duration(Duration::from_millis(1499040000000)),
+                open: 0.0163479,
+                high: 0.8,
+                low: 0.015758,
+                close: 0.015771,
+                volume: 148976.11427815,
+                close_time,
+                trade_count: 308,
+            }
+        );
+
+        // Kline still open at the time of the request is excluded
+        let instrument = Instrument::from(("eth", "btc", InstrumentKind::Spot));
+        let candles = closed_candles(&instrument, klines, close_time);
+
+        assert_eq!(candles.len(), 1);
+        assert_eq!(candles[0].exchange_time, close_time);
+        assert_eq!(candles[0].kind.close, 0.015771);
+    }
+}
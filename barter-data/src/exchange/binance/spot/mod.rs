@@ -12,6 +12,14 @@ use barter_integration::model::instrument::Instrument;
 /// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
 pub mod l2;
 
+/// [`InstrumentSpec`](barter_integration::model::instrument::spec::InstrumentSpec) trading rules
+/// fetched from the exchange information HTTP endpoint.
+pub mod spec;
+
+/// Historical [`Candle`](crate::subscription::candle::Candle)s fetched from the kline HTTP
+/// endpoint.
+pub mod kline;
+
 /// [`BinanceSpot`] WebSocket server base url.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
@@ -0,0 +1,227 @@
+use crate::{error::DataError, exchange::ExchangeId};
+use barter_integration::{
+    error::SocketError,
+    model::{
+        instrument::{
+            kind::InstrumentKind,
+            spec::{InstrumentSpec, MarketSpec},
+            Instrument,
+        },
+        Exchange,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceSpot`](super::BinanceSpot) HTTP exchange information url, detailing the trading rules
+/// of every symbol.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/exchangeInfo";
+
+/// Fetches the [`InstrumentSpec`] of every actively trading [`BinanceSpot`](super::BinanceSpot)
+/// symbol via HTTP.
+pub async fn fetch_instrument_specs() -> Result<Vec<MarketSpec>, DataError> {
+    let exchange_info = reqwest::get(HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT)
+        .await
+        .map_err(SocketError::Http)?
+        .json::<BinanceExchangeInfo>()
+        .await
+        .map_err(SocketError::Http)?;
+
+    Ok(exchange_info.market_specs())
+}
+
+/// [`BinanceSpot`](super::BinanceSpot) HTTP exchange information response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+/// ```json
+/// {
+///     "timezone": "UTC",
+///     "serverTime": 1565246363776,
+///     "symbols": [
+///         {
+///             "symbol": "ETHBTC",
+///             "status": "TRADING",
+///             "baseAsset": "ETH",
+///             "quoteAsset": "BTC",
+///             "filters": [
+///                 { "filterType": "PRICE_FILTER", "minPrice": "0.00000100", "maxPrice": "922327.00000000", "tickSize": "0.00000100" },
+///                 { "filterType": "LOT_SIZE", "minQty": "0.00010000", "maxQty": "100000.00000000", "stepSize": "0.00010000" },
+///                 { "filterType": "NOTIONAL", "minNotional": "0.00010000", "applyMinToMarket": true }
+///             ]
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceExchangeInfo {
+    pub symbols: Vec<BinanceSymbolInfo>,
+}
+
+impl BinanceExchangeInfo {
+    /// Returns the [`MarketSpec`] of every actively trading symbol.
+    pub fn market_specs(&self) -> Vec<MarketSpec> {
+        let exchange = Exchange::from(ExchangeId::BinanceSpot);
+
+        self.symbols
+            .iter()
+            .filter(|symbol| symbol.status == BinanceSymbolInfo::STATUS_TRADING)
+            .map(|symbol| MarketSpec {
+                exchange: exchange.clone(),
+                instrument: Instrument::from((
+                    &symbol.base_asset,
+                    &symbol.quote_asset,
+                    InstrumentKind::Spot,
+                )),
+                spec: InstrumentSpec::from(symbol),
+            })
+            .collect()
+    }
+}
+
+/// [`BinanceSpot`](super::BinanceSpot) trading rules of a symbol. See [`BinanceExchangeInfo`]
+/// for the raw payload example.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceSymbolInfo {
+    pub status: String,
+    #[serde(rename = "baseAsset")]
+    pub base_asset: String,
+    #[serde(rename = "quoteAsset")]
+    pub quote_asset: String,
+    pub filters: Vec<BinanceSymbolFilter>,
+}
+
+impl BinanceSymbolInfo {
+    pub const STATUS_TRADING: &'static str = "TRADING";
+}
+
+impl From<&BinanceSymbolInfo> for InstrumentSpec {
+    fn from(symbol: &BinanceSymbolInfo) -> Self {
+        symbol
+            .filters
+            .iter()
+            .fold(InstrumentSpec::default(), |spec, filter| match *filter {
+                BinanceSymbolFilter::Price { tick_size } => InstrumentSpec { tick_size, ..spec },
+                BinanceSymbolFilter::LotSize {
+                    min_quantity,
+                    step_size,
+                } => InstrumentSpec {
+                    min_quantity,
+                    step_size,
+                    ..spec
+                },
+                BinanceSymbolFilter::Notional { min_notional }
+                | BinanceSymbolFilter::MinNotional { min_notional } => InstrumentSpec {
+                    min_notional,
+                    ..spec
+                },
+                BinanceSymbolFilter::Other => spec,
+            })
+    }
+}
+
+/// [`BinanceSpot`](super::BinanceSpot) symbol trading rule filter. Filters not relevant to an
+/// [`InstrumentSpec`] are ignored.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#filters>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "filterType")]
+pub enum BinanceSymbolFilter {
+    #[serde(rename = "PRICE_FILTER")]
+    Price {
+        #[serde(
+            rename = "tickSize",
+            deserialize_with = "barter_integration::de::de_str"
+        )]
+        tick_size: f64,
+    },
+    #[serde(rename = "LOT_SIZE")]
+    LotSize {
+        #[serde(rename = "minQty", deserialize_with = "barter_integration::de::de_str")]
+        min_quantity: f64,
+        #[serde(
+            rename = "stepSize",
+            deserialize_with = "barter_integration::de::de_str"
+        )]
+        step_size: f64,
+    },
+    #[serde(rename = "NOTIONAL")]
+    Notional {
+        #[serde(
+            rename = "minNotional",
+            deserialize_with = "barter_integration::de::de_str"
+        )]
+        min_notional: f64,
+    },
+    #[serde(rename = "MIN_NOTIONAL")]
+    MinNotional {
+        #[serde(
+            rename = "minNotional",
+            deserialize_with = "barter_integration::de::de_str"
+        )]
+        min_notional: f64,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_binance_exchange_info() {
+            let input = r#"
+            {
+                "timezone": "UTC",
+                "serverTime": 1565246363776,
+                "symbols": [
+                    {
+                        "symbol": "ETHBTC",
+                        "status": "TRADING",
+                        "baseAsset": "ETH",
+                        "quoteAsset": "BTC",
+                        "filters": [
+                            { "filterType": "PRICE_FILTER", "minPrice": "0.00000100", "maxPrice": "922327.00000000", "tickSize": "0.00000100" },
+                            { "filterType": "LOT_SIZE", "minQty": "0.00010000", "maxQty": "100000.00000000", "stepSize": "0.00010000" },
+                            { "filterType": "ICEBERG_PARTS", "limit": 10 },
+                            { "filterType": "NOTIONAL", "minNotional": "0.00010000", "applyMinToMarket": true }
+                        ]
+                    },
+                    {
+                        "symbol": "BNBBTC",
+                        "status": "BREAK",
+                        "baseAsset": "BNB",
+                        "quoteAsset": "BTC",
+                        "filters": []
+                    }
+                ]
+            }
+            "#;
+
+            let exchange_info = serde_json::from_str::<BinanceExchangeInfo>(input).unwrap();
+
+            assert_eq!(
+                exchange_info.symbols[0].filters[2],
+                BinanceSymbolFilter::Other
+            );
+            assert_eq!(
+                exchange_info.market_specs(),
+                vec![MarketSpec {
+                    exchange: Exchange::from(ExchangeId::BinanceSpot),
+                    instrument: Instrument::from(("eth", "btc", InstrumentKind::Spot)),
+                    spec: InstrumentSpec {
+                        tick_size: 0.000001,
+                        step_size: 0.0001,
+                        min_quantity: 0.0001,
+                        min_notional: 0.0001,
+                    },
+                }]
+            );
+        }
+    }
+}
@@ -98,6 +98,13 @@ impl Connector for Bitfinex {
             })
             .collect()
     }
+
+    /// Bitfinex connections are limited to 25 public channel subscriptions.
+    ///
+    /// See docs: <https://docs.bitfinex.com/docs/ws-general>
+    fn subscription_limit() -> Option<usize> {
+        Some(25)
+    }
 }
 
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for Bitfinex
@@ -71,9 +71,33 @@ impl Connector for Bitmex {
         )]
     }
 
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        let stream_names = exchange_subs
+            .into_iter()
+            .map(|sub| format!("{}:{}", sub.channel.as_ref(), sub.market.as_ref(),))
+            .collect::<Vec<String>>();
+
+        vec![WsMessage::Text(
+            serde_json::json!({
+                "op": "unsubscribe",
+                "args": stream_names
+            })
+            .to_string(),
+        )]
+    }
+
     fn expected_responses<InstrumentId>(_: &Map<InstrumentId>) -> usize {
         1
     }
+
+    /// BitMEX does not document a maximum number of topics per connection, so no limit is applied.
+    ///
+    /// See docs: <https://www.bitmex.com/app/wsAPI>
+    fn subscription_limit() -> Option<usize> {
+        None
+    }
 }
 
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for Bitmex
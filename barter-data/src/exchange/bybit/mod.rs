@@ -102,9 +102,39 @@ where
         )]
     }
 
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        let stream_names = exchange_subs
+            .into_iter()
+            .map(|sub| format!("{}.{}", sub.channel.as_ref(), sub.market.as_ref(),))
+            .collect::<Vec<String>>();
+
+        vec![WsMessage::Text(
+            serde_json::json!({
+                "op": "unsubscribe",
+                "args": stream_names
+            })
+            .to_string(),
+        )]
+    }
+
     fn expected_responses<InstrumentId>(_: &Map<InstrumentId>) -> usize {
         1
     }
+
+    /// Bybit spot subscribe requests are limited to 10 args, and every
+    /// [`Subscription`](crate::subscription::Subscription) is sent in a single request.
+    /// Derivatives connections are limited to 21,000 characters of args, which 500 topics
+    /// (each ~40 characters) keeps well within.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
+    fn subscription_limit() -> Option<usize> {
+        match Server::ID {
+            ExchangeId::BybitSpot => Some(10),
+            _ => Some(500),
+        }
+    }
 }
 
 impl<Instrument, Server> StreamSelector<Instrument, PublicTrades> for Bybit<Server>
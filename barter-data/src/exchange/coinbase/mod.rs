@@ -70,6 +70,32 @@ impl Connector for Coinbase {
             })
             .collect()
     }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        exchange_subs
+            .into_iter()
+            .map(|ExchangeSub { channel, market }| {
+                WsMessage::Text(
+                    json!({
+                        "type": "unsubscribe",
+                        "product_ids": [market.as_ref()],
+                        "channels": [channel.as_ref()],
+                    })
+                    .to_string(),
+                )
+            })
+            .collect()
+    }
+
+    /// Coinbase does not document a maximum number of channel & product subscriptions per
+    /// connection, so no limit is applied.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-overview>
+    fn subscription_limit() -> Option<usize> {
+        None
+    }
 }
 
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for Coinbase
@@ -87,6 +87,33 @@ where
             })
             .collect()
     }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        exchange_subs
+            .into_iter()
+            .map(|ExchangeSub { channel, market }| {
+                WsMessage::Text(
+                    json!({
+                        "time": chrono::Utc::now().timestamp_millis(),
+                        "channel": channel.as_ref(),
+                        "event": "unsubscribe",
+                        "payload": [market.as_ref()]
+                    })
+                    .to_string(),
+                )
+            })
+            .collect()
+    }
+
+    /// Gate.io does not document a maximum number of channel subscriptions per connection, so no
+    /// limit is applied.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/>
+    fn subscription_limit() -> Option<usize> {
+        None
+    }
 }
 
 impl<'de, Server> serde::Deserialize<'de> for Gateio<Server>
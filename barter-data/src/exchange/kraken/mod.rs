@@ -78,6 +78,34 @@ impl Connector for Kraken {
             })
             .collect()
     }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        exchange_subs
+            .into_iter()
+            .map(|ExchangeSub { channel, market }| {
+                WsMessage::Text(
+                    json!({
+                        "event": "unsubscribe",
+                        "pair": [market.as_ref()],
+                        "subscription": {
+                            "name": channel.as_ref()
+                        }
+                    })
+                    .to_string(),
+                )
+            })
+            .collect()
+    }
+
+    /// Kraken does not document a maximum number of subscriptions per connection, so no limit is
+    /// applied.
+    ///
+    /// See docs: <https://docs.kraken.com/websockets/>
+    fn subscription_limit() -> Option<usize> {
+        None
+    }
 }
 
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for Kraken
@@ -126,6 +126,28 @@ where
     /// subscription payloads sent to the exchange server.
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage>;
 
+    /// Defines how to translate a collection of [`ExchangeSub`]s into the [`WsMessage`]
+    /// unsubscribe payloads sent to the exchange server over an existing connection.
+    ///
+    /// Defaults to no payloads, meaning the exchange server does not support unsubscribing by
+    /// [`ExchangeSub`]. In this case the [`ExchangeSub`]s are only removed locally, and any
+    /// messages the exchange server continues to send for them are skipped as unidentifiable.
+    fn unsubscribe_requests(
+        _exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        vec![]
+    }
+
+    /// Maximum number of [`Subscription`](subscription::Subscription)s the exchange server
+    /// accepts over a single connection.
+    ///
+    /// Stream builders automatically shard larger collections of
+    /// [`Subscription`](subscription::Subscription)s across multiple connections. Defaults to
+    /// `None`, meaning there is no limit.
+    fn subscription_limit() -> Option<usize> {
+        None
+    }
+
     /// Number of [`Subscription`](subscription::Subscription) responses expected from the
     /// exchange server in responses to the requests send. Used to validate all
     /// [`Subscription`](subscription::Subscription)s were accepted.
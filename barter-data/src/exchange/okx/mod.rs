@@ -76,6 +76,27 @@ impl Connector for Okx {
             .to_string(),
         )]
     }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        vec![WsMessage::Text(
+            json!({
+                "op": "unsubscribe",
+                "args": &exchange_subs,
+            })
+            .to_string(),
+        )]
+    }
+
+    /// OKX limits the total length of the channels in a subscribe request to 64KB, and every
+    /// [`Subscription`](crate::subscription::Subscription) is sent in a single request. 500
+    /// channels (each ~60 bytes) keeps it well within the limit.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-subscribe>
+    fn subscription_limit() -> Option<usize> {
+        Some(500)
+    }
 }
 
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for Okx
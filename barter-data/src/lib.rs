@@ -22,6 +22,8 @@
 //! - Pass [`Subscription`]s to the [`StreamBuilder::subscribe`](streams::builder::StreamBuilder::subscribe) or [`DynamicStreams::init`](streams::builder::DynamicStreams::init) methods.
 //! - Each call to the [`StreamBuilder::subscribe`](streams::builder::StreamBuilder::subscribe) (or each batch passed to the [`DynamicStreams::init`](streams::builder::DynamicStreams::init))
 //!   method opens a new WebSocket connection to the exchange - giving you full control.
+//! - Use the [`DynamicStreamsHandle`](streams::builder::dynamic::DynamicStreamsHandle) returned by [`DynamicStreams::init_with_handle`](streams::builder::dynamic::DynamicStreams::init_with_handle)
+//!   to subscribe to, or unsubscribe from, [`Subscription`]s over the running WebSocket connections.
 //!
 //! ## Examples
 //! For a comprehensive collection of examples, see the /examples directory.
@@ -87,18 +89,28 @@
 use crate::{
     error::DataError,
     event::MarketEvent,
-    exchange::{Connector, ExchangeId, PingInterval},
+    exchange::{subscription::ExchangeSub, Connector, ExchangeId, PingInterval},
     instrument::InstrumentData,
-    subscriber::Subscriber,
-    subscription::{Subscription, SubscriptionKind},
+    subscriber::{mapper::SubscriptionMapper, Subscriber},
+    subscription::{Subscription, SubscriptionKind, SubscriptionMeta},
     transformer::ExchangeTransformer,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    protocol::websocket::{WebSocketParser, WsMessage, WsSink, WsStream},
-    ExchangeStream,
+    error::SocketError,
+    model::SubscriptionId,
+    protocol::{
+        websocket::{WebSocketParser, WsError, WsMessage, WsSink, WsStream},
+        StreamParser,
+    },
+    ExchangeStream, Validator,
 };
 use futures::{SinkExt, Stream, StreamExt};
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll},
+};
 use tokio::sync::mpsc;
 use tracing::{debug, error};
 
@@ -141,9 +153,138 @@ pub mod instrument;
 ///   [`OrderBooksL3`](subscription::book::OrderBooksL3) streams.
 pub mod transformer;
 
-/// Convenient type alias for an [`ExchangeStream`] utilising a tungstenite
-/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
-pub type ExchangeWsStream<Transformer> = ExchangeStream<WebSocketParser, WsStream, Transformer>;
+/// [`ExchangeStream`] utilising a tungstenite
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket), along with the
+/// [`mpsc::UnboundedSender`] used to send messages to the exchange over the same connection.
+pub struct ExchangeWsStream<Transformer>
+where
+    Transformer: barter_integration::Transformer,
+{
+    pub stream: ExchangeStream<WebSocketParser, WsStream, Transformer>,
+    pub ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+}
+
+impl<Transformer> Debug for ExchangeWsStream<Transformer>
+where
+    Transformer: barter_integration::Transformer,
+    ExchangeStream<WebSocketParser, WsStream, Transformer>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExchangeWsStream")
+            .field("stream", &self.stream)
+            .field("ws_sink_tx", &self.ws_sink_tx)
+            .finish()
+    }
+}
+
+impl<Transformer> Stream for ExchangeWsStream<Transformer>
+where
+    Transformer: barter_integration::Transformer,
+    ExchangeStream<WebSocketParser, WsStream, Transformer>: Stream + Unpin,
+{
+    type Item = <ExchangeStream<WebSocketParser, WsStream, Transformer> as Stream>::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.poll_next_unpin(cx)
+    }
+}
+
+impl<Transformer> ExchangeWsStream<Transformer>
+where
+    Transformer: barter_integration::Transformer,
+{
+    /// Send the provided [`WsMessage`]s to the exchange over the existing connection.
+    fn send(&self, messages: Vec<WsMessage>) -> Result<(), DataError> {
+        messages.into_iter().try_for_each(|message| {
+            self.ws_sink_tx
+                .send(message)
+                .map_err(|_| DataError::Socket(SocketError::Sink))
+        })
+    }
+
+    /// Validate the exchange responses to [`Subscription`]s actioned over the existing
+    /// connection, in the same way as the initial
+    /// [`SubscriptionValidator`](subscriber::validator::SubscriptionValidator).
+    ///
+    /// Messages received whilst validating that are not subscription responses (eg/ market data
+    /// of the already active [`Subscription`]s) are transformed & buffered, so they are still
+    /// yielded by the [`Stream`].
+    async fn validate_subscriptions<Exchange>(
+        &mut self,
+        expected_responses: usize,
+    ) -> Result<(), DataError>
+    where
+        Exchange: Connector,
+        Transformer::Error: From<SocketError>,
+    {
+        let timeout = Exchange::subscription_timeout();
+        let deadline = tokio::time::sleep(timeout);
+        tokio::pin!(deadline);
+
+        let mut success_responses = 0usize;
+        while success_responses < expected_responses {
+            let input = tokio::select! {
+                _ = &mut deadline => {
+                    return Err(DataError::Socket(SocketError::Subscribe(format!(
+                        "subscription validation timeout reached: {timeout:?}"
+                    ))))
+                },
+                input = self.stream.stream.next() => match input {
+                    Some(input) => input,
+                    None => {
+                        return Err(DataError::Socket(SocketError::Subscribe(
+                            "WebSocket stream terminated unexpectedly".to_string(),
+                        )))
+                    }
+                },
+            };
+
+            if let Ok(message) = &input {
+                match WebSocketParser::parse::<Exchange::SubResponse>(Ok(message.clone())) {
+                    // Subscription success, or failure if the response fails validation
+                    Some(Ok(response)) => {
+                        let response = response.validate()?;
+                        success_responses += 1;
+                        debug!(
+                            exchange = %Exchange::ID,
+                            %success_responses,
+                            %expected_responses,
+                            payload = ?response,
+                            "received valid Ok subscription response",
+                        );
+                        continue;
+                    }
+                    Some(Err(SocketError::Terminated(close_frame))) => {
+                        return Err(DataError::Socket(SocketError::Subscribe(format!(
+                            "received WebSocket CloseFrame: {close_frame}"
+                        ))))
+                    }
+                    _ => {}
+                }
+            }
+
+            self.buffer(input);
+        }
+
+        Ok(())
+    }
+
+    /// Transform the provided input protocol message & buffer the outputs to be yielded by the
+    /// [`Stream`].
+    fn buffer(&mut self, input: Result<WsMessage, WsError>)
+    where
+        Transformer::Error: From<SocketError>,
+    {
+        match WebSocketParser::parse::<Transformer::Input>(input) {
+            Some(Ok(message)) => {
+                let outputs = self.stream.transformer.transform(message);
+                self.stream.buffer.extend(outputs);
+            }
+            Some(Err(error)) => self.stream.buffer.push_back(Err(error.into())),
+            None => {}
+        }
+    }
+}
 
 /// Defines a generic identification type for the implementor.
 pub trait Identifier<T> {
@@ -169,6 +310,30 @@ where
     where
         Subscription<Exchange, Instrument, Kind>:
             Identifier<Exchange::Channel> + Identifier<Exchange::Market>;
+
+    /// Action additional [`Subscription`]s over the existing connection.
+    ///
+    /// Returns once the exchange responses to the subscription requests have been validated, or
+    /// an error if any [`Subscription`] was rejected or not confirmed in time. Rejected
+    /// [`Subscription`]s are not consumed.
+    async fn subscribe(
+        &mut self,
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+    ) -> Result<(), DataError>
+    where
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>;
+
+    /// Unsubscribe from [`Subscription`]s over the existing connection.
+    ///
+    /// See [`Connector::unsubscribe_requests`] for exchanges that do not support unsubscribing.
+    fn unsubscribe(
+        &mut self,
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+    ) -> Result<(), DataError>
+    where
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>;
 }
 
 #[async_trait]
@@ -212,9 +377,73 @@ where
         }
 
         // Construct Transformer associated with this Exchange and SubscriptionKind
-        let transformer = Transformer::new(ws_sink_tx, map).await?;
+        let transformer = Transformer::new(ws_sink_tx.clone(), map).await?;
+
+        Ok(ExchangeWsStream {
+            stream: ExchangeStream::new(ws_stream, transformer),
+            ws_sink_tx,
+        })
+    }
+
+    async fn subscribe(
+        &mut self,
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+    ) -> Result<(), DataError>
+    where
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Map Subscriptions to exchange specific subscription payloads
+        let SubscriptionMeta {
+            instrument_map,
+            subscriptions,
+        } = <Exchange::Subscriber as Subscriber>::SubMapper::map::<Exchange, Instrument, Kind>(
+            subscriptions,
+        );
+        let expected_responses = Exchange::expected_responses(&instrument_map);
+        let subscription_ids = instrument_map.0.keys().cloned().collect::<Vec<_>>();
+
+        // Update Transformer before subscribing so the first messages can be identified
+        self.stream
+            .transformer
+            .insert(self.ws_sink_tx.clone(), instrument_map)
+            .await?;
+
+        // Send subscriptions & validate responses, forgetting them if any were not accepted
+        let result = match self.send(subscriptions) {
+            Ok(()) => {
+                self.validate_subscriptions::<Exchange>(expected_responses)
+                    .await
+            }
+            Err(error) => Err(error),
+        };
+        if result.is_err() {
+            self.stream.transformer.remove(&subscription_ids);
+        }
+
+        result
+    }
+
+    fn unsubscribe(
+        &mut self,
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+    ) -> Result<(), DataError>
+    where
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Map Subscriptions to exchange specific subscriptions & their SubscriptionIds
+        let exchange_subs = subscriptions
+            .iter()
+            .map(ExchangeSub::<Exchange::Channel, Exchange::Market>::new)
+            .collect::<Vec<_>>();
+        let subscription_ids = exchange_subs
+            .iter()
+            .map(Identifier::<SubscriptionId>::id)
+            .collect::<Vec<_>>();
 
-        Ok(ExchangeWsStream::new(ws_stream, transformer))
+        self.stream.transformer.remove(&subscription_ids);
+        self.send(Exchange::unsubscribe_requests(exchange_subs))
     }
 }
 
@@ -16,15 +16,19 @@ use crate::{
         },
         kraken::{market::KrakenMarket, Kraken},
         okx::{market::OkxMarket, Okx},
-        ExchangeId,
+        ExchangeId, StreamSelector,
     },
     instrument::InstrumentData,
-    streams::{builder::ExchangeChannel, consumer::consume},
+    streams::{
+        builder::{shard, ExchangeChannel},
+        consumer::{consume_with_commands, Command, ConsumerConfig},
+        metric::StreamMetrics,
+    },
     subscription::{
         book::{OrderBook, OrderBookL1, OrderBooksL1},
         liquidation::{Liquidation, Liquidations},
         trade::{PublicTrade, PublicTrades},
-        SubKind, Subscription,
+        SubKind, Subscription, SubscriptionKind,
     },
     Identifier,
 };
@@ -34,7 +38,12 @@ use futures::{
     Stream, StreamExt,
 };
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::{
+    collections::{hash_map::Entry, BTreeSet, HashMap},
+    fmt::{Debug, Formatter},
+    hash::Hash,
+};
+use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use vecmap::VecMap;
 
@@ -52,7 +61,10 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
     ///
     /// Each batch (ie/ `impl Iterator<Item = Subscription>`) will initialise at-least-one
     /// WebSocket `Stream` under the hood. If the batch contains more-than-one [`ExchangeId`] and/or
-    /// [`SubKind`], it will be further split under the hood for compile-time reasons.
+    /// [`SubKind`], it will be further split under the hood for compile-time reasons. Batches that
+    /// exceed the exchange
+    /// [`Connector::subscription_limit`](crate::exchange::Connector::subscription_limit) are
+    /// automatically sharded across multiple connections.
     ///
     /// ## Examples
     /// Please see barter-data-rs/examples/dynamic_multi_stream_multi_exchange.rs for a
@@ -64,309 +76,60 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
         SubBatchIter: IntoIterator<Item = SubIter>,
         SubIter: IntoIterator<Item = Sub>,
         Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
-        Instrument: InstrumentData<Id = InstrumentId> + Ord + 'static,
-        InstrumentId: Clone + Send,
-        Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
-        Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
-        Subscription<BinanceSpot, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
-        Subscription<BinanceFuturesUsd, Instrument, PublicTrades>: Identifier<BinanceMarket>,
-        Subscription<BinanceFuturesUsd, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
-        Subscription<BinanceFuturesUsd, Instrument, Liquidations>: Identifier<BinanceMarket>,
-        Subscription<Bitfinex, Instrument, PublicTrades>: Identifier<BitfinexMarket>,
-        Subscription<Bitmex, Instrument, PublicTrades>: Identifier<BitmexMarket>,
-        Subscription<BybitSpot, Instrument, PublicTrades>: Identifier<BybitMarket>,
-        Subscription<BybitPerpetualsUsd, Instrument, PublicTrades>: Identifier<BybitMarket>,
-        Subscription<Coinbase, Instrument, PublicTrades>: Identifier<CoinbaseMarket>,
-        Subscription<GateioSpot, Instrument, PublicTrades>: Identifier<GateioMarket>,
-        Subscription<GateioFuturesUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
-        Subscription<GateioFuturesBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
-        Subscription<GateioPerpetualsUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
-        Subscription<GateioPerpetualsBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
-        Subscription<GateioOptions, Instrument, PublicTrades>: Identifier<GateioMarket>,
-        Subscription<Kraken, Instrument, PublicTrades>: Identifier<KrakenMarket>,
-        Subscription<Kraken, Instrument, OrderBooksL1>: Identifier<KrakenMarket>,
-        Subscription<Okx, Instrument, PublicTrades>: Identifier<OkxMarket>,
+        Instrument: DynamicInstrument<Id = InstrumentId>,
+        InstrumentId: Clone + Eq + Hash + Send + 'static,
     {
-        // Validate & dedup Subscription batches
-        let batches = validate_batches(subscription_batches)?;
-
-        let mut channels = Channels::<Instrument::Id>::default();
+        Self::init_with_config(subscription_batches, ConsumerConfig::default()).await
+    }
 
-        for mut batch in batches {
-            batch.sort_unstable_by_key(|sub| (sub.exchange, sub.kind));
-            let by_exchange_by_sub_kind =
-                batch.into_iter().chunk_by(|sub| (sub.exchange, sub.kind));
+    /// Initialise a set of `Streams` by providing one or more [`Subscription`] batches, with
+    /// every underlying WebSocket `Stream` consumer loop configured by the provided
+    /// [`ConsumerConfig`].
+    ///
+    /// See [`DynamicStreams::init`] for more information.
+    pub async fn init_with_config<SubBatchIter, SubIter, Sub, Instrument>(
+        subscription_batches: SubBatchIter,
+        config: ConsumerConfig,
+    ) -> Result<Self, DataError>
+    where
+        SubBatchIter: IntoIterator<Item = SubIter>,
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
+        Instrument: DynamicInstrument<Id = InstrumentId>,
+        InstrumentId: Clone + Eq + Hash + Send + 'static,
+    {
+        Self::init_with_handle(subscription_batches, config, None)
+            .await
+            .map(|(streams, _)| streams)
+    }
 
-            for ((exchange, sub_kind), subs) in by_exchange_by_sub_kind.into_iter() {
-                match (exchange, sub_kind) {
-                    (ExchangeId::BinanceSpot, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<BinanceSpot, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        BinanceSpot::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::BinanceSpot, SubKind::OrderBooksL1) => {
-                        tokio::spawn(consume::<BinanceSpot, Instrument, OrderBooksL1>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        BinanceSpot::default(),
-                                        sub.instrument,
-                                        OrderBooksL1,
-                                    )
-                                })
-                                .collect(),
-                            channels.l1s.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::BinanceFuturesUsd, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<BinanceFuturesUsd, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        BinanceFuturesUsd::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL1) => {
-                        tokio::spawn(consume::<BinanceFuturesUsd, Instrument, OrderBooksL1>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::<_, Instrument, _>::new(
-                                        BinanceFuturesUsd::default(),
-                                        sub.instrument,
-                                        OrderBooksL1,
-                                    )
-                                })
-                                .collect(),
-                            channels.l1s.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::BinanceFuturesUsd, SubKind::Liquidations) => {
-                        tokio::spawn(consume::<BinanceFuturesUsd, Instrument, Liquidations>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::<_, Instrument, _>::new(
-                                        BinanceFuturesUsd::default(),
-                                        sub.instrument,
-                                        Liquidations,
-                                    )
-                                })
-                                .collect(),
-                            channels
-                                .liquidations
-                                .entry(exchange)
-                                .or_default()
-                                .tx
-                                .clone(),
-                        ));
-                    }
-                    (ExchangeId::Bitfinex, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<Bitfinex, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(Bitfinex, sub.instrument, PublicTrades)
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::Bitmex, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<Bitmex, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| Subscription::new(Bitmex, sub.instrument, PublicTrades))
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::BybitSpot, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<BybitSpot, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        BybitSpot::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<BybitPerpetualsUsd, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        BybitPerpetualsUsd::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::Coinbase, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<Coinbase, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(Coinbase, sub.instrument, PublicTrades)
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::GateioSpot, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<GateioSpot, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        GateioSpot::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::GateioFuturesUsd, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<GateioFuturesUsd, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        GateioFuturesUsd::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::GateioFuturesBtc, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<GateioFuturesBtc, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        GateioFuturesBtc::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::GateioPerpetualsUsd, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<GateioPerpetualsUsd, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        GateioPerpetualsUsd::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::GateioPerpetualsBtc, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<GateioPerpetualsBtc, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        GateioPerpetualsBtc::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::GateioOptions, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<GateioOptions, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        GateioOptions::default(),
-                                        sub.instrument,
-                                        PublicTrades,
-                                    )
-                                })
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::Kraken, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<Kraken, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| Subscription::new(Kraken, sub.instrument, PublicTrades))
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::Kraken, SubKind::OrderBooksL1) => {
-                        tokio::spawn(consume::<Kraken, Instrument, OrderBooksL1>(
-                            subs.into_iter()
-                                .map(|sub| Subscription::new(Kraken, sub.instrument, OrderBooksL1))
-                                .collect(),
-                            channels.l1s.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::Okx, SubKind::PublicTrades) => {
-                        tokio::spawn(consume::<Okx, Instrument, PublicTrades>(
-                            subs.into_iter()
-                                .map(|sub| Subscription::new(Okx, sub.instrument, PublicTrades))
-                                .collect(),
-                            channels.trades.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (exchange, sub_kind) => {
-                        return Err(DataError::Unsupported { exchange, sub_kind })
-                    }
-                }
-            }
-        }
+    /// Initialise a set of `Streams` by providing one or more [`Subscription`] batches, with
+    /// every underlying WebSocket `Stream` consumer loop configured by the provided
+    /// [`ConsumerConfig`].
+    ///
+    /// Also returns a [`DynamicStreamsHandle`] that can be used to subscribe to, or unsubscribe
+    /// from, [`Subscription`]s over the running WebSocket connections without re-connecting.
+    ///
+    /// If [`StreamMetrics`] are provided, they are recorded by every consumer loop, including
+    /// those spawned via the [`DynamicStreamsHandle`].
+    ///
+    /// See [`DynamicStreams::init`] for more information.
+    pub async fn init_with_handle<SubBatchIter, SubIter, Sub, Instrument>(
+        subscription_batches: SubBatchIter,
+        config: ConsumerConfig,
+        metrics: Option<StreamMetrics<InstrumentId>>,
+    ) -> Result<(Self, DynamicStreamsHandle<Instrument>), DataError>
+    where
+        SubBatchIter: IntoIterator<Item = SubIter>,
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
+        Instrument: DynamicInstrument<Id = InstrumentId>,
+        InstrumentId: Clone + Eq + Hash + Send + 'static,
+    {
+        // Validate & dedup Subscription batches
+        let batches = validate_batches(subscription_batches)?;
 
-        Ok(Self {
-            trades: channels
-                .trades
-                .into_iter()
-                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
-                .collect(),
-            l1s: channels
-                .l1s
-                .into_iter()
-                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
-                .collect(),
-            l2s: channels
-                .l2s
-                .into_iter()
-                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
-                .collect(),
-            liquidations: channels
-                .liquidations
-                .into_iter()
-                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
-                .collect(),
-        })
+        Instrument::init_dynamic_streams(batches, config, metrics)
     }
 
     /// Remove an exchange [`PublicTrade`] `Stream` from the [`DynamicStreams`] collection.
@@ -484,6 +247,495 @@ impl<InstrumentId> DynamicStreams<InstrumentId> {
     }
 }
 
+/// [`InstrumentData`] that [`Subscription`]s can be identified with for every exchange &
+/// [`SubKind`] supported by [`DynamicStreams`].
+///
+/// Implemented for every `Instrument` satisfying each supported exchange [`Identifier`] bound, so
+/// the bounds are declared once rather than by every [`DynamicStreams`] initialiser.
+pub trait DynamicInstrument: InstrumentData + Ord + Sized + 'static {
+    /// Initialise [`DynamicStreams`] from validated & deduplicated [`Subscription`] batches,
+    /// returning the [`DynamicStreamsHandle`] used to action further [`Subscription`]s.
+    fn init_dynamic_streams(
+        batches: Vec<Vec<Subscription<ExchangeId, Self, SubKind>>>,
+        config: ConsumerConfig,
+        metrics: Option<StreamMetrics<Self::Id>>,
+    ) -> Result<(DynamicStreams<Self::Id>, DynamicStreamsHandle<Self>), DataError>;
+}
+
+impl<Instrument> DynamicInstrument for Instrument
+where
+    Instrument: InstrumentData + Ord + 'static,
+    Instrument::Id: Clone + Eq + Hash + Send + 'static,
+    Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+    Subscription<BinanceSpot, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+    Subscription<BinanceFuturesUsd, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+    Subscription<BinanceFuturesUsd, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+    Subscription<BinanceFuturesUsd, Instrument, Liquidations>: Identifier<BinanceMarket>,
+    Subscription<Bitfinex, Instrument, PublicTrades>: Identifier<BitfinexMarket>,
+    Subscription<Bitmex, Instrument, PublicTrades>: Identifier<BitmexMarket>,
+    Subscription<BybitSpot, Instrument, PublicTrades>: Identifier<BybitMarket>,
+    Subscription<BybitPerpetualsUsd, Instrument, PublicTrades>: Identifier<BybitMarket>,
+    Subscription<Coinbase, Instrument, PublicTrades>: Identifier<CoinbaseMarket>,
+    Subscription<GateioSpot, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioFuturesUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioFuturesBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioPerpetualsUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioPerpetualsBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioOptions, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<Kraken, Instrument, PublicTrades>: Identifier<KrakenMarket>,
+    Subscription<Kraken, Instrument, OrderBooksL1>: Identifier<KrakenMarket>,
+    Subscription<Okx, Instrument, PublicTrades>: Identifier<OkxMarket>,
+{
+    fn init_dynamic_streams(
+        batches: Vec<Vec<Subscription<ExchangeId, Self, SubKind>>>,
+        config: ConsumerConfig,
+        metrics: Option<StreamMetrics<Self::Id>>,
+    ) -> Result<(DynamicStreams<Self::Id>, DynamicStreamsHandle<Self>), DataError> {
+        let mut channels = Channels::<Instrument::Id>::default();
+        let mut handle = DynamicStreamsHandle::default();
+
+        for mut batch in batches {
+            batch.sort_unstable_by_key(|sub| (sub.exchange, sub.kind));
+            let by_exchange_by_sub_kind =
+                batch.into_iter().chunk_by(|sub| (sub.exchange, sub.kind));
+
+            for ((exchange, sub_kind), subs) in by_exchange_by_sub_kind.into_iter() {
+                let connections = match handle.connections.entry((exchange, sub_kind)) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => entry.insert(match (exchange, sub_kind) {
+                        (ExchangeId::BinanceSpot, SubKind::PublicTrades) => {
+                            Connections::new::<BinanceSpot, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::BinanceSpot, SubKind::OrderBooksL1) => {
+                            Connections::new::<BinanceSpot, _>(
+                                OrderBooksL1,
+                                config,
+                                metrics.clone(),
+                                channels.l1s.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::BinanceFuturesUsd, SubKind::PublicTrades) => {
+                            Connections::new::<BinanceFuturesUsd, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL1) => {
+                            Connections::new::<BinanceFuturesUsd, _>(
+                                OrderBooksL1,
+                                config,
+                                metrics.clone(),
+                                channels.l1s.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::BinanceFuturesUsd, SubKind::Liquidations) => {
+                            Connections::new::<BinanceFuturesUsd, _>(
+                                Liquidations,
+                                config,
+                                metrics.clone(),
+                                channels
+                                    .liquidations
+                                    .entry(exchange)
+                                    .or_default()
+                                    .tx
+                                    .clone(),
+                            )
+                        }
+                        (ExchangeId::Bitfinex, SubKind::PublicTrades) => {
+                            Connections::new::<Bitfinex, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::Bitmex, SubKind::PublicTrades) => {
+                            Connections::new::<Bitmex, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::BybitSpot, SubKind::PublicTrades) => {
+                            Connections::new::<BybitSpot, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
+                            Connections::new::<BybitPerpetualsUsd, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::Coinbase, SubKind::PublicTrades) => {
+                            Connections::new::<Coinbase, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::GateioSpot, SubKind::PublicTrades) => {
+                            Connections::new::<GateioSpot, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::GateioFuturesUsd, SubKind::PublicTrades) => {
+                            Connections::new::<GateioFuturesUsd, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::GateioFuturesBtc, SubKind::PublicTrades) => {
+                            Connections::new::<GateioFuturesBtc, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::GateioPerpetualsUsd, SubKind::PublicTrades) => {
+                            Connections::new::<GateioPerpetualsUsd, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::GateioPerpetualsBtc, SubKind::PublicTrades) => {
+                            Connections::new::<GateioPerpetualsBtc, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::GateioOptions, SubKind::PublicTrades) => {
+                            Connections::new::<GateioOptions, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::Kraken, SubKind::PublicTrades) => {
+                            Connections::new::<Kraken, _>(
+                                PublicTrades,
+                                config,
+                                metrics.clone(),
+                                channels.trades.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::Kraken, SubKind::OrderBooksL1) => {
+                            Connections::new::<Kraken, _>(
+                                OrderBooksL1,
+                                config,
+                                metrics.clone(),
+                                channels.l1s.entry(exchange).or_default().tx.clone(),
+                            )
+                        }
+                        (ExchangeId::Okx, SubKind::PublicTrades) => Connections::new::<Okx, _>(
+                            PublicTrades,
+                            config,
+                            metrics.clone(),
+                            channels.trades.entry(exchange).or_default().tx.clone(),
+                        ),
+                        (exchange, sub_kind) => {
+                            return Err(DataError::Unsupported { exchange, sub_kind })
+                        }
+                    }),
+                };
+
+                // Each batch is actioned on at least one new connection
+                connections.spawn(subs.collect());
+            }
+        }
+
+        let streams = DynamicStreams {
+            trades: channels
+                .trades
+                .into_iter()
+                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
+                .collect(),
+            l1s: channels
+                .l1s
+                .into_iter()
+                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
+                .collect(),
+            l2s: channels
+                .l2s
+                .into_iter()
+                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
+                .collect(),
+            liquidations: channels
+                .liquidations
+                .into_iter()
+                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
+                .collect(),
+        };
+
+        Ok((streams, handle))
+    }
+}
+
+/// Handle used to subscribe to, or unsubscribe from, [`Subscription`]s over the running WebSocket
+/// connections of a [`DynamicStreams`], without re-connecting.
+///
+/// Initialised via [`DynamicStreams::init_with_handle`].
+#[derive(Debug)]
+pub struct DynamicStreamsHandle<Instrument> {
+    connections: HashMap<(ExchangeId, SubKind), Connections<Instrument>>,
+}
+
+impl<Instrument> Default for DynamicStreamsHandle<Instrument> {
+    fn default() -> Self {
+        Self {
+            connections: HashMap::default(),
+        }
+    }
+}
+
+impl<Instrument> DynamicStreamsHandle<Instrument>
+where
+    Instrument: InstrumentData + Ord,
+{
+    /// Subscribe to the provided [`Subscription`]s over the running WebSocket connections.
+    ///
+    /// [`Subscription`]s are actioned over the running connections for their [`ExchangeId`] and
+    /// [`SubKind`] with capacity remaining under the
+    /// [`Connector::subscription_limit`](crate::exchange::Connector::subscription_limit), and new
+    /// connections are initialised for any remaining [`Subscription`]s.
+    ///
+    /// Errors if a [`Subscription`] is invalid, or there is no running connection for its
+    /// [`ExchangeId`] and [`SubKind`], in which case no [`Subscription`]s are actioned.
+    pub fn subscribe<SubIter, Sub>(&mut self, subscriptions: SubIter) -> Result<(), DataError>
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
+    {
+        let mut commands = HashMap::<_, Vec<_>>::new();
+        for subscription in subscriptions {
+            let subscription = Validator::validate(subscription.into())?;
+            let key = (subscription.exchange, subscription.kind);
+
+            if !self.connections.contains_key(&key) {
+                return Err(DataError::Unconnected {
+                    exchange: key.0,
+                    sub_kind: key.1,
+                });
+            }
+
+            commands.entry(key).or_default().push(subscription);
+        }
+
+        commands.into_iter().for_each(|(key, subscriptions)| {
+            self.connections
+                .get_mut(&key)
+                .expect("connections contains key")
+                .subscribe(subscriptions)
+        });
+
+        Ok(())
+    }
+
+    /// Unsubscribe from the provided [`Subscription`]s over the running WebSocket connections.
+    ///
+    /// [`Subscription`]s not actioned over any running connection are ignored.
+    pub fn unsubscribe<SubIter, Sub>(&mut self, subscriptions: SubIter) -> Result<(), DataError>
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
+    {
+        let mut commands = HashMap::<_, Vec<_>>::new();
+        for subscription in subscriptions {
+            let subscription = subscription.into();
+            commands
+                .entry((subscription.exchange, subscription.kind))
+                .or_default()
+                .push(subscription);
+        }
+
+        commands.into_iter().for_each(|(key, subscriptions)| {
+            if let Some(connections) = self.connections.get_mut(&key) {
+                connections.unsubscribe(&subscriptions)
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// Sender of dynamic [`Subscription`] [`Command`]s to a running connection consumer loop.
+type ShardTx<Instrument> =
+    mpsc::UnboundedSender<Command<Subscription<ExchangeId, Instrument, SubKind>>>;
+
+/// Running connections for a single [`ExchangeId`] and [`SubKind`], each actioning at most
+/// `limit` [`Subscription`]s.
+struct Connections<Instrument> {
+    limit: Option<usize>,
+    shards: Vec<Shard<Instrument>>,
+    spawn: Box<
+        dyn Fn(Vec<Subscription<ExchangeId, Instrument, SubKind>>) -> ShardTx<Instrument>
+            + Send
+            + Sync,
+    >,
+}
+
+/// Single running connection, and the [`Subscription`]s actioned over it.
+#[derive(Debug)]
+struct Shard<Instrument> {
+    command_tx: ShardTx<Instrument>,
+    subscriptions: BTreeSet<Subscription<ExchangeId, Instrument, SubKind>>,
+}
+
+impl<Instrument> Debug for Connections<Instrument>
+where
+    Instrument: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Connections")
+            .field("limit", &self.limit)
+            .field("shards", &self.shards)
+            .finish()
+    }
+}
+
+impl<Instrument> Connections<Instrument>
+where
+    Instrument: InstrumentData + Ord,
+{
+    /// Construct a new [`Self`] that spawns consumer loops for the provided `Exchange` and `Kind`.
+    fn new<Exchange, Kind>(
+        kind: Kind,
+        config: ConsumerConfig,
+        metrics: Option<StreamMetrics<Instrument::Id>>,
+        exchange_tx: mpsc::UnboundedSender<MarketEvent<Instrument::Id, Kind::Event>>,
+    ) -> Self
+    where
+        Exchange: StreamSelector<Instrument, Kind> + Send + Sync + 'static,
+        Instrument: 'static,
+        Instrument::Id: Eq + Hash,
+        Kind: SubscriptionKind + Copy + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        Self {
+            limit: Exchange::subscription_limit(),
+            shards: Vec::new(),
+            spawn: Box::new(move |subscriptions| {
+                spawn_consumer::<Exchange, Instrument, Kind>(
+                    kind,
+                    subscriptions,
+                    config,
+                    metrics.clone(),
+                    exchange_tx.clone(),
+                )
+            }),
+        }
+    }
+
+    /// Spawn new connections for the provided [`Subscription`]s, sharded by the `limit`.
+    fn spawn(&mut self, subscriptions: Vec<Subscription<ExchangeId, Instrument, SubKind>>) {
+        for subscriptions in shard(subscriptions, self.limit) {
+            self.shards.push(Shard {
+                command_tx: (self.spawn)(subscriptions.clone()),
+                subscriptions: subscriptions.into_iter().collect(),
+            })
+        }
+    }
+
+    /// Action the provided [`Subscription`]s over the running connections with remaining
+    /// capacity, spawning new connections for the remainder.
+    fn subscribe(&mut self, subscriptions: Vec<Subscription<ExchangeId, Instrument, SubKind>>) {
+        self.prune();
+
+        // Filter out duplicate Subscriptions & those that are already actioned
+        let mut subscriptions = subscriptions
+            .into_iter()
+            .filter(|subscription| {
+                !self
+                    .shards
+                    .iter()
+                    .any(|shard| shard.subscriptions.contains(subscription))
+            })
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        for shard in &mut self.shards {
+            if subscriptions.is_empty() {
+                break;
+            }
+
+            let capacity = self.limit.map_or(subscriptions.len(), |limit| {
+                limit.saturating_sub(shard.subscriptions.len())
+            });
+            if capacity == 0 {
+                continue;
+            }
+
+            let added = subscriptions
+                .drain(..capacity.min(subscriptions.len()))
+                .collect::<Vec<_>>();
+            match shard.command_tx.send(Command::Subscribe(added.clone())) {
+                Ok(()) => shard.subscriptions.extend(added),
+                // Consumer loop terminated since pruning, so allocate the Subscriptions elsewhere
+                Err(_) => subscriptions.extend(added),
+            }
+        }
+
+        self.prune();
+        if !subscriptions.is_empty() {
+            self.spawn(subscriptions);
+        }
+    }
+
+    /// Unsubscribe from the provided [`Subscription`]s over the running connections that
+    /// actioned them.
+    fn unsubscribe(&mut self, subscriptions: &[Subscription<ExchangeId, Instrument, SubKind>]) {
+        for shard in &mut self.shards {
+            let removed = subscriptions
+                .iter()
+                .filter(|subscription| shard.subscriptions.remove(subscription))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            // A terminated consumer loop has nothing left to unsubscribe from
+            if !removed.is_empty() {
+                let _ = shard.command_tx.send(Command::Unsubscribe(removed));
+            }
+        }
+
+        self.prune();
+    }
+
+    /// Remove connections whose consumer loop has terminated (eg/ after
+    /// [`GiveUp::Terminate`](crate::streams::reconnect::GiveUp::Terminate)),
+    /// so their capacity is never allocated to new [`Subscription`]s.
+    fn prune(&mut self) {
+        self.shards.retain(|shard| !shard.command_tx.is_closed());
+    }
+}
+
 pub fn validate_batches<SubBatchIter, SubIter, Sub, Instrument>(
     batches: SubBatchIter,
 ) -> Result<Vec<Vec<Subscription<ExchangeId, Instrument, SubKind>>>, DataError>
@@ -529,3 +781,151 @@ impl<InstrumentId> Default for Channels<InstrumentId> {
         }
     }
 }
+
+/// Spawn a [`consume_with_commands`] loop for the provided dynamic [`Subscription`]s, returning
+/// the [`mpsc::UnboundedSender`] used to send it dynamic [`Subscription`] [`Command`]s.
+fn spawn_consumer<Exchange, Instrument, Kind>(
+    kind: Kind,
+    subscriptions: impl IntoIterator<Item = Subscription<ExchangeId, Instrument, SubKind>>,
+    config: ConsumerConfig,
+    metrics: Option<StreamMetrics<Instrument::Id>>,
+    exchange_tx: mpsc::UnboundedSender<MarketEvent<Instrument::Id, Kind::Event>>,
+) -> mpsc::UnboundedSender<Command<Subscription<ExchangeId, Instrument, SubKind>>>
+where
+    Exchange: StreamSelector<Instrument, Kind> + Send + Sync + 'static,
+    Instrument: InstrumentData + 'static,
+    Instrument::Id: Eq + Hash,
+    Kind: SubscriptionKind + Copy + Send + Sync + 'static,
+    Kind::Event: Send,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let to_exchange_sub = move |sub: Subscription<ExchangeId, Instrument, SubKind>| {
+        Subscription::new(Exchange::default(), sub.instrument, kind)
+    };
+
+    let (command_tx, command_rx) = mpsc::unbounded_channel();
+    let commands = UnboundedReceiverStream::new(command_rx)
+        .map(move |command: Command<_>| command.map(to_exchange_sub));
+
+    tokio::spawn(consume_with_commands(
+        subscriptions.into_iter().map(to_exchange_sub).collect(),
+        config,
+        exchange_tx,
+        commands,
+        metrics,
+    ));
+
+    command_tx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+    use std::sync::{Arc, Mutex};
+
+    fn subscription(
+        base: &str,
+        sub_kind: SubKind,
+    ) -> Subscription<ExchangeId, Instrument, SubKind> {
+        Subscription::from((
+            ExchangeId::BinanceSpot,
+            base,
+            "usdt",
+            InstrumentKind::Spot,
+            sub_kind,
+        ))
+    }
+
+    #[test]
+    fn test_dynamic_streams_handle() {
+        // Connections that record the Subscriptions & command receiver of every spawned connection
+        let spawned = Arc::new(Mutex::new(Vec::new()));
+        let spawned_clone = Arc::clone(&spawned);
+        let mut connections = Connections {
+            limit: Some(2),
+            shards: Vec::new(),
+            spawn: Box::new(move |subscriptions| {
+                let (command_tx, command_rx) = mpsc::unbounded_channel();
+                spawned_clone
+                    .lock()
+                    .unwrap()
+                    .push((subscriptions, command_rx));
+                command_tx
+            }),
+        };
+        connections.spawn(vec![subscription("btc", SubKind::PublicTrades)]);
+
+        let mut handle = DynamicStreamsHandle {
+            connections: HashMap::from([(
+                (ExchangeId::BinanceSpot, SubKind::PublicTrades),
+                connections,
+            )]),
+        };
+
+        // Subscription without a running connection for its ExchangeId & SubKind is rejected
+        assert!(matches!(
+            handle.subscribe([
+                subscription("eth", SubKind::PublicTrades),
+                subscription("eth", SubKind::OrderBooksL1),
+            ]),
+            Err(DataError::Unconnected {
+                exchange: ExchangeId::BinanceSpot,
+                sub_kind: SubKind::OrderBooksL1
+            })
+        ));
+        assert!(spawned.lock().unwrap()[0].1.try_recv().is_err());
+
+        // Subscriptions fill the running connection up to the limit, then spawn a new connection
+        handle
+            .subscribe([
+                subscription("btc", SubKind::PublicTrades),
+                subscription("eth", SubKind::PublicTrades),
+                subscription("sol", SubKind::PublicTrades),
+            ])
+            .unwrap();
+        {
+            let mut spawned = spawned.lock().unwrap();
+            assert_eq!(spawned.len(), 2);
+            assert_eq!(
+                spawned[0].1.try_recv().unwrap(),
+                Command::Subscribe(vec![subscription("eth", SubKind::PublicTrades)])
+            );
+            assert_eq!(
+                spawned[1].0,
+                vec![subscription("sol", SubKind::PublicTrades)]
+            );
+        }
+
+        // Unsubscribe is only sent to the connection that actioned the Subscription
+        handle
+            .unsubscribe([
+                subscription("sol", SubKind::PublicTrades),
+                subscription("sol", SubKind::OrderBooksL1),
+            ])
+            .unwrap();
+        {
+            let mut spawned = spawned.lock().unwrap();
+            assert!(spawned[0].1.try_recv().is_err());
+            assert_eq!(
+                spawned[1].1.try_recv().unwrap(),
+                Command::Unsubscribe(vec![subscription("sol", SubKind::PublicTrades)])
+            );
+        }
+
+        // Connections whose consumer loop has terminated are pruned rather than subscribed over
+        drop(spawned.lock().unwrap().remove(1));
+        handle
+            .subscribe([subscription("sol", SubKind::PublicTrades)])
+            .unwrap();
+        {
+            let spawned = spawned.lock().unwrap();
+            assert_eq!(spawned.len(), 2);
+            assert_eq!(
+                spawned[1].0,
+                vec![subscription("sol", SubKind::PublicTrades)]
+            );
+        }
+    }
+}
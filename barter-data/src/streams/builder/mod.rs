@@ -1,4 +1,10 @@
-use super::{consumer::consume, Streams};
+use super::{
+    consumer::{consume_with_commands, ConsumerConfig},
+    lifecycle::StreamOutput,
+    metric::StreamMetrics,
+    reconnect::ReconnectPolicy,
+    Streams,
+};
 use crate::{
     error::DataError,
     event::MarketEvent,
@@ -7,7 +13,7 @@ use crate::{
     Identifier,
 };
 use barter_integration::{error::SocketError, model::instrument::Instrument, Validator};
-use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
+use std::{collections::HashMap, fmt::Debug, future::Future, marker::PhantomData, pin::Pin};
 use tokio::sync::mpsc;
 
 /// Defines the [`MultiStreamBuilder`](multi::MultiStreamBuilder) API for ergonomically
@@ -23,28 +29,46 @@ pub type SubscribeFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>>;
 
 /// Builder to configure and initialise a [`Streams<MarketEvent<SubscriptionKind::Event>`](Streams) instance
 /// for a specific [`SubscriptionKind`].
-#[derive(Default)]
-pub struct StreamBuilder<Kind>
+///
+/// The `Output` defaults to [`MarketEvent<SubscriptionKind::Event>`](MarketEvent). Use a
+/// [`lifecycle::Event`](super::lifecycle::Event) `Output` (see
+/// [`Streams::builder_with_lifecycle`]) to also receive stream-level events.
+pub struct StreamBuilder<Kind, Output = MarketEvent<Instrument, <Kind as SubscriptionKind>::Event>>
 where
     Kind: SubscriptionKind,
 {
-    pub channels: HashMap<ExchangeId, ExchangeChannel<MarketEvent<Instrument, Kind::Event>>>,
+    pub channels: HashMap<ExchangeId, ExchangeChannel<Output>>,
     pub futures: Vec<SubscribeFuture>,
+    pub consumer_config: ConsumerConfig,
+    pub metrics: Option<StreamMetrics<Instrument>>,
+    phantom: PhantomData<Kind>,
 }
 
-impl<Kind> Debug for StreamBuilder<Kind>
+impl<Kind, Output> Debug for StreamBuilder<Kind, Output>
 where
     Kind: SubscriptionKind,
+    Output: Debug,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("StreamBuilder<SubscriptionKind>")
             .field("channels", &self.channels)
             .field("num_futures", &self.futures.len())
+            .field("consumer_config", &self.consumer_config)
+            .field("metrics", &self.metrics)
             .finish()
     }
 }
 
-impl<Kind> StreamBuilder<Kind>
+impl<Kind, Output> Default for StreamBuilder<Kind, Output>
+where
+    Kind: SubscriptionKind,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Kind, Output> StreamBuilder<Kind, Output>
 where
     Kind: SubscriptionKind,
 {
@@ -53,12 +77,49 @@ where
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            consumer_config: ConsumerConfig::default(),
+            metrics: None,
+            phantom: PhantomData,
         }
     }
 
+    /// Set the [`ReconnectPolicy`] used by the consumer loops of all subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls.
+    ///
+    /// Since the policy is applied per [`subscribe()`](StreamBuilder::subscribe()) call, each
+    /// WebSocket connection can be given a distinct [`ReconnectPolicy`] if required.
+    pub fn reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.consumer_config.reconnect_policy = policy;
+        self
+    }
+
+    /// Set the duration without a [`MarketEvent`] for an instrument after which the consumer loops
+    /// of all subsequent [`subscribe()`](StreamBuilder::subscribe()) calls emit a
+    /// [`lifecycle::Event::Stale`](super::lifecycle::Event::Stale).
+    ///
+    /// Note that stale events are only yielded if the `Output` is a
+    /// [`lifecycle::Event`](super::lifecycle::Event).
+    pub fn stale_after(mut self, stale_after: std::time::Duration) -> Self {
+        self.consumer_config.stale_after_ms = Some(stale_after.as_millis() as u64);
+        self
+    }
+
+    /// Record data-feed health [`StreamMetrics`] in the consumer loops of all subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls.
+    ///
+    /// The same [`StreamMetrics`] can be shared between [`StreamBuilder`]s by cloning it.
+    pub fn metrics(mut self, metrics: StreamMetrics<Instrument>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
     /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
     ///
+    /// If the collection exceeds the [`Connector::subscription_limit`], it is automatically
+    /// sharded across multiple connections. Every connection for an exchange is merged into the
+    /// same output stream.
+    ///
     /// Note that [`Subscription`]s are not actioned until the
     /// [`init()`](StreamBuilder::init()) method is invoked.
     pub fn subscribe<SubIter, Sub, Exchange>(mut self, subscriptions: SubIter) -> Self
@@ -68,6 +129,7 @@ where
         Exchange: StreamSelector<Instrument, Kind> + Ord + Send + Sync + 'static,
         Kind: Ord + Send + Sync + 'static,
         Kind::Event: Send,
+        Output: StreamOutput<Instrument, MarketEvent<Instrument, Kind::Event>> + Send + 'static,
         Subscription<Exchange, Instrument, Kind>:
             Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
@@ -78,6 +140,10 @@ where
         // '--> Add ExchangeChannel Entry if this Exchange <--> SubscriptionKind combination is new
         let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
 
+        // ConsumerConfig to use for this WebSocket connection
+        let config = self.consumer_config;
+        let metrics = self.metrics.clone();
+
         // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
         self.futures.push(Box::pin(async move {
             // Validate Subscriptions
@@ -87,8 +153,16 @@ where
             subscriptions.sort();
             subscriptions.dedup();
 
-            // Spawn a MarketStream consumer loop with these Subscriptions<Exchange, Kind>
-            tokio::spawn(consume(subscriptions, exchange_tx));
+            // Spawn a MarketStream consumer loop for each shard of Subscriptions<Exchange, Kind>
+            for shard in shard(subscriptions, Exchange::subscription_limit()) {
+                tokio::spawn(consume_with_commands(
+                    shard,
+                    config,
+                    exchange_tx.clone(),
+                    futures::stream::pending(),
+                    metrics.clone(),
+                ));
+            }
 
             Ok(())
         }));
@@ -102,7 +176,7 @@ where
     ///
     /// Each consumer loop distributes consumed [`MarketEvent<SubscriptionKind::Event>s`](MarketEvent) to
     /// the [`Streams`] `HashMap` returned by this method.
-    pub async fn init(self) -> Result<Streams<MarketEvent<Instrument, Kind::Event>>, DataError> {
+    pub async fn init(self) -> Result<Streams<Output>, DataError> {
         // Await Stream initialisation perpetual and ensure success
         futures::future::try_join_all(self.futures).await?;
 
@@ -139,6 +213,22 @@ impl<T> Default for ExchangeChannel<T> {
     }
 }
 
+/// Split the provided collection of [`Subscription`]s into shards of at most `limit`
+/// [`Subscription`]s, each of which is actioned on a distinct connection.
+pub fn shard<T>(subscriptions: Vec<T>, limit: Option<usize>) -> Vec<Vec<T>> {
+    match limit {
+        Some(limit) if subscriptions.len() > limit => {
+            let mut subscriptions = subscriptions.into_iter().peekable();
+            let mut shards = Vec::new();
+            while subscriptions.peek().is_some() {
+                shards.push(subscriptions.by_ref().take(limit.max(1)).collect());
+            }
+            shards
+        }
+        _ => vec![subscriptions],
+    }
+}
+
 /// Validate the provided collection of [`Subscription`]s, ensuring that the associated exchange
 /// supports every [`Subscription`] [`InstrumentKind`](barter_integration::model::InstrumentKind).
 pub fn validate<Exchange, Kind>(
@@ -229,4 +319,39 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_shard() {
+        struct TestCase {
+            input: Vec<u32>,
+            limit: Option<usize>,
+            expected: Vec<Vec<u32>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: no limit yields a single shard
+                input: vec![1, 2, 3],
+                limit: None,
+                expected: vec![vec![1, 2, 3]],
+            },
+            TestCase {
+                // TC1: collection within limit yields a single shard
+                input: vec![1, 2, 3],
+                limit: Some(3),
+                expected: vec![vec![1, 2, 3]],
+            },
+            TestCase {
+                // TC2: collection exceeding limit is split into shards of at most limit
+                input: vec![1, 2, 3, 4, 5],
+                limit: Some(2),
+                expected: vec![vec![1, 2], vec![3, 4], vec![5]],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = shard(test.input, test.limit);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
 }
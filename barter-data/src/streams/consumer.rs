@@ -1,89 +1,389 @@
 use crate::{
     error::DataError,
     event::MarketEvent,
-    exchange::StreamSelector,
+    exchange::{subscription::ExchangeSub, Connector, StreamSelector},
     instrument::InstrumentData,
+    streams::{
+        lifecycle::{Event, StalenessMonitor, StreamOutput},
+        metric::StreamMetrics,
+        reconnect::{GiveUp, ReconnectPolicy},
+    },
     subscription::{Subscription, SubscriptionKind},
     Identifier, MarketStream,
 };
-use futures::StreamExt;
-use std::time::Duration;
-use tokio::sync::mpsc;
+use barter_integration::model::SubscriptionId;
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, hash::Hash, time::Duration};
+use tokio::{
+    sync::mpsc,
+    time::{Instant, Interval},
+};
 use tracing::{debug, error, info, warn};
 
-/// Initial duration that the [`consume`] function should wait after disconnecting before attempting
-/// to re-initialise a [`MarketStream`]. This duration will increase exponentially as a result
-/// of repeated disconnections with re-initialisation failures.
+/// Default initial duration that the [`consume`] function should wait after disconnecting before
+/// attempting to re-initialise a [`MarketStream`]. See the [`ReconnectPolicy`] for how this
+/// duration increases as a result of repeated re-initialisation failures.
 pub const STARTING_RECONNECT_BACKOFF_MS: u64 = 125;
 
+/// Configuration for a [`consume`] loop.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct ConsumerConfig {
+    /// Policy used to re-initialise the [`MarketStream`] after it disconnects.
+    pub reconnect_policy: ReconnectPolicy,
+    /// Duration without consuming an item for an instrument after which an [`Event::Stale`] is
+    /// emitted. `None` disables staleness detection.
+    pub stale_after_ms: Option<u64>,
+}
+
+/// Command sent to a running [`consume_with_commands`] loop in order to change its
+/// [`Subscription`]s without re-connecting.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum Command<Sub> {
+    /// Subscribe to additional [`Subscription`]s over the existing connection.
+    Subscribe(Vec<Sub>),
+    /// Unsubscribe from [`Subscription`]s over the existing connection.
+    Unsubscribe(Vec<Sub>),
+}
+
+impl<Sub> Command<Sub> {
+    /// Map every `Sub` of the [`Command`] into an `O`.
+    pub fn map<F, O>(self, op: F) -> Command<O>
+    where
+        F: FnMut(Sub) -> O,
+    {
+        match self {
+            Command::Subscribe(subscriptions) => {
+                Command::Subscribe(subscriptions.into_iter().map(op).collect())
+            }
+            Command::Unsubscribe(subscriptions) => {
+                Command::Unsubscribe(subscriptions.into_iter().map(op).collect())
+            }
+        }
+    }
+}
+
 /// Central [`MarketEvent<T>`](MarketEvent) consumer loop.
 ///
 /// Initialises an exchange [`MarketStream`] using a collection of [`Subscription`]s. Consumed
 /// events are distributed downstream via the `exchange_tx mpsc::UnboundedSender`. A re-connection
-/// mechanism with an exponential backoff policy is utilised to ensure maximum up-time.
-pub async fn consume<Exchange, Instrument, Kind>(
+/// mechanism configured by the [`ReconnectPolicy`] is utilised to ensure maximum up-time.
+///
+/// If the `Output` supports it (eg/ [`lifecycle::Event`](Event)), lifecycle events are also sent
+/// downstream, indicating when the connection state changes and where consumed events may
+/// contain a gap or have gone stale.
+pub async fn consume<Exchange, Instrument, Kind, Output>(
     subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
-    exchange_tx: mpsc::UnboundedSender<MarketEvent<Instrument::Id, Kind::Event>>,
+    config: ConsumerConfig,
+    exchange_tx: mpsc::UnboundedSender<Output>,
+) -> Result<(), DataError>
+where
+    Exchange: StreamSelector<Instrument, Kind>,
+    Kind: SubscriptionKind,
+    Instrument: InstrumentData,
+    Instrument::Id: Eq + Hash,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    Output: StreamOutput<Instrument::Id, MarketEvent<Instrument::Id, Kind::Event>>,
+{
+    consume_with_commands(
+        subscriptions,
+        config,
+        exchange_tx,
+        futures::stream::pending(),
+        None,
+    )
+    .await
+}
+
+/// Central [`MarketEvent<T>`](MarketEvent) consumer loop that also actions [`Command`]s received
+/// from the provided `commands` [`Stream`], allowing the [`Subscription`]s to change while the
+/// [`MarketStream`] is running.
+///
+/// The current set of [`Subscription`]s is used whenever the [`MarketStream`] re-connects.
+/// [`Command`]s received while re-connecting are actioned once the [`MarketStream`] has been
+/// re-initialised.
+///
+/// If [`StreamMetrics`] are provided, every consumed [`MarketEvent`], re-connection and parse
+/// error is recorded. See [`consume`] for more information.
+pub async fn consume_with_commands<Exchange, Instrument, Kind, Output, Commands>(
+    mut subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
+    config: ConsumerConfig,
+    exchange_tx: mpsc::UnboundedSender<Output>,
+    mut commands: Commands,
+    metrics: Option<StreamMetrics<Instrument::Id>>,
 ) -> Result<(), DataError>
 where
     Exchange: StreamSelector<Instrument, Kind>,
     Kind: SubscriptionKind,
     Instrument: InstrumentData,
+    Instrument::Id: Eq + Hash,
     Subscription<Exchange, Instrument, Kind>:
         Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    Output: StreamOutput<Instrument::Id, MarketEvent<Instrument::Id, Kind::Event>>,
+    Commands: Stream<Item = Command<Subscription<Exchange, Instrument, Kind>>> + Unpin,
 {
     // Determine ExchangeId associated with these Subscriptions
     let exchange = Exchange::ID;
+    let ConsumerConfig {
+        reconnect_policy: policy,
+        stale_after_ms,
+    } = config;
 
     info!(
         %exchange,
         ?subscriptions,
-        policy = "retry connection with exponential backoff",
+        ?config,
         "MarketStream consumer loop running",
     );
 
+    // Optional monitor to detect instruments that have stopped yielding MarketEvents
+    let mut staleness = stale_after_ms.map(|stale_after_ms| {
+        StalenessMonitor::new(
+            Duration::from_millis(stale_after_ms),
+            subscriptions
+                .iter()
+                .map(|subscription| subscription.instrument.id().clone()),
+            Instant::now(),
+        )
+    });
+    let mut staleness_interval = staleness.as_ref().map(|monitor| {
+        tokio::time::interval((monitor.stale_after / 2).max(Duration::from_millis(1)))
+    });
+
     // Consumer loop retry parameters
     let mut attempt: u32 = 0;
-    let mut backoff_ms: u64 = STARTING_RECONNECT_BACKOFF_MS;
+    let mut backoff = None;
+    let mut connected = false;
+    let mut disconnected = false;
+    let mut commands_open = true;
 
     'retry: loop {
+        // Wait for the backoff determined by the previous iteration, if any
+        if let Some(backoff) = backoff.take() {
+            tokio::time::sleep(backoff).await;
+        }
+
         // Increment retry parameters at start of every iteration
         attempt += 1;
-        backoff_ms *= 2;
         info!(%exchange, attempt, "attempting to initialise MarketStream");
 
-        // Attempt to initialise MarketStream: if it fails on first attempt return DataError
+        // Attempt to initialise MarketStream
         let mut stream = match Exchange::Stream::init(&subscriptions).await {
             Ok(stream) => {
                 info!(%exchange, attempt, "successfully initialised MarketStream");
                 attempt = 0;
-                backoff_ms = STARTING_RECONNECT_BACKOFF_MS;
+
+                // Notify downstream of the new connection state
+                let lifecycle_events = if !std::mem::replace(&mut connected, true) {
+                    vec![Event::Connected(exchange)]
+                } else if std::mem::take(&mut disconnected) {
+                    if let Some(metrics) = &metrics {
+                        metrics.record_reconnect(exchange);
+                    }
+
+                    let mut events = vec![Event::Resubscribed(exchange)];
+
+                    // Snapshot based instrument state has been rebuilt by the new MarketStream
+                    if Kind::SNAPSHOT_BASED {
+                        events.extend(subscriptions.iter().map(|subscription| {
+                            Event::SnapshotReset {
+                                exchange,
+                                instrument: subscription.instrument.id().clone(),
+                            }
+                        }));
+                    }
+
+                    events
+                } else {
+                    vec![]
+                };
+
+                for event in lifecycle_events {
+                    if send_event(&exchange_tx, event).is_err() {
+                        break 'retry Ok(());
+                    }
+                }
+
+                // Staleness is measured relative to the (re-)initialisation of the MarketStream
+                if let Some(monitor) = &mut staleness {
+                    monitor.reset(Instant::now());
+                }
+
                 stream
             }
             Err(error) => {
                 error!(%exchange, attempt, ?error, "failed to initialise MarketStream");
 
-                // Exit function if Stream::init failed the first attempt, else retry
-                if attempt == 1 {
-                    return Err(error);
-                } else {
-                    continue;
+                // Action the ReconnectPolicy GiveUp behaviour if all attempts have been exhausted
+                if policy.is_exhausted(attempt) {
+                    match policy.give_up {
+                        GiveUp::Terminate => {
+                            error!(
+                                %exchange,
+                                attempt,
+                                action = "terminating consumer loop",
+                                "exhausted ReconnectPolicy attempts to initialise MarketStream"
+                            );
+                            break 'retry Err(error);
+                        }
+                        GiveUp::Cooldown { cooldown_ms } => {
+                            warn!(
+                                %exchange,
+                                attempt,
+                                cooldown_ms,
+                                action = "restarting backoff sequence after cooldown",
+                                "exhausted ReconnectPolicy attempts to initialise MarketStream"
+                            );
+                            attempt = 0;
+                            backoff = Some(Duration::from_millis(cooldown_ms));
+                            continue 'retry;
+                        }
+                    }
                 }
+
+                backoff = Some(policy.backoff(attempt + 1));
+                continue 'retry;
             }
         };
 
         // Consume Result<MarketEvent<T>, DataError> from MarketStream
-        while let Some(event_result) = stream.next().await {
+        loop {
+            let event_result = tokio::select! {
+                event_result = stream.next() => match event_result {
+                    Some(event_result) => event_result,
+                    None => break,
+                },
+                now = next_staleness_check(staleness_interval.as_mut()) => {
+                    let Some(monitor) = &mut staleness else {
+                        continue;
+                    };
+
+                    for (instrument, stale_for) in monitor.poll_stale(now) {
+                        warn!(
+                            %exchange,
+                            ?instrument,
+                            ?stale_for,
+                            "MarketStream instrument has not yielded a MarketEvent recently"
+                        );
+
+                        let event = Event::Stale {
+                            exchange,
+                            instrument,
+                            stale_for,
+                        };
+                        if send_event(&exchange_tx, event).is_err() {
+                            break 'retry Ok(());
+                        }
+                    }
+                    continue;
+                }
+                command = commands.next(), if commands_open => {
+                    let Some(command) = command else {
+                        commands_open = false;
+                        continue;
+                    };
+
+                    match command {
+                        Command::Subscribe(requested) => {
+                            // Filter out Subscriptions that are already active
+                            let mut active = subscriptions
+                                .iter()
+                                .map(subscription_id)
+                                .collect::<HashSet<_>>();
+                            let added = requested
+                                .into_iter()
+                                .filter(|subscription| active.insert(subscription_id(subscription)))
+                                .collect::<Vec<_>>();
+
+                            if added.is_empty() {
+                                continue;
+                            }
+
+                            info!(%exchange, subscriptions = ?added, "subscribing over existing MarketStream");
+                            if let Err(error) = stream.subscribe(&added).await {
+                                error!(
+                                    %exchange,
+                                    ?error,
+                                    action = "Subscriptions not added",
+                                    "failed to subscribe over existing MarketStream"
+                                );
+
+                                // Notify downstream of every instrument that is not consumed
+                                for subscription in &added {
+                                    let event = Event::SubscribeRejected {
+                                        exchange,
+                                        instrument: subscription.instrument.id().clone(),
+                                    };
+                                    if send_event(&exchange_tx, event).is_err() {
+                                        break 'retry Ok(());
+                                    }
+                                }
+                                continue;
+                            }
+
+                            // Subscriptions are only active once confirmed by the exchange
+                            if let Some(monitor) = &mut staleness {
+                                let now = Instant::now();
+                                added.iter().for_each(|subscription| {
+                                    monitor.record(subscription.instrument.id(), now)
+                                });
+                            }
+
+                            subscriptions.extend(added);
+                        }
+                        Command::Unsubscribe(requested) => {
+                            let requested = requested
+                                .iter()
+                                .map(subscription_id)
+                                .collect::<HashSet<_>>();
+                            let (removed, retained): (Vec<_>, Vec<_>) = std::mem::take(&mut subscriptions)
+                                .into_iter()
+                                .partition(|subscription| requested.contains(&subscription_id(subscription)));
+                            subscriptions = retained;
+
+                            if removed.is_empty() {
+                                continue;
+                            }
+
+                            info!(%exchange, subscriptions = ?removed, "unsubscribing over existing MarketStream");
+                            if let Err(error) = stream.unsubscribe(&removed) {
+                                error!(
+                                    %exchange,
+                                    ?error,
+                                    action = "unsubscribed locally",
+                                    "failed to unsubscribe over existing MarketStream"
+                                );
+                            }
+
+                            if let Some(monitor) = &mut staleness {
+                                removed.iter().for_each(|subscription| {
+                                    monitor.remove(subscription.instrument.id())
+                                });
+                            }
+                        }
+                    }
+                    continue;
+                }
+            };
+
             match event_result {
                 // If Ok: send MarketEvent<T> to exchange receiver
                 Ok(market_event) => {
-                    if let Err(error) = exchange_tx.send(market_event) {
-                        debug!(
-                            payload = ?error.0,
-                            why = "receiver dropped",
-                            action = "shutting down Stream",
-                            "failed to send Event<MarketData> to Exchange receiver"
+                    if let Some(monitor) = &mut staleness {
+                        monitor.record(&market_event.instrument, Instant::now());
+                    }
+
+                    if let Some(metrics) = &metrics {
+                        metrics.record_event(
+                            exchange,
+                            &market_event.instrument,
+                            market_event.exchange_time,
+                            market_event.received_time,
                         );
+                    }
+
+                    if send_event(&exchange_tx, Event::Item(market_event)).is_err() {
                         break 'retry Ok(());
                     }
                 }
@@ -100,6 +400,10 @@ where
 
                 // If non-terminal DataError: log & continue
                 Err(error) => {
+                    if let (Some(metrics), true) = (&metrics, error.is_parse_error()) {
+                        metrics.record_parse_error(exchange);
+                    }
+
                     warn!(
                         %exchange,
                         %error,
@@ -111,13 +415,62 @@ where
             }
         }
 
-        // If MarketStream ends unexpectedly, attempt re-connection after backoff_ms
+        // If MarketStream ends unexpectedly, attempt re-connection after backoff
         warn!(
             %exchange,
-            backoff_ms,
             action = "attempt re-connection after backoff",
             "exchange MarketStream unexpectedly ended"
         );
-        tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+        backoff = Some(policy.backoff(1));
+
+        // Notify downstream that the MarketStream disconnected
+        disconnected = true;
+        if send_event(&exchange_tx, Event::Disconnected(exchange)).is_err() {
+            break 'retry Ok(());
+        }
+    }
+}
+
+/// Determine the [`SubscriptionId`] of a [`Subscription`], used to identify it across
+/// [`Command`]s.
+fn subscription_id<Exchange, Instrument, Kind>(
+    subscription: &Subscription<Exchange, Instrument, Kind>,
+) -> SubscriptionId
+where
+    Exchange: Connector,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription).id()
+}
+
+/// Wait for the next staleness check, or forever if staleness detection is disabled.
+async fn next_staleness_check(interval: Option<&mut Interval>) -> Instant {
+    match interval {
+        Some(interval) => interval.tick().await,
+        None => std::future::pending().await,
     }
 }
+
+/// Send a lifecycle [`Event`] downstream, if it can be represented by the `Output`.
+///
+/// Returns an `Err` if the downstream receiver has been dropped.
+fn send_event<InstrumentId, T, Output>(
+    exchange_tx: &mpsc::UnboundedSender<Output>,
+    event: Event<InstrumentId, T>,
+) -> Result<(), ()>
+where
+    Output: StreamOutput<InstrumentId, T>,
+{
+    let Some(output) = Output::from_event(event) else {
+        return Ok(());
+    };
+
+    exchange_tx.send(output).map_err(|_| {
+        debug!(
+            why = "receiver dropped",
+            action = "shutting down Stream",
+            "failed to send Event<MarketData> to Exchange receiver"
+        );
+    })
+}
@@ -0,0 +1,281 @@
+use crate::{event::MarketEvent, exchange::ExchangeId};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, hash::Hash, time::Duration};
+use tokio::time::Instant;
+
+/// Stream lifecycle event yielded by a [`consume`](super::consumer::consume) loop, wrapping the
+/// consumed `T` items.
+///
+/// Connection level events are associated with an [`ExchangeId`], whereas data level events are
+/// associated with a specific `InstrumentId` on that exchange.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum Event<InstrumentId, T> {
+    /// The [`MarketStream`](crate::MarketStream) has been initialised for the first time.
+    Connected(ExchangeId),
+    /// The [`MarketStream`](crate::MarketStream) disconnected and is attempting to re-connect.
+    Disconnected(ExchangeId),
+    /// The [`MarketStream`](crate::MarketStream) re-connected and re-sent all subscriptions after
+    /// a disconnection. Items consumed either side of the disconnection may have a gap.
+    Resubscribed(ExchangeId),
+    /// The instrument state (eg/ L2 OrderBook) was rebuilt from a fresh snapshot after a
+    /// re-connection, so it is discontinuous with previously consumed items.
+    SnapshotReset {
+        exchange: ExchangeId,
+        instrument: InstrumentId,
+    },
+    /// No item has been consumed for the instrument for at least `stale_for`. Emitted at most once
+    /// until the next item for the instrument is consumed.
+    Stale {
+        exchange: ExchangeId,
+        instrument: InstrumentId,
+        stale_for: Duration,
+    },
+    /// A [`Subscription`](crate::subscription::Subscription) to the instrument requested over the
+    /// existing connection was rejected (or not confirmed) by the exchange, so it is not consumed.
+    SubscribeRejected {
+        exchange: ExchangeId,
+        instrument: InstrumentId,
+    },
+    Item(T),
+}
+
+impl<InstrumentId, T> From<T> for Event<InstrumentId, T> {
+    fn from(item: T) -> Self {
+        Self::Item(item)
+    }
+}
+
+impl<InstrumentId, T> Event<InstrumentId, T> {
+    /// Determine if this [`Event`] indicates consumed items can no longer be relied upon, either
+    /// because data may have been missed or because it is no longer arriving.
+    pub fn is_unhealthy(&self) -> bool {
+        matches!(
+            self,
+            Event::Disconnected(_) | Event::SnapshotReset { .. } | Event::Stale { .. }
+        )
+    }
+
+    /// Map the `T` of an [`Event::Item`] into an `O`.
+    pub fn map<F, O>(self, op: F) -> Event<InstrumentId, O>
+    where
+        F: FnOnce(T) -> O,
+    {
+        match self {
+            Event::Connected(exchange) => Event::Connected(exchange),
+            Event::Disconnected(exchange) => Event::Disconnected(exchange),
+            Event::Resubscribed(exchange) => Event::Resubscribed(exchange),
+            Event::SnapshotReset {
+                exchange,
+                instrument,
+            } => Event::SnapshotReset {
+                exchange,
+                instrument,
+            },
+            Event::Stale {
+                exchange,
+                instrument,
+                stale_for,
+            } => Event::Stale {
+                exchange,
+                instrument,
+                stale_for,
+            },
+            Event::SubscribeRejected {
+                exchange,
+                instrument,
+            } => Event::SubscribeRejected {
+                exchange,
+                instrument,
+            },
+            Event::Item(item) => Event::Item(op(item)),
+        }
+    }
+}
+
+/// Output that a [`consume`](super::consumer::consume) loop can send downstream.
+///
+/// Implemented for:
+/// - Plain [`MarketEvent`]s, which discard every lifecycle [`Event`].
+/// - Lifecycle [`Event`]s, which retain every [`Event`].
+pub trait StreamOutput<InstrumentId, T>: Sized {
+    /// Construct `Self` from a lifecycle [`Event`], returning `None` if it cannot be represented.
+    fn from_event(event: Event<InstrumentId, T>) -> Option<Self>;
+}
+
+impl<InstrumentId, T> StreamOutput<InstrumentId, MarketEvent<InstrumentId, T>>
+    for MarketEvent<InstrumentId, T>
+{
+    fn from_event(event: Event<InstrumentId, MarketEvent<InstrumentId, T>>) -> Option<Self> {
+        match event {
+            Event::Item(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+
+impl<InstrumentId, T> StreamOutput<InstrumentId, T> for Event<InstrumentId, T> {
+    fn from_event(event: Event<InstrumentId, T>) -> Option<Self> {
+        Some(event)
+    }
+}
+
+/// Tracks the time each instrument last consumed an item in order to detect stale data.
+#[derive(Clone, Debug)]
+pub struct StalenessMonitor<InstrumentId> {
+    pub stale_after: Duration,
+    pub instruments: HashMap<InstrumentId, InstrumentActivity>,
+}
+
+/// Last activity of an instrument tracked by the [`StalenessMonitor`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct InstrumentActivity {
+    pub last_item: Instant,
+    pub stale: bool,
+}
+
+impl<InstrumentId> StalenessMonitor<InstrumentId>
+where
+    InstrumentId: Clone + Eq + Hash,
+{
+    /// Construct a new [`Self`] that considers the provided instruments active as of `now`.
+    pub fn new<Iter>(stale_after: Duration, instruments: Iter, now: Instant) -> Self
+    where
+        Iter: IntoIterator<Item = InstrumentId>,
+    {
+        let activity = InstrumentActivity {
+            last_item: now,
+            stale: false,
+        };
+
+        Self {
+            stale_after,
+            instruments: instruments
+                .into_iter()
+                .map(|instrument| (instrument, activity))
+                .collect(),
+        }
+    }
+
+    /// Record that an item was consumed for the instrument at `now`.
+    pub fn record(&mut self, instrument: &InstrumentId, now: Instant) {
+        match self.instruments.get_mut(instrument) {
+            Some(activity) => {
+                activity.last_item = now;
+                activity.stale = false;
+            }
+            None => {
+                self.instruments.insert(
+                    instrument.clone(),
+                    InstrumentActivity {
+                        last_item: now,
+                        stale: false,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Stop tracking the instrument, eg/ after it has been unsubscribed from.
+    pub fn remove(&mut self, instrument: &InstrumentId) {
+        self.instruments.remove(instrument);
+    }
+
+    /// Reset every instrument to be considered active as of `now`.
+    pub fn reset(&mut self, now: Instant) {
+        self.instruments.values_mut().for_each(|activity| {
+            activity.last_item = now;
+            activity.stale = false;
+        })
+    }
+
+    /// Return every instrument that has newly become stale as of `now`, along with the duration it
+    /// has been stale for.
+    pub fn poll_stale(&mut self, now: Instant) -> Vec<(InstrumentId, Duration)> {
+        self.instruments
+            .iter_mut()
+            .filter_map(|(instrument, activity)| {
+                let stale_for = now.saturating_duration_since(activity.last_item);
+                if activity.stale || stale_for < self.stale_after {
+                    return None;
+                }
+
+                activity.stale = true;
+                Some((instrument.clone(), stale_for))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_staleness_monitor_poll_stale() {
+        let start = Instant::now();
+        let mut monitor = StalenessMonitor::new(Duration::from_secs(5), [1, 2], start);
+
+        // No instrument is stale before the stale_after duration has elapsed
+        assert!(monitor
+            .poll_stale(start + Duration::from_secs(4))
+            .is_empty());
+
+        // Instrument 2 consumes an item, so only instrument 1 becomes stale
+        monitor.record(&2, start + Duration::from_secs(4));
+        assert_eq!(
+            monitor.poll_stale(start + Duration::from_secs(6)),
+            vec![(1, Duration::from_secs(6))]
+        );
+
+        // Instrument 1 is only reported once until it consumes another item
+        assert!(monitor
+            .poll_stale(start + Duration::from_secs(7))
+            .is_empty());
+
+        // Instrument 1 becomes active again, then both instruments become stale
+        monitor.record(&1, start + Duration::from_secs(8));
+        let mut actual = monitor.poll_stale(start + Duration::from_secs(13));
+        actual.sort();
+        assert_eq!(
+            actual,
+            vec![(1, Duration::from_secs(5)), (2, Duration::from_secs(9))]
+        );
+
+        // Reset considers all instruments active again
+        monitor.reset(start + Duration::from_secs(14));
+        assert!(monitor
+            .poll_stale(start + Duration::from_secs(15))
+            .is_empty());
+
+        // Removed instruments are no longer reported as stale
+        monitor.remove(&1);
+        assert_eq!(
+            monitor.poll_stale(start + Duration::from_secs(20)),
+            vec![(2, Duration::from_secs(6))]
+        );
+    }
+
+    #[test]
+    fn test_stream_output_from_event() {
+        type Item = MarketEvent<u64, ()>;
+        let disconnected = Event::<u64, Item>::Disconnected(ExchangeId::Okx);
+        let stale = Event::<u64, Item>::Stale {
+            exchange: ExchangeId::Okx,
+            instrument: 1,
+            stale_for: Duration::from_secs(1),
+        };
+
+        assert_eq!(
+            <MarketEvent<u64, ()> as StreamOutput<u64, Item>>::from_event(disconnected.clone()),
+            None
+        );
+        assert_eq!(
+            <Event<u64, Item> as StreamOutput<u64, Item>>::from_event(disconnected.clone()),
+            Some(disconnected)
+        );
+        assert_eq!(
+            <Event<u64, Item> as StreamOutput<u64, Item>>::from_event(stale.clone()),
+            Some(stale)
+        );
+    }
+}
@@ -0,0 +1,472 @@
+use crate::exchange::ExchangeId;
+use barter_integration::metric::{Field, Metric, Tag, Value};
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{Display, Write},
+    hash::Hash,
+    sync::Arc,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+use tracing::warn;
+
+/// Default number of recent latency samples retained per instrument to calculate the latency
+/// percentiles.
+pub const DEFAULT_LATENCY_SAMPLES: usize = 1024;
+
+/// Latency percentiles included in every instrument [`Metric`].
+const LATENCY_PERCENTILES: [(&str, f64); 3] = [
+    ("latency_p50_ms", 0.50),
+    ("latency_p90_ms", 0.90),
+    ("latency_p99_ms", 0.99),
+];
+
+/// [`Field`]s that are monotonically increasing totals, typed as Prometheus counters. Every other
+/// [`Field`] is typed as a gauge.
+const COUNTER_FIELDS: [&str; 3] = ["messages", "reconnects", "parse_errors"];
+
+/// Shared data-feed health metrics recorded by [`consume`](super::consumer::consume) loops.
+///
+/// Tracks per exchange & instrument message rates and end-to-end latency (`received_time` minus
+/// `exchange_time`) percentiles, along with per exchange re-connection and parse error counts.
+/// Cloning is cheap and every clone records into the same underlying registry.
+#[derive(Debug)]
+pub struct StreamMetrics<InstrumentId> {
+    registry: Arc<Mutex<Registry<InstrumentId>>>,
+}
+
+impl<InstrumentId> Clone for StreamMetrics<InstrumentId> {
+    fn clone(&self) -> Self {
+        Self {
+            registry: Arc::clone(&self.registry),
+        }
+    }
+}
+
+impl<InstrumentId> Default for StreamMetrics<InstrumentId> {
+    fn default() -> Self {
+        Self::new(DEFAULT_LATENCY_SAMPLES, Utc::now())
+    }
+}
+
+#[derive(Debug)]
+struct Registry<InstrumentId> {
+    latency_samples: usize,
+    window_start: DateTime<Utc>,
+    instruments: HashMap<(ExchangeId, InstrumentId), InstrumentStats>,
+    exchanges: HashMap<ExchangeId, ExchangeStats>,
+}
+
+#[derive(Debug, Default)]
+struct InstrumentStats {
+    messages: u64,
+    window_messages: u64,
+    latencies_ms: VecDeque<f64>,
+}
+
+#[derive(Debug, Default)]
+struct ExchangeStats {
+    reconnects: u64,
+    parse_errors: u64,
+}
+
+impl<InstrumentId> StreamMetrics<InstrumentId> {
+    /// Construct a new [`Self`] that retains `latency_samples` recent latency samples per
+    /// instrument, with the first message rate window starting at `now`.
+    pub fn new(latency_samples: usize, now: DateTime<Utc>) -> Self {
+        Self {
+            registry: Arc::new(Mutex::new(Registry {
+                latency_samples: latency_samples.max(1),
+                window_start: now,
+                instruments: HashMap::new(),
+                exchanges: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Record that a [`MarketEvent`](crate::event::MarketEvent) was consumed.
+    pub fn record_event(
+        &self,
+        exchange: ExchangeId,
+        instrument: &InstrumentId,
+        exchange_time: DateTime<Utc>,
+        received_time: DateTime<Utc>,
+    ) where
+        InstrumentId: Clone + Eq + Hash,
+    {
+        let mut registry = self.registry.lock();
+        let latency_samples = registry.latency_samples;
+
+        let stats = registry
+            .instruments
+            .entry((exchange, instrument.clone()))
+            .or_default();
+
+        stats.messages += 1;
+        stats.window_messages += 1;
+
+        if stats.latencies_ms.len() == latency_samples {
+            stats.latencies_ms.pop_front();
+        }
+        let latency = received_time.signed_duration_since(exchange_time);
+        stats
+            .latencies_ms
+            .push_back(latency.num_microseconds().unwrap_or(i64::MAX) as f64 / 1_000.0);
+    }
+
+    /// Record that the exchange [`MarketStream`](crate::MarketStream) re-connected.
+    pub fn record_reconnect(&self, exchange: ExchangeId) {
+        self.registry
+            .lock()
+            .exchanges
+            .entry(exchange)
+            .or_default()
+            .reconnects += 1;
+    }
+
+    /// Record that an exchange message could not be parsed.
+    pub fn record_parse_error(&self, exchange: ExchangeId) {
+        self.registry
+            .lock()
+            .exchanges
+            .entry(exchange)
+            .or_default()
+            .parse_errors += 1;
+    }
+
+    /// Generate a [`Metric`] for every exchange & instrument as of `now`, and start a new message
+    /// rate window.
+    ///
+    /// Instrument [`Metric`]s are named "market_stream_instrument" and contain the total
+    /// `messages`, the `message_rate` per second since the previous snapshot, and the latency
+    /// percentiles of recent messages. Exchange [`Metric`]s are named "market_stream_exchange"
+    /// and contain the total `reconnects` and `parse_errors`.
+    pub fn snapshot(&self, now: DateTime<Utc>) -> Vec<Metric>
+    where
+        InstrumentId: Display,
+    {
+        self.metrics(now, true)
+    }
+
+    /// Generate a [`Metric`] for every exchange & instrument as of `now`, without starting a new
+    /// message rate window. Reading is therefore invisible to consumers of [`Self::snapshot`].
+    ///
+    /// See [`Self::snapshot`] for the contents of each [`Metric`].
+    pub fn read(&self, now: DateTime<Utc>) -> Vec<Metric>
+    where
+        InstrumentId: Display,
+    {
+        self.metrics(now, false)
+    }
+
+    fn metrics(&self, now: DateTime<Utc>, start_window: bool) -> Vec<Metric>
+    where
+        InstrumentId: Display,
+    {
+        let mut registry = self.registry.lock();
+        let window_secs = now
+            .signed_duration_since(registry.window_start)
+            .num_milliseconds() as f64
+            / 1_000.0;
+        if start_window {
+            registry.window_start = now;
+        }
+        let time = now.timestamp_millis() as u64;
+
+        let mut metrics = registry
+            .instruments
+            .iter_mut()
+            .map(|((exchange, instrument), stats)| {
+                let message_rate = match window_secs > 0.0 {
+                    true => stats.window_messages as f64 / window_secs,
+                    false => 0.0,
+                };
+                if start_window {
+                    stats.window_messages = 0;
+                }
+
+                let mut fields = vec![
+                    Field::new("messages", stats.messages),
+                    Field::new("message_rate", message_rate),
+                ];
+
+                let mut latencies = stats.latencies_ms.iter().copied().collect::<Vec<_>>();
+                latencies.sort_by(f64::total_cmp);
+                fields.extend(LATENCY_PERCENTILES.iter().filter_map(|(key, percentile)| {
+                    nearest_rank(&latencies, *percentile).map(|latency| Field::new(key, latency))
+                }));
+
+                Metric {
+                    name: "market_stream_instrument",
+                    time,
+                    tags: vec![
+                        Tag::new("exchange", exchange.as_str()),
+                        Tag::new("instrument", instrument.to_string()),
+                    ],
+                    fields,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        metrics.extend(registry.exchanges.iter().map(|(exchange, stats)| Metric {
+            name: "market_stream_exchange",
+            time,
+            tags: vec![Tag::new("exchange", exchange.as_str())],
+            fields: vec![
+                Field::new("reconnects", stats.reconnects),
+                Field::new("parse_errors", stats.parse_errors),
+            ],
+        }));
+
+        metrics
+    }
+
+    /// [`Self::read`] the [`Metric`]s as of now, rendered in the Prometheus text exposition
+    /// format.
+    pub fn prometheus(&self) -> String
+    where
+        InstrumentId: Display,
+    {
+        render_prometheus(&self.read(Utc::now()))
+    }
+
+    /// Serve [`Self::prometheus`] to every connection accepted by the provided [`TcpListener`].
+    ///
+    /// This is a minimal stand-in for a Prometheus scrape endpoint: every HTTP request is
+    /// answered with the current metrics, regardless of the request path.
+    pub async fn serve_prometheus(self, listener: TcpListener) -> std::io::Result<()>
+    where
+        InstrumentId: Display,
+    {
+        loop {
+            let (mut socket, address) = listener.accept().await?;
+            let body = self.prometheus();
+
+            tokio::spawn(async move {
+                // Request contents are irrelevant, but must be read before responding
+                let mut request = [0u8; 1024];
+                let _ = socket.read(&mut request).await;
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\n\
+                    Content-Type: text/plain; version=0.0.4\r\n\
+                    Content-Length: {}\r\n\
+                    Connection: close\r\n\r\n{body}",
+                    body.len()
+                );
+
+                if let Err(error) = socket.write_all(response.as_bytes()).await {
+                    warn!(%address, %error, "failed to write Prometheus metrics response");
+                }
+            });
+        }
+    }
+}
+
+/// Render [`Metric`]s in the Prometheus text exposition format, with one sample per [`Field`]
+/// named `<metric>_<field>` and labelled by the [`Metric`] [`Tag`]s.
+///
+/// Samples are grouped by name, each group preceded by a `# TYPE` line. Monotonic totals (eg/
+/// `messages`) are typed as counters and every other [`Field`] as a gauge. Non-numeric
+/// [`Field`]s are skipped.
+pub fn render_prometheus(metrics: &[Metric]) -> String {
+    // Samples of every metric family, in the order each family is first encountered
+    let mut families = Vec::<(String, &str, String)>::new();
+
+    for metric in metrics {
+        let labels = metric
+            .tags
+            .iter()
+            .map(|tag| format!("{}=\"{}\"", tag.key, escape_label_value(&tag.value)))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        for field in &metric.fields {
+            let value = match &field.value {
+                Value::Float(value) => value.to_string(),
+                Value::Int(value) => value.to_string(),
+                Value::UInt(value) => value.to_string(),
+                Value::Bool(value) => u8::from(*value).to_string(),
+                Value::String(_) => continue,
+            };
+
+            let name = format!("{}_{}", metric.name, field.key);
+            let samples = match families.iter_mut().find(|(family, ..)| *family == name) {
+                Some((.., samples)) => samples,
+                None => {
+                    let kind = match COUNTER_FIELDS.contains(&field.key) {
+                        true => "counter",
+                        false => "gauge",
+                    };
+                    families.push((name.clone(), kind, String::new()));
+                    &mut families.last_mut().expect("family just pushed").2
+                }
+            };
+            let _ = writeln!(samples, "{name}{{{labels}}} {value}");
+        }
+    }
+
+    families
+        .into_iter()
+        .fold(String::new(), |mut output, (name, kind, samples)| {
+            let _ = writeln!(output, "# TYPE {name} {kind}");
+            output.push_str(&samples);
+            output
+        })
+}
+
+/// Escape a Prometheus label value.
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Determine the nearest-rank percentile of the provided sorted values.
+fn nearest_rank(sorted: &[f64], percentile: f64) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+
+    let rank = (percentile * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_nearest_rank() {
+        struct TestCase {
+            sorted: Vec<f64>,
+            percentile: f64,
+            expected: Option<f64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no samples
+                sorted: vec![],
+                percentile: 0.5,
+                expected: None,
+            },
+            TestCase {
+                // TC1: median of odd number of samples
+                sorted: vec![1.0, 2.0, 3.0],
+                percentile: 0.5,
+                expected: Some(2.0),
+            },
+            TestCase {
+                // TC2: p90 of 10 samples
+                sorted: (1..=10).map(f64::from).collect(),
+                percentile: 0.9,
+                expected: Some(9.0),
+            },
+            TestCase {
+                // TC3: p99 of few samples is the maximum
+                sorted: vec![1.0, 2.0, 3.0],
+                percentile: 0.99,
+                expected: Some(3.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = nearest_rank(&test.sorted, test.percentile);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_stream_metrics_snapshot() {
+        let start = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let metrics = StreamMetrics::<u64>::new(2, start);
+
+        // Three messages for instrument 1, with only the two most recent latencies retained
+        for latency_ms in [50, 10, 30] {
+            metrics.record_event(
+                ExchangeId::Okx,
+                &1,
+                start,
+                start + Duration::milliseconds(latency_ms),
+            );
+        }
+        metrics.record_reconnect(ExchangeId::Okx);
+        metrics.record_parse_error(ExchangeId::Okx);
+        metrics.record_parse_error(ExchangeId::Okx);
+
+        let snapshot = metrics.snapshot(start + Duration::seconds(2));
+        assert_eq!(snapshot.len(), 2);
+
+        let instrument = &snapshot[0];
+        assert_eq!(instrument.name, "market_stream_instrument");
+        assert_eq!(
+            instrument.tags,
+            vec![Tag::new("exchange", "okx"), Tag::new("instrument", "1")]
+        );
+        assert_eq!(
+            instrument.fields,
+            vec![
+                Field::new("messages", 3u64),
+                Field::new("message_rate", 1.5),
+                Field::new("latency_p50_ms", 10.0),
+                Field::new("latency_p90_ms", 30.0),
+                Field::new("latency_p99_ms", 30.0),
+            ]
+        );
+
+        let exchange = &snapshot[1];
+        assert_eq!(exchange.name, "market_stream_exchange");
+        assert_eq!(
+            exchange.fields,
+            vec![
+                Field::new("reconnects", 1u64),
+                Field::new("parse_errors", 2u64)
+            ]
+        );
+
+        // Message rate window restarts after every snapshot
+        let snapshot = metrics.snapshot(start + Duration::seconds(4));
+        assert_eq!(snapshot[0].fields[1], Field::new("message_rate", 0.0));
+
+        // Reading does not restart the message rate window
+        metrics.record_event(ExchangeId::Okx, &1, start, start);
+        let read = metrics.read(start + Duration::seconds(5));
+        assert_eq!(read[0].fields[1], Field::new("message_rate", 1.0));
+        let snapshot = metrics.snapshot(start + Duration::seconds(6));
+        assert_eq!(snapshot[0].fields[1], Field::new("message_rate", 0.5));
+    }
+
+    #[test]
+    fn test_render_prometheus() {
+        let metric = |instrument: &str, messages: u64| Metric {
+            name: "market_stream_instrument",
+            time: 0,
+            tags: vec![
+                Tag::new("exchange", "okx"),
+                Tag::new("instrument", instrument.to_string()),
+            ],
+            fields: vec![
+                Field::new("messages", messages),
+                Field::new("message_rate", 1.5),
+                Field::new("note", "skipped".to_string()),
+            ],
+        };
+
+        assert_eq!(
+            render_prometheus(&[metric("say \"hi\"", 3), metric("btc", 4)]),
+            "# TYPE market_stream_instrument_messages counter\n\
+            market_stream_instrument_messages{exchange=\"okx\",instrument=\"say \\\"hi\\\"\"} 3\n\
+            market_stream_instrument_messages{exchange=\"okx\",instrument=\"btc\"} 4\n\
+            # TYPE market_stream_instrument_message_rate gauge\n\
+            market_stream_instrument_message_rate{exchange=\"okx\",instrument=\"say \\\"hi\\\"\"} 1.5\n\
+            market_stream_instrument_message_rate{exchange=\"okx\",instrument=\"btc\"} 1.5\n"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use self::builder::{multi::MultiStreamBuilder, StreamBuilder};
-use crate::{exchange::ExchangeId, subscription::SubscriptionKind};
+use crate::{event::MarketEvent, exchange::ExchangeId, subscription::SubscriptionKind};
+use barter_integration::model::instrument::Instrument;
 use std::collections::HashMap;
 use tokio::sync::mpsc;
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamMap};
@@ -13,6 +14,18 @@ pub mod builder;
 /// to drive a re-connecting [`MarketStream`](super::MarketStream).
 pub mod consumer;
 
+/// Re-connection policy used by the [`consume`](consumer::consume) loop to drive a re-connecting
+/// [`MarketStream`](super::MarketStream).
+pub mod reconnect;
+
+/// Lifecycle events (connection state, snapshot resets & staleness) optionally yielded by the
+/// [`consume`](consumer::consume) loop alongside consumed [`MarketEvent`]s.
+pub mod lifecycle;
+
+/// Data-feed health metrics (message rates, latency percentiles, re-connections & parse errors)
+/// optionally recorded by the [`consume`](consumer::consume) loop.
+pub mod metric;
+
 /// Ergonomic collection of exchange [`MarketEvent<T>`](crate::event::MarketEvent) receivers.
 #[derive(Debug)]
 pub struct Streams<T> {
@@ -29,6 +42,17 @@ impl<T> Streams<T> {
         StreamBuilder::<Kind>::new()
     }
 
+    /// Construct a [`StreamBuilder`] for configuring new
+    /// [`lifecycle::Event<MarketEvent<SubscriptionKind::Event>>`](lifecycle::Event) [`Streams`],
+    /// which also yield connection, snapshot reset & staleness lifecycle events.
+    pub fn builder_with_lifecycle<Kind>(
+    ) -> StreamBuilder<Kind, lifecycle::Event<Instrument, MarketEvent<Instrument, Kind::Event>>>
+    where
+        Kind: SubscriptionKind,
+    {
+        StreamBuilder::new()
+    }
+
     /// Construct a [`MultiStreamBuilder`] for configuring new
     /// [`MarketEvent<T>`](crate::event::MarketEvent) [`Streams`].
     pub fn builder_multi() -> MultiStreamBuilder<T> {
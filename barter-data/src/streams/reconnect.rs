@@ -0,0 +1,162 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Configures how a [`consume`](super::consumer::consume) loop re-initialises a
+/// [`MarketStream`](crate::MarketStream) after it disconnects or fails to initialise.
+///
+/// The backoff before re-connection attempt `n` (starting at 1) is calculated as:
+/// `min(backoff_ms_initial * backoff_multiplier^(n - 1), backoff_ms_max) + jitter`, where the
+/// jitter is sampled uniformly from `0..=jitter_ms_max`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ReconnectPolicy {
+    /// Backoff before the first re-connection attempt.
+    pub backoff_ms_initial: u64,
+    /// Factor the backoff is multiplied by after every consecutive failed attempt.
+    pub backoff_multiplier: u32,
+    /// Upper bound on the (pre-jitter) backoff.
+    pub backoff_ms_max: u64,
+    /// Maximum random jitter added to every backoff, used to avoid many connections re-connecting
+    /// in lockstep.
+    pub jitter_ms_max: u64,
+    /// Maximum number of consecutive failed attempts before the [`GiveUp`] behaviour is actioned.
+    /// `None` retries indefinitely.
+    pub max_attempts: Option<u32>,
+    /// Behaviour once `max_attempts` consecutive attempts have failed.
+    pub give_up: GiveUp,
+}
+
+/// Behaviour of a [`consume`](super::consumer::consume) loop once the
+/// [`ReconnectPolicy::max_attempts`] have been exhausted.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum GiveUp {
+    /// Stop the consumer loop and return the last [`DataError`](crate::error::DataError). This
+    /// closes the associated stream once every other sender has been dropped.
+    Terminate,
+    /// Wait for `cooldown_ms`, reset the attempt counter, and restart the backoff sequence.
+    Cooldown { cooldown_ms: u64 },
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            backoff_ms_initial: super::consumer::STARTING_RECONNECT_BACKOFF_MS,
+            backoff_multiplier: 2,
+            backoff_ms_max: 60_000,
+            jitter_ms_max: 0,
+            max_attempts: None,
+            give_up: GiveUp::Terminate,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Calculate the backoff (excluding jitter) before re-connection attempt number `attempt`.
+    pub fn backoff_ms(&self, attempt: u32) -> u64 {
+        let exponent = attempt.saturating_sub(1);
+        let multiplier = (self.backoff_multiplier as u64).saturating_pow(exponent);
+
+        self.backoff_ms_initial
+            .saturating_mul(multiplier)
+            .min(self.backoff_ms_max)
+    }
+
+    /// Calculate the [`Duration`] to wait before re-connection attempt number `attempt`,
+    /// including a randomly sampled jitter.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let jitter_ms = match self.jitter_ms_max {
+            0 => 0,
+            max => rand::thread_rng().gen_range(0..=max),
+        };
+
+        Duration::from_millis(self.backoff_ms(attempt).saturating_add(jitter_ms))
+    }
+
+    /// Determine if `attempt` consecutive failed attempts exhausts this policy.
+    pub fn is_exhausted(&self, attempt: u32) -> bool {
+        self.max_attempts
+            .is_some_and(|max_attempts| attempt >= max_attempts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reconnect_policy_backoff_ms() {
+        struct TestCase {
+            policy: ReconnectPolicy,
+            attempt: u32,
+            expected: u64,
+        }
+
+        let policy = ReconnectPolicy {
+            backoff_ms_initial: 100,
+            backoff_multiplier: 2,
+            backoff_ms_max: 1_000,
+            ..Default::default()
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: first attempt uses initial backoff
+                policy,
+                attempt: 1,
+                expected: 100,
+            },
+            TestCase {
+                // TC1: third attempt doubles twice
+                policy,
+                attempt: 3,
+                expected: 400,
+            },
+            TestCase {
+                // TC2: backoff is capped at backoff_ms_max
+                policy,
+                attempt: 5,
+                expected: 1_000,
+            },
+            TestCase {
+                // TC3: large attempt number saturates rather than overflowing
+                policy,
+                attempt: u32::MAX,
+                expected: 1_000,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.policy.backoff_ms(test.attempt);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_reconnect_policy_backoff_jitter_bounds() {
+        let policy = ReconnectPolicy {
+            backoff_ms_initial: 100,
+            backoff_multiplier: 2,
+            backoff_ms_max: 1_000,
+            jitter_ms_max: 50,
+            ..Default::default()
+        };
+
+        for _ in 0..100 {
+            let backoff = policy.backoff(2).as_millis() as u64;
+            assert!((200..=250).contains(&backoff), "{backoff} out of bounds");
+        }
+    }
+
+    #[test]
+    fn test_reconnect_policy_is_exhausted() {
+        let unlimited = ReconnectPolicy::default();
+        assert!(!unlimited.is_exhausted(u32::MAX));
+
+        let limited = ReconnectPolicy {
+            max_attempts: Some(3),
+            ..Default::default()
+        };
+        assert!(!limited.is_exhausted(2));
+        assert!(limited.is_exhausted(3));
+    }
+}
@@ -56,6 +56,7 @@ pub struct OrderBooksL2;
 
 impl SubscriptionKind for OrderBooksL2 {
     type Event = OrderBook;
+    const SNAPSHOT_BASED: bool = true;
 }
 
 /// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields level 3 [`OrderBook`]
@@ -68,6 +69,7 @@ pub struct OrderBooksL3;
 
 impl SubscriptionKind for OrderBooksL3 {
     type Event = OrderBook;
+    const SNAPSHOT_BASED: bool = true;
 }
 
 /// Normalised Barter [`OrderBook`] snapshot.
@@ -38,6 +38,10 @@ where
     Self: Debug + Clone,
 {
     type Event: Debug;
+
+    /// Whether the [`Self::Event`] state is rebuilt from a fresh snapshot every time the
+    /// associated [`MarketStream`](crate::MarketStream) is initialised (eg/ L2 OrderBooks).
+    const SNAPSHOT_BASED: bool = false;
 }
 
 /// Barter [`Subscription`] used to subscribe to a [`SubscriptionKind`] for a particular exchange
@@ -74,35 +74,62 @@ where
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
         map: Map<Instrument>,
     ) -> Result<Self, DataError> {
-        // Initialise InstrumentOrderBooks for all Subscriptions
-        let (sub_ids, init_book_requests): (Vec<_>, Vec<_>) = map
-            .0
-            .into_iter()
-            .map(|(sub_id, instrument)| {
-                (
-                    sub_id,
-                    Updater::init::<Exchange, Kind>(ws_sink_tx.clone(), instrument),
-                )
-            })
-            .unzip();
-
-        // Await all initial OrderBook snapshot requests
-        let init_order_books = futures::future::join_all(init_book_requests)
-            .await
-            .into_iter()
-            .collect::<Result<Vec<InstrumentOrderBook<Instrument, Updater>>, DataError>>()?;
-
-        // Construct OrderBookMap if all requests successful
-        let book_map = sub_ids
-            .into_iter()
-            .zip(init_order_books.into_iter())
-            .collect::<Map<InstrumentOrderBook<Instrument, Updater>>>();
-
         Ok(Self {
-            book_map,
+            book_map: init_book_map::<Exchange, Kind, Updater>(ws_sink_tx, map).await?,
             phantom: PhantomData,
         })
     }
+
+    async fn insert(
+        &mut self,
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        map: Map<Instrument>,
+    ) -> Result<(), DataError> {
+        let book_map = init_book_map::<Exchange, Kind, Updater>(ws_sink_tx, map).await?;
+        self.book_map.0.extend(book_map.0);
+        Ok(())
+    }
+
+    fn remove(&mut self, subscription_ids: &[SubscriptionId]) {
+        for subscription_id in subscription_ids {
+            self.book_map.0.remove(subscription_id);
+        }
+    }
+}
+
+/// Initialise an [`InstrumentOrderBook`] for every [`Instrument`] in the provided [`Map`].
+async fn init_book_map<Exchange, Kind, Updater>(
+    ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+    map: Map<Instrument>,
+) -> Result<Map<InstrumentOrderBook<Instrument, Updater>>, DataError>
+where
+    Exchange: Send,
+    Kind: Send,
+    Updater: OrderBookUpdater,
+{
+    // Initialise InstrumentOrderBooks for all Subscriptions
+    let (sub_ids, init_book_requests): (Vec<_>, Vec<_>) = map
+        .0
+        .into_iter()
+        .map(|(sub_id, instrument)| {
+            (
+                sub_id,
+                Updater::init::<Exchange, Kind>(ws_sink_tx.clone(), instrument),
+            )
+        })
+        .unzip();
+
+    // Await all initial OrderBook snapshot requests
+    let init_order_books = futures::future::join_all(init_book_requests)
+        .await
+        .into_iter()
+        .collect::<Result<Vec<InstrumentOrderBook<Instrument, Updater>>, DataError>>()?;
+
+    // Construct OrderBookMap if all requests successful
+    Ok(sub_ids
+        .into_iter()
+        .zip(init_order_books)
+        .collect::<Map<InstrumentOrderBook<Instrument, Updater>>>())
 }
 
 impl<Exchange, InstrumentId, Kind, Updater> Transformer
@@ -4,7 +4,7 @@ use crate::{
     subscription::{Map, SubscriptionKind},
 };
 use async_trait::async_trait;
-use barter_integration::{protocol::websocket::WsMessage, Transformer};
+use barter_integration::{model::SubscriptionId, protocol::websocket::WsMessage, Transformer};
 use tokio::sync::mpsc;
 
 /// Generic OrderBook [`ExchangeTransformer`]s.
@@ -29,4 +29,18 @@ where
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
         instrument_map: Map<InstrumentId>,
     ) -> Result<Self, DataError>;
+
+    /// Insert the `instrument_map` of [`Subscription`](crate::subscription::Subscription)s
+    /// actioned over the existing connection, so their messages can be identified.
+    ///
+    /// The [`mpsc::UnboundedSender`] can be used by [`Self`] to send messages back to the exchange.
+    async fn insert(
+        &mut self,
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<InstrumentId>,
+    ) -> Result<(), DataError>;
+
+    /// Remove the [`SubscriptionId`]s of [`Subscription`](crate::subscription::Subscription)s
+    /// that have been unsubscribed from over the existing connection.
+    fn remove(&mut self, subscription_ids: &[SubscriptionId]);
 }
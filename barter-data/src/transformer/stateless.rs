@@ -29,7 +29,7 @@ where
     Exchange: Connector + Send,
     InstrumentId: Clone + Send,
     Kind: SubscriptionKind + Send,
-    Input: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    Input: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de> + Send,
     MarketIter<InstrumentId, Kind::Event>: From<(ExchangeId, InstrumentId, Input)>,
 {
     async fn new(
@@ -41,6 +41,21 @@ where
             phantom: PhantomData,
         })
     }
+
+    async fn insert(
+        &mut self,
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<InstrumentId>,
+    ) -> Result<(), DataError> {
+        self.instrument_map.0.extend(instrument_map.0);
+        Ok(())
+    }
+
+    fn remove(&mut self, subscription_ids: &[SubscriptionId]) {
+        for subscription_id in subscription_ids {
+            self.instrument_map.0.remove(subscription_id);
+        }
+    }
 }
 
 impl<Exchange, InstrumentId, Kind, Input> Transformer
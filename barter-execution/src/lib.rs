@@ -49,6 +49,10 @@ pub mod execution;
 /// Simulated Exchange and it's associated simulated [`ExecutionClient`].
 pub mod simulated;
 
+/// Order manager that maintains the desired two-sided quotes of a market-making strategy by
+/// diffing them against resting orders.
+pub mod order_manager;
+
 /// Defines the communication with the exchange. Each exchange integration requires it's own
 /// implementation.
 #[async_trait]
@@ -11,6 +11,7 @@ use uuid::Uuid;
 
 pub mod balance;
 pub mod order;
+pub mod quote;
 pub mod trade;
 
 /// Normalised Barter [`AccountEvent`] containing metadata about the included
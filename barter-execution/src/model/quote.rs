@@ -0,0 +1,50 @@
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use serde::{Deserialize, Serialize};
+
+/// Desired resting limit order at a single price level of a [`Quotes`] side.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Quote {
+    pub price: f64,
+    pub quantity: f64,
+}
+
+impl Quote {
+    pub fn new(price: f64, quantity: f64) -> Self {
+        Self { price, quantity }
+    }
+}
+
+/// Desired two-sided quotes for a market, produced by a market-making strategy. Each side is
+/// ordered best level first (ie/ highest bid & lowest ask). An empty side withdraws every
+/// resting order on that side.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Quotes {
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    pub bids: Vec<Quote>,
+    pub asks: Vec<Quote>,
+}
+
+impl Quotes {
+    /// Construct [`Quotes`] for the provided market without any levels on either side.
+    pub fn empty<E, I>(exchange: E, instrument: I) -> Self
+    where
+        E: Into<Exchange>,
+        I: Into<Instrument>,
+    {
+        Self {
+            exchange: exchange.into(),
+            instrument: instrument.into(),
+            bids: vec![],
+            asks: vec![],
+        }
+    }
+
+    /// Return the desired [`Quote`] levels of the provided [`Side`].
+    pub fn side(&self, side: Side) -> &[Quote] {
+        match side {
+            Side::Buy => &self.bids,
+            Side::Sell => &self.asks,
+        }
+    }
+}
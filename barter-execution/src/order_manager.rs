@@ -0,0 +1,431 @@
+use crate::{
+    model::{
+        order::{Cancelled, Open, Order, OrderId, OrderKind, RequestCancel, RequestOpen},
+        quote::{Quote, Quotes},
+        AccountEvent, AccountEventKind, ClientOrderId,
+    },
+    ExecutionClient, ExecutionError,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Configuration for constructing an [`OrderManager`] via the new() constructor method.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    /// [`OrderKind`] of every order placed (eg/ [`OrderKind::PostOnly`]).
+    pub kind: OrderKind,
+    /// Maximum absolute price difference between a desired [`Quote`] & it's resting order before
+    /// the resting order is amended.
+    pub price_tolerance: f64,
+    /// Maximum absolute difference between a desired [`Quote`] quantity & it's resting order
+    /// remaining quantity before the resting order is amended.
+    pub quantity_tolerance: f64,
+}
+
+/// Requests required to transition the resting orders of a market to the desired [`Quotes`].
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct QuoteDiff {
+    /// Orders to place at levels without a resting order.
+    pub place: Vec<Order<RequestOpen>>,
+    /// Resting orders to cancel at levels that are no longer quoted.
+    pub cancel: Vec<Order<RequestCancel>>,
+    /// Resting orders to amend since they drifted beyond tolerance of their desired [`Quote`].
+    pub amend: Vec<Amend>,
+}
+
+impl QuoteDiff {
+    /// Determines if the resting orders already match the desired [`Quotes`].
+    pub fn is_empty(&self) -> bool {
+        self.place.is_empty() && self.cancel.is_empty() && self.amend.is_empty()
+    }
+}
+
+/// Request to amend a resting order. Executed as a cancel followed by a replacement open, since
+/// [`ExecutionClient`] has no native amend.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Amend {
+    pub cancel: Order<RequestCancel>,
+    pub replace: Order<RequestOpen>,
+}
+
+/// Outcome of executing a [`QuoteDiff`] via an [`OrderManager`].
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct QuoteUpdate {
+    pub opened: Vec<Order<Open>>,
+    pub cancelled: Vec<Order<Cancelled>>,
+    pub errors: Vec<ExecutionError>,
+}
+
+/// Manages the resting orders of a single market on behalf of a market-making strategy.
+///
+/// Each update diffs the desired [`Quotes`] against the tracked resting orders, and executes the
+/// resulting place, cancel & amend requests via the [`ExecutionClient`]. Resting orders are kept
+/// up to date with fills & cancels via [`AccountEvent`]s, and can be re-synchronised with the
+/// exchange at any time.
+#[derive(Debug)]
+pub struct OrderManager<Client>
+where
+    Client: ExecutionClient,
+{
+    config: Config,
+    client: Client,
+    resting: Vec<Order<Open>>,
+}
+
+impl<Client> OrderManager<Client>
+where
+    Client: ExecutionClient,
+{
+    /// Constructs a new [`OrderManager`] component using the provided configuration struct &
+    /// [`ExecutionClient`].
+    pub fn new(config: Config, client: Client) -> Self {
+        Self {
+            config,
+            client,
+            resting: vec![],
+        }
+    }
+
+    /// Return the tracked resting orders of the market.
+    pub fn resting(&self) -> &[Order<Open>] {
+        &self.resting
+    }
+
+    /// Determine the [`QuoteDiff`] required to transition the tracked resting orders to the
+    /// desired [`Quotes`].
+    pub fn diff(&self, quotes: &Quotes) -> QuoteDiff {
+        let mut diff = QuoteDiff::default();
+
+        for side in [Side::Buy, Side::Sell] {
+            self.diff_side(side, quotes.side(side), &mut diff);
+        }
+
+        diff
+    }
+
+    /// Diff the desired [`Quote`] levels of one [`Side`] against it's resting orders.
+    ///
+    /// Resting orders within tolerance of a desired level are kept. Remaining resting orders are
+    /// amended to the remaining desired levels (best first), with any surplus levels placed &
+    /// surplus resting orders cancelled.
+    fn diff_side(&self, side: Side, desired: &[Quote], diff: &mut QuoteDiff) {
+        let mut resting = self
+            .resting
+            .iter()
+            .filter(|order| order.side == side)
+            .collect::<Vec<_>>();
+
+        // Sort resting orders best first (ie/ highest bid & lowest ask)
+        resting.sort_by(|a, b| match side {
+            Side::Buy => b.state.price.total_cmp(&a.state.price),
+            Side::Sell => a.state.price.total_cmp(&b.state.price),
+        });
+
+        // Keep resting orders that are within tolerance of a desired level
+        let mut unmatched = Vec::with_capacity(desired.len());
+        for quote in desired {
+            match resting
+                .iter()
+                .position(|order| self.within_tolerance(quote, order))
+            {
+                Some(index) => {
+                    resting.remove(index);
+                }
+                None => unmatched.push(quote),
+            }
+        }
+
+        let mut resting = resting.into_iter();
+        for quote in unmatched {
+            match resting.next() {
+                Some(order) => diff.amend.push(Amend {
+                    cancel: request_cancel(order),
+                    replace: self.request_open(side, quote),
+                }),
+                None => diff.place.push(self.request_open(side, quote)),
+            }
+        }
+
+        diff.cancel.extend(resting.map(request_cancel));
+    }
+
+    /// Determines if a resting order is within tolerance of it's desired [`Quote`].
+    fn within_tolerance(&self, quote: &Quote, order: &Order<Open>) -> bool {
+        (quote.price - order.state.price).abs() <= self.config.price_tolerance
+            && (quote.quantity - order.state.remaining_quantity()).abs()
+                <= self.config.quantity_tolerance
+    }
+
+    /// Build an [`Order<RequestOpen>`] for a desired [`Quote`].
+    fn request_open(&self, side: Side, quote: &Quote) -> Order<RequestOpen> {
+        Order {
+            exchange: self.config.exchange.clone(),
+            instrument: self.config.instrument.clone(),
+            cid: ClientOrderId(Uuid::new_v4()),
+            side,
+            state: RequestOpen {
+                kind: self.config.kind,
+                price: quote.price,
+                quantity: quote.quantity,
+            },
+        }
+    }
+
+    /// Transition the resting orders of the market to the desired [`Quotes`] by executing the
+    /// [`QuoteDiff`] via the [`ExecutionClient`].
+    ///
+    /// Cancels (including the cancel leg of amends) are executed before opens, and an amended
+    /// order is only replaced if it's cancel succeeded, since it may otherwise have been filled.
+    pub async fn update(&mut self, quotes: &Quotes) -> QuoteUpdate {
+        let mut update = QuoteUpdate::default();
+
+        let diff = self.diff(quotes);
+        if diff.is_empty() {
+            return update;
+        }
+
+        // Cancel resting orders, with the cancel leg of each amend first
+        let (amend_cancels, replacements): (Vec<_>, Vec<_>) = diff
+            .amend
+            .into_iter()
+            .map(|amend| (amend.cancel, amend.replace))
+            .unzip();
+
+        let cancel_requests = amend_cancels
+            .into_iter()
+            .chain(diff.cancel)
+            .collect::<Vec<_>>();
+
+        let mut cancel_results = if cancel_requests.is_empty() {
+            vec![]
+        } else {
+            self.client.cancel_orders(cancel_requests).await
+        }
+        .into_iter();
+
+        // Replace amended orders only if their cancel succeeded
+        let mut open_requests = Vec::with_capacity(replacements.len() + diff.place.len());
+        for (replace, result) in replacements.into_iter().zip(cancel_results.by_ref()) {
+            if self.apply_cancel(result, &mut update) {
+                open_requests.push(replace);
+            }
+        }
+        for result in cancel_results {
+            self.apply_cancel(result, &mut update);
+        }
+
+        // Open replacement & new orders
+        open_requests.extend(diff.place);
+        if !open_requests.is_empty() {
+            for result in self.client.open_orders(open_requests).await {
+                match result {
+                    Ok(open) => {
+                        self.resting.push(open.clone());
+                        update.opened.push(open);
+                    }
+                    Err(error) => update.errors.push(error),
+                }
+            }
+        }
+
+        update
+    }
+
+    /// Apply the result of a cancel request to the tracked resting orders, returning true if the
+    /// order was cancelled.
+    fn apply_cancel(
+        &mut self,
+        result: Result<Order<Cancelled>, ExecutionError>,
+        update: &mut QuoteUpdate,
+    ) -> bool {
+        match result {
+            Ok(cancelled) => {
+                self.remove_resting(&cancelled.instrument, &cancelled.state.id);
+                update.cancelled.push(cancelled);
+                true
+            }
+            Err(error) => {
+                update.errors.push(error);
+                false
+            }
+        }
+    }
+
+    /// Update the tracked resting orders from an [`AccountEvent`], applying fills & cancels of
+    /// the market's orders.
+    pub fn update_from_account(&mut self, event: &AccountEvent) {
+        match &event.kind {
+            AccountEventKind::Trade(trade) => {
+                let Some(index) = self.resting.iter().position(|order| {
+                    order.instrument == trade.instrument && order.state.id == trade.order_id
+                }) else {
+                    return;
+                };
+
+                let order = &mut self.resting[index];
+                order.state.filled_quantity += trade.quantity;
+                if order.state.remaining_quantity() <= 0.0 {
+                    self.resting.remove(index);
+                }
+            }
+            AccountEventKind::OrdersCancelled(cancelled) => {
+                for order in cancelled {
+                    self.remove_resting(&order.instrument, &order.state.id);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Re-synchronise the tracked resting orders with the open orders of the market on the
+    /// exchange (eg/ after a reconnection).
+    pub async fn sync(&mut self) -> Result<(), ExecutionError> {
+        let orders = self.client.fetch_orders_open().await?;
+
+        self.resting = orders
+            .into_iter()
+            .filter(|order| {
+                order.exchange == self.config.exchange && order.instrument == self.config.instrument
+            })
+            .collect();
+
+        Ok(())
+    }
+
+    /// Remove the tracked resting order with the provided [`OrderId`].
+    fn remove_resting(&mut self, instrument: &Instrument, id: &OrderId) {
+        self.resting
+            .retain(|order| !(&order.instrument == instrument && &order.state.id == id));
+    }
+}
+
+/// Build an [`Order<RequestCancel>`] for a resting order.
+fn request_cancel(order: &Order<Open>) -> Order<RequestCancel> {
+    Order {
+        exchange: order.exchange.clone(),
+        instrument: order.instrument.clone(),
+        cid: order.cid,
+        side: order.side,
+        state: RequestCancel::from(order.state.id.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{simulated::execution::SimulatedExecution, test_util::order_open};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use tokio::sync::mpsc;
+
+    #[test]
+    fn test_order_manager_diff() {
+        let mut manager = OrderManager::new(
+            Config {
+                exchange: Exchange::from("exchange"),
+                instrument: Instrument::from(("base", "quote", InstrumentKind::Perpetual)),
+                kind: OrderKind::PostOnly,
+                price_tolerance: 0.5,
+                quantity_tolerance: 0.1,
+            },
+            SimulatedExecution {
+                request_tx: mpsc::unbounded_channel().0,
+            },
+        );
+
+        let cid = ClientOrderId(Uuid::new_v4());
+        let open = |id: &str, side, price, quantity, filled| Order {
+            state: Open {
+                id: OrderId::from(id),
+                ..order_open(cid, side, price, quantity, filled).state
+            },
+            ..order_open(cid, side, price, quantity, filled)
+        };
+        manager.resting = vec![
+            open("bid_1", Side::Buy, 99.0, 1.0, 0.0),
+            open("bid_0", Side::Buy, 100.0, 1.0, 0.5),
+            open("ask_0", Side::Sell, 101.0, 1.0, 0.0),
+            open("ask_1", Side::Sell, 102.0, 1.0, 0.0),
+        ];
+
+        let quotes = |bids: Vec<Quote>, asks: Vec<Quote>| Quotes {
+            bids,
+            asks,
+            ..Quotes::empty("exchange", ("base", "quote", InstrumentKind::Perpetual))
+        };
+
+        // Summarise diff as (side, price) of each place, cancel & amend (cancelled id, price)
+        let summarise = |diff: QuoteDiff| {
+            (
+                diff.place
+                    .iter()
+                    .map(|order| (order.side, order.state.price))
+                    .collect::<Vec<_>>(),
+                diff.cancel
+                    .iter()
+                    .map(|order| order.state.id.0.clone())
+                    .collect::<Vec<_>>(),
+                diff.amend
+                    .iter()
+                    .map(|amend| (amend.cancel.state.id.0.clone(), amend.replace.state.price))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        struct TestCase {
+            input: Quotes,
+            expected: (Vec<(Side, f64)>, Vec<String>, Vec<(String, f64)>),
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: resting orders within tolerance are kept, partially filled bid is amended
+                input: quotes(
+                    vec![Quote::new(100.0, 1.0), Quote::new(99.2, 1.0)],
+                    vec![Quote::new(101.0, 1.0), Quote::new(102.0, 1.0)],
+                ),
+                expected: (vec![], vec![], vec![("bid_0".to_owned(), 100.0)]),
+            },
+            TestCase {
+                // TC1: partially filled bid within quantity tolerance is kept
+                input: quotes(
+                    vec![Quote::new(100.0, 0.5), Quote::new(99.0, 1.0)],
+                    vec![Quote::new(101.0, 1.0), Quote::new(102.0, 1.0)],
+                ),
+                expected: (vec![], vec![], vec![]),
+            },
+            TestCase {
+                // TC2: additional levels are placed & quotes beyond tolerance are amended
+                input: quotes(
+                    vec![
+                        Quote::new(100.0, 0.5),
+                        Quote::new(99.0, 1.0),
+                        Quote::new(98.0, 1.0),
+                    ],
+                    vec![Quote::new(103.0, 1.0), Quote::new(102.0, 1.0)],
+                ),
+                expected: (
+                    vec![(Side::Buy, 98.0)],
+                    vec![],
+                    vec![("ask_0".to_owned(), 103.0)],
+                ),
+            },
+            TestCase {
+                // TC3: empty sides cancel every resting order
+                input: quotes(vec![Quote::new(100.0, 0.5)], vec![]),
+                expected: (
+                    vec![],
+                    vec!["bid_1".to_owned(), "ask_0".to_owned(), "ask_1".to_owned()],
+                    vec![],
+                ),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = summarise(manager.diff(&test.input));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::util::run_default_exchange;
+use barter_data::subscription::trade::PublicTrade;
+use barter_execution::{
+    model::{
+        order::OrderKind,
+        quote::{Quote, Quotes},
+        AccountEventKind,
+    },
+    order_manager::{Config as OrderManagerConfig, OrderManager},
+    simulated::{execution::SimulatedExecution, SimulatedEvent},
+    ExecutionId,
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Side,
+};
+use tokio::sync::mpsc;
+
+// Only the SimulatedExchange runner is used from the utilities shared with simulated_exchange.rs
+#[allow(dead_code)]
+mod util;
+
+#[tokio::test]
+async fn order_manager_maintains_quotes_on_simulated_exchange() {
+    let (event_account_tx, mut event_account_rx) = mpsc::unbounded_channel();
+    let (event_simulated_tx, event_simulated_rx) = mpsc::unbounded_channel();
+
+    // Build SimulatedExchange & run on it's own Tokio task
+    tokio::spawn(run_default_exchange(event_account_tx, event_simulated_rx));
+
+    let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+    let mut manager = OrderManager::new(
+        OrderManagerConfig {
+            exchange: ExecutionId::Simulated.into(),
+            instrument: instrument.clone(),
+            kind: OrderKind::PostOnly,
+            price_tolerance: 0.01,
+            quantity_tolerance: 0.0,
+        },
+        SimulatedExecution {
+            request_tx: event_simulated_tx.clone(),
+        },
+    );
+
+    let quotes = |bids: &[(f64, f64)], asks: &[(f64, f64)]| Quotes {
+        bids: bids.iter().map(|(p, q)| Quote::new(*p, *q)).collect(),
+        asks: asks.iter().map(|(p, q)| Quote::new(*p, *q)).collect(),
+        ..Quotes::empty(ExecutionId::Simulated, instrument.clone())
+    };
+    let resting_prices = |manager: &OrderManager<SimulatedExecution>| {
+        let mut prices = manager
+            .resting()
+            .iter()
+            .map(|order| (order.side, order.state.price))
+            .collect::<Vec<_>>();
+        prices.sort_by(|a, b| a.1.total_cmp(&b.1));
+        prices
+    };
+
+    // 1. Place two levels on each side
+    let update = manager
+        .update(&quotes(
+            &[(100.0, 1.0), (99.0, 1.0)],
+            &[(101.0, 1.0), (102.0, 1.0)],
+        ))
+        .await;
+    assert_eq!((update.opened.len(), update.cancelled.len()), (4, 0));
+    assert!(update.errors.is_empty());
+
+    // 2. Shift the second bid level beyond tolerance, so only it is amended
+    let update = manager
+        .update(&quotes(
+            &[(100.005, 1.0), (98.0, 1.0)],
+            &[(101.0, 1.0), (102.0, 1.0)],
+        ))
+        .await;
+    assert_eq!((update.opened.len(), update.cancelled.len()), (1, 1));
+    assert_eq!(
+        resting_prices(&manager),
+        vec![
+            (Side::Buy, 98.0),
+            (Side::Buy, 100.0),
+            (Side::Sell, 101.0),
+            (Side::Sell, 102.0)
+        ]
+    );
+
+    // 3. PublicTrade fully fills the best bid, which is removed from the resting orders
+    event_simulated_tx
+        .send(SimulatedEvent::MarketTrade((
+            instrument.clone(),
+            PublicTrade {
+                id: "order_manager".to_string(),
+                side: Side::Sell,
+                price: 100.0,
+                amount: 1.0,
+            },
+        )))
+        .unwrap();
+
+    loop {
+        let event = event_account_rx.recv().await.unwrap();
+        manager.update_from_account(&event);
+        if matches!(event.kind, AccountEventKind::Trade(_)) {
+            break;
+        }
+    }
+    assert_eq!(manager.resting().len(), 3);
+
+    // 4. Re-quote the filled level & withdraw the asks
+    let update = manager
+        .update(&quotes(&[(100.0, 1.0), (98.0, 1.0)], &[]))
+        .await;
+    assert_eq!((update.opened.len(), update.cancelled.len()), (1, 2));
+    assert!(update.errors.is_empty());
+
+    // 5. Resting orders tracked by the OrderManager match those open on the exchange
+    let tracked = resting_prices(&manager);
+    manager.sync().await.unwrap();
+    assert_eq!(resting_prices(&manager), tracked);
+    assert_eq!(tracked, vec![(Side::Buy, 98.0), (Side::Buy, 100.0)]);
+}
@@ -0,0 +1,227 @@
+use crate::model::{instrument::Instrument, Exchange, MarketId};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Trading rules of an [`Instrument`] on an [`Exchange`], used to normalise order prices &
+/// quantities to values the venue will accept.
+///
+/// A value of `0.0` disables the associated rule.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct InstrumentSpec {
+    /// Minimum price increment (eg/ 0.01).
+    pub tick_size: f64,
+    /// Minimum quantity increment, also known as lot size (eg/ 0.0001).
+    pub step_size: f64,
+    /// Minimum absolute order quantity.
+    pub min_quantity: f64,
+    /// Minimum order notional value (price * quantity), denominated in the quote currency.
+    pub min_notional: f64,
+}
+
+impl InstrumentSpec {
+    /// Rounds the provided price to the nearest [`Self::tick_size`].
+    pub fn round_price(&self, price: f64) -> f64 {
+        match increments(price, self.tick_size) {
+            Some(ticks) => from_increments(ticks.round(), self.tick_size),
+            None => price,
+        }
+    }
+
+    /// Rounds the provided quantity towards zero to a multiple of [`Self::step_size`], so the
+    /// order never exceeds the size requested.
+    pub fn round_quantity(&self, quantity: f64) -> f64 {
+        match increments(quantity, self.step_size) {
+            // Tolerate floating-point error for quantities already on a step (eg/ 0.3 / 0.1)
+            Some(steps) => from_increments((steps + steps.signum() * 1e-9).trunc(), self.step_size),
+            None => quantity,
+        }
+    }
+
+    /// Determines if an order with the provided price & quantity is below the
+    /// [`Self::min_quantity`] or [`Self::min_notional`].
+    pub fn is_below_minimum(&self, price: f64, quantity: f64) -> bool {
+        quantity.abs() < self.min_quantity || (price * quantity).abs() < self.min_notional
+    }
+}
+
+/// Number of increments in the provided value, or `None` if the increment is disabled.
+fn increments(value: f64, increment: f64) -> Option<f64> {
+    (increment > 0.0).then(|| value / increment)
+}
+
+/// Converts a whole number of increments back into a value. Sub-unit increments divide by their
+/// integer inverse (eg/ 3 / 10 rather than 3 * 0.1) so the result is the closest f64 to the
+/// decimal value.
+fn from_increments(increments: f64, increment: f64) -> f64 {
+    let inverse = (1.0 / increment).round();
+    if increment < 1.0 && (inverse * increment - 1.0).abs() < 1e-9 {
+        increments / inverse
+    } else {
+        increments * increment
+    }
+}
+
+/// [`InstrumentSpec`] of an [`Instrument`] traded on an [`Exchange`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MarketSpec {
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    pub spec: InstrumentSpec,
+}
+
+/// Registry of the [`InstrumentSpec`] of every market, keyed by [`MarketId`].
+///
+/// Loadable from a JSON array of [`MarketSpec`]s, or from specs fetched from venue REST
+/// endpoints.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct InstrumentSpecs {
+    specs: HashMap<MarketId, InstrumentSpec>,
+}
+
+impl InstrumentSpecs {
+    /// Constructs a new [`InstrumentSpecs`] registry from a JSON array of [`MarketSpec`]s.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str::<Vec<MarketSpec>>(json).map(Self::from_iter)
+    }
+
+    /// Upserts the [`InstrumentSpec`] of the provided market.
+    pub fn insert(&mut self, exchange: &Exchange, instrument: &Instrument, spec: InstrumentSpec) {
+        self.specs.insert(MarketId::new(exchange, instrument), spec);
+    }
+
+    /// Returns the [`InstrumentSpec`] of the provided market, if present.
+    pub fn get(&self, exchange: &Exchange, instrument: &Instrument) -> Option<&InstrumentSpec> {
+        self.specs.get(&MarketId::new(exchange, instrument))
+    }
+}
+
+impl FromIterator<MarketSpec> for InstrumentSpecs {
+    fn from_iter<Iter: IntoIterator<Item = MarketSpec>>(iter: Iter) -> Self {
+        Self {
+            specs: iter
+                .into_iter()
+                .map(|market| {
+                    (
+                        MarketId::new(&market.exchange, &market.instrument),
+                        market.spec,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Extend<MarketSpec> for InstrumentSpecs {
+    fn extend<Iter: IntoIterator<Item = MarketSpec>>(&mut self, iter: Iter) {
+        for market in iter {
+            self.insert(&market.exchange, &market.instrument, market.spec);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_instrument_spec_rounding() {
+        let spec = InstrumentSpec {
+            tick_size: 0.01,
+            step_size: 0.1,
+            min_quantity: 0.2,
+            min_notional: 10.0,
+        };
+
+        struct TestCase {
+            input: f64,
+            expected_price: f64,
+            expected_quantity: f64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: values already on a tick & step
+                input: 0.3,
+                expected_price: 0.3,
+                expected_quantity: 0.3,
+            },
+            TestCase {
+                // TC1: price rounds to nearest tick, quantity rounds down to step
+                input: 1.2378,
+                expected_price: 1.24,
+                expected_quantity: 1.2,
+            },
+            TestCase {
+                // TC2: negative quantity rounds towards zero
+                input: -1.2378,
+                expected_price: -1.24,
+                expected_quantity: -1.2,
+            },
+            TestCase {
+                // TC3: quantity smaller than a single step
+                input: 0.05,
+                expected_price: 0.05,
+                expected_quantity: 0.0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                spec.round_price(test.input),
+                test.expected_price,
+                "TC{} price failed",
+                index
+            );
+            assert_eq!(
+                spec.round_quantity(test.input),
+                test.expected_quantity,
+                "TC{} quantity failed",
+                index
+            );
+        }
+
+        // Disabled rules leave values unchanged
+        assert_eq!(InstrumentSpec::default().round_price(1.2378), 1.2378);
+        assert_eq!(InstrumentSpec::default().round_quantity(1.2378), 1.2378);
+
+        // Minimum quantity & notional
+        assert!(spec.is_below_minimum(100.0, 0.1));
+        assert!(spec.is_below_minimum(10.0, 0.5));
+        assert!(!spec.is_below_minimum(100.0, -0.2));
+    }
+
+    #[test]
+    fn test_instrument_specs_from_json() {
+        let input = r#"[
+            {
+                "exchange": "binance_spot",
+                "instrument": { "base": "btc", "quote": "usdt", "instrument_kind": "spot" },
+                "spec": { "tick_size": 0.01, "step_size": 0.00001, "min_notional": 5.0 }
+            }
+        ]"#;
+
+        let specs = InstrumentSpecs::from_json(input).unwrap();
+
+        assert_eq!(
+            specs.get(
+                &Exchange::from("binance_spot"),
+                &Instrument::from(("btc", "usdt", InstrumentKind::Spot))
+            ),
+            Some(&InstrumentSpec {
+                tick_size: 0.01,
+                step_size: 0.00001,
+                min_quantity: 0.0,
+                min_notional: 5.0,
+            })
+        );
+        assert_eq!(
+            specs.get(
+                &Exchange::from("binance_spot"),
+                &Instrument::from(("eth", "usdt", InstrumentKind::Spot))
+            ),
+            None
+        );
+    }
+}
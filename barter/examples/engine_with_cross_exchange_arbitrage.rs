@@ -0,0 +1,184 @@
+use barter::{
+    data::historical,
+    engine::{trader::Trader, Engine},
+    event::{Event, EventTx},
+    execution::{
+        router::ExecutionRouter,
+        simulated::{Config as ExecutionConfig, SimulatedExecution},
+        Fees,
+    },
+    portfolio::{
+        allocator::DefaultAllocator, portfolio::MetaPortfolio,
+        repository::in_memory::InMemoryRepository, risk::DefaultRisk,
+    },
+    statistic::summary::{
+        trading::{Config as StatisticConfig, TradingSummary},
+        Initialiser,
+    },
+    strategy::arbitrage::{Config as StrategyConfig, CrossExchangeArbitrage},
+};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    exchange::ExchangeId,
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Exchange, Market,
+};
+use parking_lot::Mutex;
+use std::{collections::HashMap, fs, sync::Arc};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+// Synthetic OrderBookL1 data for the same instrument on Binance & Kraken, hand-written to contain
+// arbitrage windows. Prices are illustrative only & are not a recording of real market data.
+const DATA_SYNTHETIC_ORDER_BOOKS_L1: &str = "examples/data/order_books_l1_synthetic.json";
+
+const TAKER_FEE: f64 = 0.001;
+
+#[tokio::main]
+async fn main() {
+    // Create channel to distribute Commands to the Engine & it's Traders (eg/ Command::Terminate)
+    let (_command_tx, command_rx) = mpsc::channel(20);
+
+    // Create Event channel to listen to all Engine Events in real-time
+    let (event_tx, event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+
+    // Generate unique identifier to associate an Engine's components
+    let engine_id = Uuid::new_v4();
+
+    // Create the Markets to be arbitraged: the same instrument on every exchange
+    let exchanges = [ExchangeId::BinanceSpot, ExchangeId::Kraken];
+    let markets = exchanges
+        .iter()
+        .map(|exchange| {
+            Market::new(
+                Exchange::from(*exchange),
+                ("btc", "usdt", InstrumentKind::Spot),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // Build global shared-state MetaPortfolio (1-to-1 relationship with an Engine)
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(markets.clone())
+            .starting_cash(10_000.0)
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 1000.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Route the OrderEvents of each Market to it's own simulated exchange
+    let execution = markets
+        .iter()
+        .fold(ExecutionRouter::new(), |router, market| {
+            router.route(
+                &market.exchange,
+                &market.instrument,
+                SimulatedExecution::new(ExecutionConfig {
+                    simulated_fees_pct: Fees {
+                        exchange: TAKER_FEE,
+                        slippage: 0.0,
+                        network: 0.0,
+                    },
+                }),
+            )
+        });
+
+    // Create channel for the multi-market Trader so the Engine can distribute Commands to it
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    // Build a single Trader observing the merged OrderBookL1 feed of every Market
+    let trader = Trader::builder()
+        .engine_id(engine_id)
+        .market(markets[0].clone())
+        .markets(markets.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx.clone())
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new(
+            load_json_market_event_order_books_l1().into_iter(),
+        ))
+        .strategy(CrossExchangeArbitrage::new(
+            StrategyConfig {
+                taker_fees: exchanges
+                    .into_iter()
+                    .map(|exchange| (exchange, TAKER_FEE))
+                    .collect(),
+                entry_spread: 0.002,
+                exit_spread: -0.003,
+                quantity: 0.03,
+            },
+            engine_id,
+            Arc::clone(&portfolio),
+        ))
+        .execution(execution)
+        .build()
+        .expect("failed to build trader");
+
+    // Build Engine (1-to-many relationship with Traders)
+    // Route Commands for every Market to the multi-market Trader
+    let trader_command_txs = markets
+        .into_iter()
+        .map(|market| (market, trader_command_tx.clone()))
+        .collect::<HashMap<_, _>>();
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(vec![trader])
+        .trader_command_txs(trader_command_txs)
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: 10_000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .build()
+        .expect("failed to build engine");
+
+    // Run Engine trading & listen to Events it produces
+    tokio::spawn(listen_to_engine_events(event_rx));
+    engine.run().await;
+}
+
+fn load_json_market_event_order_books_l1() -> Vec<MarketEvent<Instrument, DataKind>> {
+    let books = fs::read_to_string(DATA_SYNTHETIC_ORDER_BOOKS_L1).expect("failed to read file");
+
+    serde_json::from_str::<Vec<MarketEvent<Instrument, DataKind>>>(&books)
+        .expect("failed to parse order books String")
+}
+
+// Listen to Events that occur in the Engine. These can be used for updating event-sourcing,
+// updating dashboard, etc etc.
+async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
+    while let Some(event) = event_rx.recv().await {
+        match event {
+            Event::Signal(signal) => {
+                // Signal Event occurred in Engine
+                println!("{signal:?}");
+            }
+            Event::Fill(fill_event) => {
+                // Fill Event occurred in Engine
+                println!("{fill_event:?}");
+            }
+            Event::PositionExit(exited_position) => {
+                // PositionExit Event occurred in Engine
+                println!("{exited_position:?}");
+            }
+            _ => {}
+        }
+    }
+}
@@ -169,6 +169,10 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // Market Event occurred in Engine
                 println!("{market:?}");
             }
+            Event::Timer(timer) => {
+                // Strategy Timer fired in Engine
+                println!("{timer:?}");
+            }
             Event::Signal(signal) => {
                 // Signal Event occurred in Engine
                 println!("{signal:?}");
@@ -180,6 +184,10 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // OrderNew Event occurred in Engine
                 println!("{new_order:?}");
             }
+            Event::OrderRejected(rejected_order) => {
+                // OrderRejected Event occurred in Engine
+                println!("{rejected_order:?}");
+            }
             Event::OrderUpdate => {
                 // OrderUpdate Event occurred in Engine
             }
@@ -203,6 +211,22 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // Balance update Event occurred in Engine
                 println!("{balance_update:?}");
             }
+            Event::MarginCall(margin_call) => {
+                // MarginCall Event occurred in Engine
+                println!("{margin_call:?}");
+            }
+            Event::ExitTriggered(exit_triggered) => {
+                // ExitTriggered Event occurred in Engine
+                println!("{exit_triggered:?}");
+            }
+            Event::FundingPayment(funding_payment) => {
+                // FundingPayment Event occurred in Engine
+                println!("{funding_payment:?}");
+            }
+            Event::CircuitBreakerTripped(tripped) => {
+                // CircuitBreakerTripped Event occurred in Engine
+                println!("{tripped:?}");
+            }
         }
     }
 }
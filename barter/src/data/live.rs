@@ -1,4 +1,5 @@
 use super::{Feed, MarketGenerator};
+use barter_data::{event::MarketEvent, streams::lifecycle};
 use tokio::sync::mpsc;
 
 /// Live [`Feed`] of market events.
@@ -30,7 +31,28 @@ impl<Event> MarketFeed<Event> {
     ///  2. Use [`Streams::join`](barter_data::streams::Streams::join) to join all exchange
     ///     [`mpsc::UnboundedReceiver`] streams into a unified [`mpsc::UnboundedReceiver`].
     ///  3. Construct [`Self`] with the unified [`mpsc::UnboundedReceiver`].
+    ///
+    /// If the [`Streams`](barter_data::streams::Streams) yield `Barter-Data`
+    /// [`lifecycle::Event`]s, unhealthy events are surfaced as [`Feed::Unhealthy`].
     pub fn new(market_rx: mpsc::UnboundedReceiver<Event>) -> Self {
         Self { market_rx }
     }
 }
+
+impl<InstrumentId, T> MarketGenerator<MarketEvent<InstrumentId, T>>
+    for MarketFeed<lifecycle::Event<InstrumentId, MarketEvent<InstrumentId, T>>>
+{
+    /// Yields the next [`MarketEvent`], mapping unhealthy `Barter-Data`
+    /// [`lifecycle::Event`]s (eg/ disconnections & stale instruments) to [`Feed::Unhealthy`].
+    fn next(&mut self) -> Feed<MarketEvent<InstrumentId, T>> {
+        loop {
+            match self.market_rx.try_recv() {
+                Ok(lifecycle::Event::Item(event)) => break Feed::Next(event),
+                Ok(event) if event.is_unhealthy() => break Feed::Unhealthy,
+                Ok(_) => continue,
+                Err(mpsc::error::TryRecvError::Empty) => continue,
+                Err(mpsc::error::TryRecvError::Disconnected) => break Feed::Finished,
+            }
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use crate::{
+    event::Event,
+    portfolio::position::PositionId,
+    statistic::metric::{drawdown::Drawdown, EquityPoint},
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Thresholds that trip a [`CircuitBreaker`]. Every threshold is optional, and is disabled when
+/// `None`.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CircuitBreakerConfig {
+    /// Maximum loss of equity since the start of the UTC day, as a fraction of the equity at the
+    /// start of the day (eg/ 0.05 for 5%).
+    pub max_intraday_loss: Option<f64>,
+    /// Maximum peak-to-trough [`Drawdown`] of equity, as a fraction of the peak (eg/ 0.2 for 20%).
+    pub max_drawdown: Option<f64>,
+    /// Maximum number of consecutive [`Position`](crate::portfolio::position::Position)s exited
+    /// at a loss.
+    pub max_consecutive_losses: Option<u32>,
+}
+
+/// Threshold breach that tripped a [`CircuitBreaker`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum Breach {
+    IntradayLoss { loss: f64, limit: f64 },
+    Drawdown { drawdown: f64, limit: f64 },
+    ConsecutiveLosses { losses: u32, limit: u32 },
+}
+
+/// Auditable record of a [`CircuitBreaker`] tripping, after which new entries are halted and
+/// every open [`Position`](crate::portfolio::position::Position) is exited.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CircuitBreakerTripped {
+    pub time: DateTime<Utc>,
+    /// Equity (realised & unrealised) at the time of tripping.
+    pub equity: f64,
+    pub breach: Breach,
+}
+
+/// Kill switch that watches the Portfolio [`Event::Balance`], [`Event::PositionUpdate`] &
+/// [`Event::PositionExit`] events, and trips when a [`CircuitBreakerConfig`] threshold is
+/// breached.
+///
+/// Shared between every [`Trader`](super::trader::Trader) of an [`Engine`](super::Engine). Once
+/// tripped, [`Trader`](super::trader::Trader)s stop entering new
+/// [`Position`](crate::portfolio::position::Position)s and exit their open ones, until the
+/// [`CircuitBreaker`] is [`reset`](CircuitBreaker::reset).
+#[derive(Clone, PartialEq, Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    balance_total: f64,
+    unrealised_profit_loss: HashMap<PositionId, f64>,
+    drawdown: Drawdown,
+    day: NaiveDate,
+    day_start_equity: f64,
+    consecutive_losses: u32,
+    trips: u64,
+    tripped: Option<CircuitBreakerTripped>,
+}
+
+impl CircuitBreaker {
+    /// Constructs a new [`CircuitBreaker`] for a Portfolio with the provided starting equity.
+    pub fn new(config: CircuitBreakerConfig, starting_equity: f64, time: DateTime<Utc>) -> Self {
+        Self {
+            config,
+            balance_total: starting_equity,
+            unrealised_profit_loss: HashMap::new(),
+            drawdown: Drawdown::init(starting_equity),
+            day: time.date_naive(),
+            day_start_equity: starting_equity,
+            consecutive_losses: 0,
+            trips: 0,
+            tripped: None,
+        }
+    }
+
+    /// Current equity, including the unrealised P&L of every open
+    /// [`Position`](crate::portfolio::position::Position).
+    pub fn equity(&self) -> f64 {
+        self.balance_total + self.unrealised_profit_loss.values().sum::<f64>()
+    }
+
+    /// Determines if the [`CircuitBreaker`] is tripped.
+    pub fn is_tripped(&self) -> bool {
+        self.tripped.is_some()
+    }
+
+    /// Returns the [`CircuitBreakerTripped`] record if the [`CircuitBreaker`] is tripped.
+    pub fn tripped(&self) -> Option<&CircuitBreakerTripped> {
+        self.tripped.as_ref()
+    }
+
+    /// Number of times the [`CircuitBreaker`] has tripped.
+    pub fn trips(&self) -> u64 {
+        self.trips
+    }
+
+    /// Re-arms a tripped [`CircuitBreaker`], resetting the consecutive losses and using the
+    /// current equity as the start of day equity.
+    pub fn reset(&mut self) {
+        self.tripped = None;
+        self.consecutive_losses = 0;
+        self.day_start_equity = self.equity();
+    }
+
+    /// Updates the [`CircuitBreaker`] from a Portfolio [`Event`]. Returns a
+    /// [`CircuitBreakerTripped`] if the [`Event`] caused it to trip.
+    pub fn update(&mut self, event: &Event) -> Option<CircuitBreakerTripped> {
+        let previous_equity = self.equity();
+        let time = match event {
+            Event::Balance(balance) => {
+                self.balance_total = balance.total;
+                balance.time
+            }
+            Event::PositionUpdate(update) => {
+                self.unrealised_profit_loss
+                    .insert(update.position_id.clone(), update.unrealised_profit_loss);
+                update.update_time
+            }
+            Event::PositionExit(exit) => {
+                // Realise the P&L now, since the subsequent Event::Balance confirms the same total
+                self.unrealised_profit_loss.remove(&exit.position_id);
+                self.balance_total += exit.realised_profit_loss;
+
+                match exit.realised_profit_loss < 0.0 {
+                    true => self.consecutive_losses += 1,
+                    false => self.consecutive_losses = 0,
+                }
+                exit.exit_time
+            }
+            _ => return None,
+        };
+
+        // Intraday loss is measured from the equity prior to the first Event of each UTC day
+        if time.date_naive() > self.day {
+            self.day = time.date_naive();
+            self.day_start_equity = previous_equity;
+        }
+
+        let equity = self.equity();
+        self.drawdown.update(EquityPoint {
+            time,
+            total: equity,
+        });
+
+        if self.is_tripped() {
+            return None;
+        }
+
+        let breach = self.breach(equity)?;
+        let tripped = CircuitBreakerTripped {
+            time,
+            equity,
+            breach,
+        };
+        self.tripped = Some(tripped);
+        self.trips += 1;
+
+        Some(tripped)
+    }
+
+    /// Determines the first [`CircuitBreakerConfig`] threshold breached, if any.
+    fn breach(&self, equity: f64) -> Option<Breach> {
+        if let Some(limit) = self.config.max_intraday_loss {
+            let loss = (self.day_start_equity - equity) / self.day_start_equity;
+            if loss >= limit {
+                return Some(Breach::IntradayLoss { loss, limit });
+            }
+        }
+
+        if let Some(limit) = self.config.max_drawdown {
+            let drawdown = -self.drawdown.drawdown;
+            if drawdown >= limit {
+                return Some(Breach::Drawdown { drawdown, limit });
+            }
+        }
+
+        if let Some(limit) = self.config.max_consecutive_losses {
+            if self.consecutive_losses >= limit {
+                return Some(Breach::ConsecutiveLosses {
+                    losses: self.consecutive_losses,
+                    limit,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::{
+            position::{PositionExit, PositionUpdate},
+            Balance,
+        },
+        test_util::position,
+    };
+    use chrono::{Duration, TimeZone};
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    fn balance(time: DateTime<Utc>, total: f64) -> Event {
+        Event::Balance(Balance::new(time, total, total))
+    }
+
+    fn position_update(time: DateTime<Utc>, unrealised_profit_loss: f64) -> Event {
+        Event::PositionUpdate(PositionUpdate {
+            position_id: "position".to_string(),
+            update_time: time,
+            current_symbol_price: 0.0,
+            current_value_gross: 0.0,
+            unrealised_profit_loss,
+        })
+    }
+
+    fn position_exit(time: DateTime<Utc>, realised_profit_loss: f64) -> Event {
+        let mut position = position();
+        position.position_id = "position".to_string();
+        position.realised_profit_loss = realised_profit_loss;
+        let mut exit = PositionExit::try_from(&mut {
+            position.meta.exit_balance = Some(Balance::new(time, 0.0, 0.0));
+            position
+        })
+        .unwrap();
+        exit.exit_time = time;
+        Event::PositionExit(exit)
+    }
+
+    #[test]
+    fn test_circuit_breaker_update() {
+        struct TestCase {
+            config: CircuitBreakerConfig,
+            events: Vec<Event>,
+            expected: Option<Breach>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no thresholds configured
+                config: CircuitBreakerConfig::default(),
+                events: vec![balance(start(), 10.0)],
+                expected: None,
+            },
+            TestCase {
+                // TC1: intraday loss including unrealised P&L
+                config: CircuitBreakerConfig {
+                    max_intraday_loss: Some(0.05),
+                    ..Default::default()
+                },
+                events: vec![
+                    position_update(start(), -40.0),
+                    position_update(start(), -50.0),
+                ],
+                expected: Some(Breach::IntradayLoss {
+                    loss: 0.05,
+                    limit: 0.05,
+                }),
+            },
+            TestCase {
+                // TC2: intraday loss measured from the start of the latest UTC day
+                config: CircuitBreakerConfig {
+                    max_intraday_loss: Some(0.05),
+                    ..Default::default()
+                },
+                events: vec![
+                    balance(start(), 960.0),
+                    balance(start() + Duration::days(1), 910.0),
+                ],
+                expected: Some(Breach::IntradayLoss {
+                    loss: 50.0 / 960.0,
+                    limit: 0.05,
+                }),
+            },
+            TestCase {
+                // TC3: drawdown from the equity peak
+                config: CircuitBreakerConfig {
+                    max_drawdown: Some(0.2),
+                    ..Default::default()
+                },
+                events: vec![
+                    balance(start(), 1250.0),
+                    balance(start() + Duration::days(1), 1100.0),
+                    balance(start() + Duration::days(2), 1000.0),
+                ],
+                expected: Some(Breach::Drawdown {
+                    drawdown: 0.2,
+                    limit: 0.2,
+                }),
+            },
+            TestCase {
+                // TC4: consecutive losses reset by a profitable exit
+                config: CircuitBreakerConfig {
+                    max_consecutive_losses: Some(2),
+                    ..Default::default()
+                },
+                events: vec![
+                    position_exit(start(), -1.0),
+                    position_exit(start(), 1.0),
+                    position_exit(start(), -1.0),
+                ],
+                expected: None,
+            },
+            TestCase {
+                // TC5: consecutive losses
+                config: CircuitBreakerConfig {
+                    max_consecutive_losses: Some(2),
+                    ..Default::default()
+                },
+                events: vec![position_exit(start(), -1.0), position_exit(start(), -1.0)],
+                expected: Some(Breach::ConsecutiveLosses {
+                    losses: 2,
+                    limit: 2,
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut breaker = CircuitBreaker::new(test.config, 1000.0, start());
+            let actual = test
+                .events
+                .iter()
+                .filter_map(|event| breaker.update(event))
+                .map(|tripped| tripped.breach)
+                .last();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+            assert_eq!(
+                breaker.is_tripped(),
+                test.expected.is_some(),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_circuit_breaker_trips_once_until_reset() {
+        let config = CircuitBreakerConfig {
+            max_consecutive_losses: Some(1),
+            ..Default::default()
+        };
+        let mut breaker = CircuitBreaker::new(config, 1000.0, start());
+
+        assert!(breaker.update(&position_exit(start(), -1.0)).is_some());
+        assert!(breaker.update(&position_exit(start(), -1.0)).is_none());
+        assert_eq!(breaker.trips(), 1);
+
+        breaker.reset();
+        assert!(!breaker.is_tripped());
+        assert!(breaker.update(&position_exit(start(), -1.0)).is_some());
+        assert_eq!(breaker.trips(), 2);
+    }
+}
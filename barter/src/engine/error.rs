@@ -1,4 +1,5 @@
 use crate::portfolio::repository::error::RepositoryError;
+use barter_integration::model::MarketId;
 use thiserror::Error;
 
 /// All errors generated in barter-engine.
@@ -7,6 +8,9 @@ pub enum EngineError {
     #[error("Failed to build struct due to missing attributes: {0}")]
     BuilderIncomplete(&'static str),
 
+    #[error("Trader Execution has no execution handler routed for market: {0}")]
+    MarketNotRouted(MarketId),
+
     #[error("Failed to interact with repository")]
     RepositoryInteractionError(#[from] RepositoryError),
 }
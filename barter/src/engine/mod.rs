@@ -9,7 +9,7 @@ use crate::{
         FillUpdater, MarketUpdater, OrderGenerator,
     },
     statistic::summary::{PositionSummariser, TableBuilder},
-    strategy::SignalGenerator,
+    strategy::MultiMarketSignalGenerator,
 };
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Market, MarketId};
@@ -24,6 +24,10 @@ use uuid::Uuid;
 /// Barter Engine module specific errors.
 pub mod error;
 
+/// [`CircuitBreaker`](circuit_breaker::CircuitBreaker) kill switch that halts new entries and
+/// exits every open [`Position`] when Portfolio loss thresholds are breached.
+pub mod circuit_breaker;
+
 /// Contains the trading event loop for a Trader capable of trading a single market pair. A Trader
 /// has it's own Data handler, Strategy & Execution handler, as well as shared access to a global
 /// Portfolio instance.
@@ -45,6 +49,10 @@ pub enum Command {
     /// Exit a [`Position`]. Uses the [`Market`] provided to route this [`Command`] to the relevant
     /// [`Trader`] instance. Involves one [`Trader`].
     ExitPosition(Market),
+
+    /// Re-arm a tripped [`CircuitBreaker`](circuit_breaker::CircuitBreaker), allowing new
+    /// entries to resume. Involves all [`Trader`]s.
+    ResetCircuitBreaker,
 }
 
 /// Lego components for constructing an [`Engine`] via the new() constructor method.
@@ -55,7 +63,7 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater + Send,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
+    Strategy: MultiMarketSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     /// Unique identifier for an [`Engine`] in Uuid v4 format. Used as a unique identifier seed for
@@ -68,7 +76,8 @@ where
     /// Collection of [`Trader`] instances that can concurrently trade a market pair on it's own thread.
     pub traders: Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>,
     /// `HashMap` containing a [`Command`] transmitter for every [`Trader`] associated with this
-    /// [`Engine`].
+    /// [`Engine`]. A [`Trader`] bartering on many [`Market`]s shares it's transmitter between
+    /// each of them.
     pub trader_command_txs: HashMap<Market, mpsc::Sender<Command>>,
     /// Uses trading session's exited [`Position`]s to calculate an average statistical summary
     /// across all [`Market`]s traded.
@@ -95,7 +104,7 @@ where
         + Send
         + 'static,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send + 'static,
-    Strategy: SignalGenerator + Send,
+    Strategy: MultiMarketSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     /// Unique identifier for an [`Engine`] in Uuid v4 format. Used as a unique identifier seed for
@@ -129,7 +138,7 @@ where
         + Send
         + 'static,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send + 'static,
+    Strategy: MultiMarketSignalGenerator + Send + 'static,
     Execution: ExecutionClient + Send + 'static,
 {
     /// Constructs a new trading [`Engine`] instance using the provided [`EngineLego`].
@@ -185,6 +194,9 @@ where
                             Command::ExitAllPositions => {
                                 self.exit_all_positions().await;
                             },
+                            Command::ResetCircuitBreaker => {
+                                self.reset_circuit_breaker().await;
+                            },
                         }
                     } else {
                         // Terminate traders due to dropped receiver
@@ -290,6 +302,20 @@ where
         }
     }
 
+    /// Re-arm the [`CircuitBreaker`](circuit_breaker::CircuitBreaker) shared by every [`Trader`]
+    /// associated with this [`Engine`].
+    async fn reset_circuit_breaker(&self) {
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            if command_tx.send(Command::ResetCircuitBreaker).await.is_err() {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::ResetCircuitBreaker to Trader command_rx"
+                );
+            }
+        }
+    }
+
     /// Exit a [`Position`]. Uses the [`Market`] provided to route this [`Command`] to the relevant
     /// [`Trader`] instance.
     async fn exit_position(&self, market: Market) {
@@ -319,7 +345,7 @@ where
     fn generate_session_summary(mut self) -> Table {
         // Fetch statistics for each Market
         let stats_per_market = self.trader_command_txs.into_keys().filter_map(|market| {
-            let market_id = MarketId::from(&market);
+            let market_id = MarketId::new(&market.exchange, &market.instrument);
 
             match self.portfolio.lock().get_statistics(&market_id) {
                 Ok(statistics) => Some((market_id.0, statistics)),
@@ -364,7 +390,7 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater + Send,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
+    Strategy: MultiMarketSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     engine_id: Option<Uuid>,
@@ -387,7 +413,7 @@ where
         + FillUpdater
         + Send,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
+    Strategy: MultiMarketSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     fn new() -> Self {
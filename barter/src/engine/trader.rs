@@ -1,18 +1,37 @@
-use super::{error::EngineError, Command};
+use super::{circuit_breaker::CircuitBreaker, error::EngineError, Command};
 use crate::{
     data::{Feed, MarketGenerator},
     event::{Event, MessageTransmitter},
     execution::ExecutionClient,
-    portfolio::{FillUpdater, MarketUpdater, OrderGenerator},
-    strategy::{SignalForceExit, SignalGenerator},
+    portfolio::{
+        error::PortfolioError,
+        funding::FundingSchedule,
+        repository::{
+            determine_strategy_state_id, error::RepositoryError, StrategyStateHandler,
+            StrategyStateId,
+        },
+        risk::OrderRejected,
+        FillUpdater, MarketUpdater, OrderGenerator,
+    },
+    strategy::{
+        timer::{Timer, TimerEvent, TimerHandler, Timers},
+        MultiMarketSignalGenerator, Signal, SignalForceExit, StrategyState,
+    },
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{instrument::Instrument, Market};
+use barter_integration::model::{instrument::Instrument, Market, MarketId};
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 use serde::Serialize;
-use std::{collections::VecDeque, fmt::Debug, marker::PhantomData, sync::Arc};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    marker::PhantomData,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tokio::sync::mpsc;
-use tracing::{debug, info, warn};
+use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 /// Lego components for constructing a [`Trader`] via the new() constructor method.
@@ -23,7 +42,7 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>>,
-    Strategy: SignalGenerator,
+    Strategy: MultiMarketSignalGenerator,
     Execution: ExecutionClient,
 {
     /// Identifier for the [`Engine`](super::Engine) this [`Trader`] is associated with
@@ -31,6 +50,10 @@ where
     pub engine_id: Uuid,
     /// Communicates the unique [`Market`] this [`Trader`] is bartering on.
     pub market: Market,
+    /// Every [`Market`] this [`Trader`] is bartering on, including it's primary [`Market`].
+    /// Contains more than one [`Market`] when running a multi-market strategy fed by a merged
+    /// [`MarketEvent`] stream.
+    pub markets: Vec<Market>,
     /// mpsc::Receiver for receiving [`Command`]s from a remote source.
     pub command_rx: mpsc::Receiver<Command>,
     /// [`Event`] transmitter for sending every [`Event`] the [`Trader`] encounters to an external sink.
@@ -40,10 +63,22 @@ where
     pub portfolio: Arc<Mutex<Portfolio>>,
     /// Data handler that implements [`MarketGenerator`].
     pub data: Data,
-    /// Strategy that implements [`SignalGenerator`].
+    /// Strategy that implements [`MultiMarketSignalGenerator`].
     pub strategy: Strategy,
     /// Execution handler that implements [`ExecutionClient`].
     pub execution: Execution,
+    /// Optional [`CircuitBreaker`] shared between every [`Trader`] of an
+    /// [`Engine`](super::Engine).
+    pub circuit_breaker: Option<Arc<Mutex<CircuitBreaker>>>,
+    /// Optional historical [`FundingSchedule`] applied to this [`Trader`]'s open perpetual
+    /// Position.
+    pub funding_schedule: Option<FundingSchedule>,
+    /// Historical [`MarketEvent`]s (oldest first) used to warm-up the Strategy before trading.
+    pub warm_up: Vec<MarketEvent<Instrument, DataKind>>,
+    /// Optional [`StrategyStatePersistence`] used to snapshot & restore the Strategy state.
+    pub strategy_state: Option<StrategyStatePersistence<Portfolio, Strategy>>,
+    /// Optional [`StrategyTimers`] fired on the [`MarketEvent`] clock.
+    pub timers: Option<StrategyTimers<Strategy>>,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -59,7 +94,7 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
+    Strategy: MultiMarketSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     /// Identifier for the [`Engine`](super::Engine) this [`Trader`] is associated with
@@ -67,6 +102,8 @@ where
     engine_id: Uuid,
     /// Communicates the unique [`Market`] this [`Trader`] is bartering on.
     market: Market,
+    /// Every [`Market`] this [`Trader`] is bartering on, including it's primary [`Market`].
+    markets: Vec<Market>,
     /// `mpsc::Receiver` for receiving [`Command`]s from a remote source.
     command_rx: mpsc::Receiver<Command>,
     /// [`Event`] transmitter for sending every [`Event`] the [`Trader`] encounters to an external
@@ -79,10 +116,27 @@ where
     portfolio: Arc<Mutex<Portfolio>>,
     /// Data handler that implements [`MarketGenerator`].
     data: Data,
-    /// Strategy that implements [`SignalGenerator`].
+    /// Strategy that implements [`MultiMarketSignalGenerator`].
     strategy: Strategy,
     /// Execution handler that implements [`ExecutionClient`].
     execution: Execution,
+    /// Optional [`CircuitBreaker`] shared between every [`Trader`] of an
+    /// [`Engine`](super::Engine).
+    circuit_breaker: Option<Arc<Mutex<CircuitBreaker>>>,
+    /// Number of [`CircuitBreaker`] trips this [`Trader`] has already actioned.
+    circuit_breaker_trips: u64,
+    /// Optional historical [`FundingSchedule`] applied to this [`Trader`]'s open perpetual
+    /// Position.
+    funding_schedule: Option<FundingSchedule>,
+    /// Historical [`MarketEvent`]s (oldest first) used to warm-up the Strategy before trading.
+    warm_up: Vec<MarketEvent<Instrument, DataKind>>,
+    /// Exchange time of the latest warm-up [`MarketEvent`] of each market. Used to skip live
+    /// [`MarketEvent`]s the Strategy has already been fed.
+    warmed_up_to: HashMap<MarketId, DateTime<Utc>>,
+    /// Optional [`StrategyStatePersistence`] used to snapshot & restore the Strategy state.
+    strategy_state: Option<StrategyStatePersistence<Portfolio, Strategy>>,
+    /// Optional [`StrategyTimers`] fired on the [`MarketEvent`] clock.
+    timers: Option<StrategyTimers<Strategy>>,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -93,7 +147,7 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
+    Strategy: MultiMarketSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     /// Constructs a new [`Trader`] instance using the provided [`TraderLego`].
@@ -107,6 +161,7 @@ where
         Self {
             engine_id: lego.engine_id,
             market: lego.market,
+            markets: lego.markets,
             command_rx: lego.command_rx,
             event_tx: lego.event_tx,
             event_q: VecDeque::with_capacity(4),
@@ -114,6 +169,13 @@ where
             data: lego.data,
             strategy: lego.strategy,
             execution: lego.execution,
+            circuit_breaker: lego.circuit_breaker,
+            circuit_breaker_trips: 0,
+            funding_schedule: lego.funding_schedule,
+            warm_up: lego.warm_up,
+            warmed_up_to: HashMap::new(),
+            strategy_state: lego.strategy_state,
+            timers: lego.timers,
             _statistic_marker: PhantomData,
         }
     }
@@ -126,7 +188,15 @@ where
     /// Run the trading event-loop for this [`Trader`] instance. Loop will run until [`Trader`]
     /// receives a [`Command::Terminate`] via the mpsc::Receiver command_rx, or the
     /// [`MarketGenerator`] yields [`Feed::Finished`].
+    ///
+    /// The Strategy is first restored from it's latest persisted [`StrategyState`] snapshot (if
+    /// configured), else warmed up with any historical warm-up [`MarketEvent`]s.
     pub fn run(mut self) {
+        // Restore or warm-up the Strategy before trading on the MarketGenerator Feed
+        if !self.restore_strategy_state() {
+            self.warm_up();
+        }
+
         // Run trading loop for this Trader instance
         'trading: loop {
             // Check for new remote Commands before continuing to generate another MarketEvent
@@ -137,10 +207,14 @@ where
                         self.event_q
                             .push_back(Event::SignalForceExit(SignalForceExit::from(market)));
                     }
+                    Command::ResetCircuitBreaker => self.reset_circuit_breaker(),
                     _ => continue,
                 }
             }
 
+            // Exit this Trader's open Position if the CircuitBreaker has newly tripped
+            self.action_circuit_breaker();
+
             // If the Feed<MarketEvent> yields, populate event_q with the next MarketEvent
             match self.data.next() {
                 Feed::Next(market) => {
@@ -164,30 +238,102 @@ where
             while let Some(event) = self.event_q.pop_front() {
                 match event {
                     Event::Market(market) => {
-                        if let Some(signal) = self.strategy.generate_signal(&market) {
-                            self.event_tx.send(Event::Signal(signal.clone()));
-                            self.event_q.push_back(Event::Signal(signal));
+                        // Skip Strategy for MarketEvents it has already seen during warm-up
+                        if !self.is_warmed_up(&market) {
+                            // Fire Timers due by this MarketEvent before the Strategy analyses it
+                            self.fire_timers(&market);
+
+                            for signal in self.strategy.generate_signals(&market) {
+                                self.event_tx.send(Event::Signal(signal.clone()));
+                                self.event_q.push_back(Event::Signal(signal));
+                            }
                         }
 
-                        if let Some(position_update) = self
+                        self.apply_funding(&market);
+
+                        let position_update = self
                             .portfolio
                             .lock()
                             .update_from_market(&market)
-                            .expect("failed to update Portfolio from market")
-                        {
-                            self.event_tx.send(Event::PositionUpdate(position_update));
+                            .expect("failed to update Portfolio from market");
+
+                        if let Some(position_update) = position_update {
+                            let event = Event::PositionUpdate(position_update);
+                            self.update_circuit_breaker(&event);
+                            self.event_tx.send(event);
                         }
-                    }
 
-                    Event::Signal(signal) => {
-                        if let Some(order) = self
+                        let margin_call = self
                             .portfolio
                             .lock()
-                            .generate_order(&signal)
-                            .expect("failed to generate order")
-                        {
-                            self.event_tx.send(Event::OrderNew(order.clone()));
-                            self.event_q.push_back(Event::OrderNew(order));
+                            .margin_call(&market)
+                            .expect("failed to check Portfolio margin");
+
+                        // Liquidate the Position if it's equity has fallen to maintenance margin
+                        if let Some(margin_call) = margin_call {
+                            warn!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                margin_call = ?margin_call,
+                                action = "liquidating Position",
+                                "Position margin call"
+                            );
+                            self.event_tx.send(Event::MarginCall(margin_call));
+                            self.event_q
+                                .push_back(Event::SignalForceExit(SignalForceExit::new(
+                                    market.exchange.clone(),
+                                    market.instrument.clone(),
+                                )));
+                            continue;
+                        }
+
+                        let exit_triggered = self
+                            .portfolio
+                            .lock()
+                            .exit_trigger(&market)
+                            .expect("failed to evaluate Portfolio exit rules");
+
+                        // Exit the Position if a stop-loss, take-profit, etc. rule is triggered
+                        if let Some(exit_triggered) = exit_triggered {
+                            info!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                exit_triggered = ?exit_triggered,
+                                action = "exiting Position",
+                                "Position exit rule triggered"
+                            );
+                            self.event_tx.send(Event::ExitTriggered(exit_triggered));
+                            self.event_q
+                                .push_back(Event::SignalForceExit(SignalForceExit::new(
+                                    market.exchange.clone(),
+                                    market.instrument.clone(),
+                                )));
+                        }
+                    }
+
+                    Event::Signal(signal) => {
+                        // Halt new entries whilst the CircuitBreaker is tripped, only permitting
+                        // OrderEvents that reduce an open Position
+                        let order = if self.circuit_breaker_tripped() {
+                            self.portfolio.lock().generate_reducing_order(&signal)
+                        } else {
+                            self.portfolio.lock().generate_order(&signal)
+                        };
+                        match order {
+                            Ok(Some(order)) => {
+                                self.event_tx.send(Event::OrderNew(order.clone()));
+                                self.event_q.push_back(Event::OrderNew(order));
+                            }
+                            Ok(None) => {}
+                            Err(PortfolioError::OrderRejected(rejected)) => {
+                                info!(
+                                    engine_id = %self.engine_id,
+                                    violations = ?rejected.violations,
+                                    "OrderEvent rejected by risk manager"
+                                );
+                                self.event_tx.send(Event::OrderRejected(*rejected));
+                            }
+                            Err(error) => panic!("failed to generate order: {error:?}"),
                         }
                     }
 
@@ -203,15 +349,22 @@ where
                         }
                     }
 
-                    Event::OrderNew(order) => {
-                        let fill = self
-                            .execution
-                            .generate_fill(&order)
-                            .expect("failed to generate Fill");
-
-                        self.event_tx.send(Event::Fill(fill.clone()));
-                        self.event_q.push_back(Event::Fill(fill));
-                    }
+                    Event::OrderNew(order) => match self.execution.generate_fill(&order) {
+                        Ok(fill) => {
+                            self.event_tx.send(Event::Fill(fill.clone()));
+                            self.event_q.push_back(Event::Fill(fill));
+                        }
+                        Err(error) => {
+                            error!(
+                                engine_id = %self.engine_id,
+                                ?error,
+                                action = "dropping OrderEvent",
+                                "failed to generate Fill"
+                            );
+                            self.event_tx
+                                .send(Event::OrderRejected(OrderRejected::new(order, vec![])));
+                        }
+                    },
 
                     Event::Fill(fill) => {
                         let fill_side_effect_events = self
@@ -220,18 +373,242 @@ where
                             .update_from_fill(&fill)
                             .expect("failed to update Portfolio from fill");
 
+                        fill_side_effect_events
+                            .iter()
+                            .for_each(|event| self.update_circuit_breaker(event));
                         self.event_tx.send_many(fill_side_effect_events);
                     }
                     _ => {}
                 }
             }
 
+            // Periodically persist a snapshot of the Strategy state
+            self.persist_strategy_state(false);
+
             debug!(
                 engine_id = &*self.engine_id.to_string(),
                 market = &*format!("{:?}", self.market),
                 "Trader trading loop stopped"
             );
         }
+
+        // Persist a final snapshot of the Strategy state on shutdown
+        self.persist_strategy_state(true);
+    }
+
+    /// Restores the Strategy from it's latest persisted [`StrategyState`] snapshot, returning
+    /// true if a snapshot was restored.
+    fn restore_strategy_state(&mut self) -> bool {
+        let Some(strategy_state) = &self.strategy_state else {
+            return false;
+        };
+
+        let strategy_state_id = determine_strategy_state_id(self.engine_id, &self.market);
+        let restored = (strategy_state.restore)(
+            &mut self.strategy,
+            &mut *self.portfolio.lock(),
+            &strategy_state_id,
+        );
+
+        match restored {
+            Ok(restored) => {
+                info!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    restored,
+                    "Trader restored Strategy state snapshot"
+                );
+                restored
+            }
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    action = "continuing with initial Strategy state",
+                    "failed to restore Strategy state snapshot"
+                );
+                false
+            }
+        }
+    }
+
+    /// Persists a snapshot of the Strategy [`StrategyState`] if forced, or the configured
+    /// interval has elapsed since the last snapshot.
+    fn persist_strategy_state(&mut self, force: bool) {
+        let Some(strategy_state) = &mut self.strategy_state else {
+            return;
+        };
+
+        if !force && strategy_state.persisted.elapsed() < strategy_state.interval {
+            return;
+        }
+        strategy_state.persisted = Instant::now();
+
+        let strategy_state_id = determine_strategy_state_id(self.engine_id, &self.market);
+        if let Err(error) = (strategy_state.persist)(
+            &self.strategy,
+            &mut *self.portfolio.lock(),
+            &strategy_state_id,
+        ) {
+            warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                ?error,
+                action = "continuing without persisting snapshot",
+                "failed to persist Strategy state snapshot"
+            );
+        }
+    }
+
+    /// Feeds the Strategy every historical warm-up [`MarketEvent`] with order generation
+    /// suppressed (ie/ generated [`Signal`](crate::strategy::Signal)s are discarded), so
+    /// stateful strategies (eg/ indicators) are ready to trade as soon as the live [`Feed`]
+    /// starts.
+    fn warm_up(&mut self) {
+        if self.warm_up.is_empty() {
+            return;
+        }
+
+        let warm_up = std::mem::take(&mut self.warm_up);
+        let events = warm_up.len();
+
+        for market in warm_up {
+            // Discard Signals so no orders are generated during warm-up
+            let _ = self.strategy.generate_signals(&market);
+
+            self.warmed_up_to
+                .entry(MarketId::new(&market.exchange, &market.instrument))
+                .and_modify(|time| *time = (*time).max(market.exchange_time))
+                .or_insert(market.exchange_time);
+        }
+
+        info!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            events,
+            "Trader Strategy warmed up from historical MarketEvents"
+        );
+    }
+
+    /// Determines if the Strategy has already been fed the input [`MarketEvent`] during warm-up,
+    /// ie/ it is no newer than the latest warm-up [`MarketEvent`] of it's market.
+    fn is_warmed_up(&self, market: &MarketEvent<Instrument, DataKind>) -> bool {
+        self.warmed_up_to
+            .get(&MarketId::new(&market.exchange, &market.instrument))
+            .is_some_and(|warmed_up_to| market.exchange_time <= *warmed_up_to)
+    }
+
+    /// Advances the [`StrategyTimers`] (if any) clock to the exchange time of the input
+    /// [`MarketEvent`], sending an [`Event::Timer`] & queueing every [`Signal`] the Strategy
+    /// generates for each due [`Timer`].
+    fn fire_timers(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        let Some(timers) = &mut self.timers else {
+            return;
+        };
+
+        for timer in timers.timers.advance(market.exchange_time) {
+            debug!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                ?timer,
+                "Trader fired Strategy Timer"
+            );
+
+            let signals = (timers.on_timer)(&mut self.strategy, &timer, market);
+            self.event_tx.send(Event::Timer(timer));
+            for signal in signals {
+                self.event_tx.send(Event::Signal(signal.clone()));
+                self.event_q.push_back(Event::Signal(signal));
+            }
+        }
+    }
+
+    /// Updates the shared [`CircuitBreaker`] (if any) with a Portfolio [`Event`], sending an
+    /// [`Event::CircuitBreakerTripped`] if it caused the [`CircuitBreaker`] to trip.
+    fn update_circuit_breaker(&mut self, event: &Event) {
+        let Some(circuit_breaker) = &self.circuit_breaker else {
+            return;
+        };
+
+        if let Some(tripped) = circuit_breaker.lock().update(event) {
+            warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                ?tripped,
+                action = "halting entries & exiting all Positions",
+                "CircuitBreaker tripped"
+            );
+            self.event_tx.send(Event::CircuitBreakerTripped(tripped));
+        }
+    }
+
+    /// Determines if the shared [`CircuitBreaker`] (if any) is tripped.
+    fn circuit_breaker_tripped(&self) -> bool {
+        self.circuit_breaker
+            .as_ref()
+            .is_some_and(|circuit_breaker| circuit_breaker.lock().is_tripped())
+    }
+
+    /// Re-arms the shared [`CircuitBreaker`] (if any) if it is tripped, allowing new entries to
+    /// resume.
+    fn reset_circuit_breaker(&mut self) {
+        let Some(circuit_breaker) = &self.circuit_breaker else {
+            return;
+        };
+
+        let mut circuit_breaker = circuit_breaker.lock();
+        if circuit_breaker.is_tripped() {
+            circuit_breaker.reset();
+            info!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                action = "resuming entries",
+                "CircuitBreaker reset"
+            );
+        }
+    }
+
+    /// Exits this [`Trader`]'s open [`Position`](crate::portfolio::position::Position)s once per
+    /// [`CircuitBreaker`] trip.
+    fn action_circuit_breaker(&mut self) {
+        let Some(circuit_breaker) = &self.circuit_breaker else {
+            return;
+        };
+
+        let trips = circuit_breaker.lock().trips();
+        if trips > self.circuit_breaker_trips {
+            self.circuit_breaker_trips = trips;
+            self.event_q.extend(
+                self.markets
+                    .iter()
+                    .cloned()
+                    .map(|market| Event::SignalForceExit(SignalForceExit::from(market))),
+            );
+        }
+    }
+
+    /// Applies every [`FundingRate`](crate::portfolio::funding::FundingRate) in the
+    /// [`FundingSchedule`] that is due by the time of the input [`MarketEvent`] to the open
+    /// Position of it's market, sending an [`Event::FundingPayment`] for each payment.
+    fn apply_funding(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        let Some(funding_schedule) = &mut self.funding_schedule else {
+            return;
+        };
+
+        let due = funding_schedule.due(&market.exchange, &market.instrument, market.exchange_time);
+
+        for funding in due {
+            let funding_payment = self
+                .portfolio
+                .lock()
+                .update_from_funding(&funding)
+                .expect("failed to update Portfolio from funding");
+
+            if let Some(funding_payment) = funding_payment {
+                self.event_tx.send(Event::FundingPayment(funding_payment));
+            }
+        }
     }
 
     /// Returns a [`Command`] if one has been received.
@@ -270,17 +647,23 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>>,
-    Strategy: SignalGenerator,
+    Strategy: MultiMarketSignalGenerator,
     Execution: ExecutionClient,
 {
     engine_id: Option<Uuid>,
     market: Option<Market>,
+    markets: Option<Vec<Market>>,
     command_rx: Option<mpsc::Receiver<Command>>,
     event_tx: Option<EventTx>,
     portfolio: Option<Arc<Mutex<Portfolio>>>,
     data: Option<Data>,
     strategy: Option<Strategy>,
     execution: Option<Execution>,
+    circuit_breaker: Option<Arc<Mutex<CircuitBreaker>>>,
+    funding_schedule: Option<FundingSchedule>,
+    warm_up: Option<Vec<MarketEvent<Instrument, DataKind>>>,
+    strategy_state: Option<StrategyStatePersistence<Portfolio, Strategy>>,
+    timers: Option<StrategyTimers<Strategy>>,
     _statistic_marker: Option<PhantomData<Statistic>>,
 }
 
@@ -291,19 +674,25 @@ where
     Statistic: Serialize + Send,
     Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
     Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
+    Strategy: MultiMarketSignalGenerator + Send,
     Execution: ExecutionClient + Send,
 {
     fn new() -> Self {
         Self {
             engine_id: None,
             market: None,
+            markets: None,
             command_rx: None,
             event_tx: None,
             portfolio: None,
             data: None,
             strategy: None,
             execution: None,
+            circuit_breaker: None,
+            funding_schedule: None,
+            warm_up: None,
+            strategy_state: None,
+            timers: None,
             _statistic_marker: None,
         }
     }
@@ -322,6 +711,15 @@ where
         }
     }
 
+    /// Additional [`Market`]s traded by a multi-market strategy, fed by a merged [`MarketEvent`]
+    /// stream. The primary [`Market`] is always traded.
+    pub fn markets(self, value: Vec<Market>) -> Self {
+        Self {
+            markets: Some(value),
+            ..self
+        }
+    }
+
     pub fn command_rx(self, value: mpsc::Receiver<Command>) -> Self {
         Self {
             command_rx: Some(value),
@@ -364,16 +762,61 @@ where
         }
     }
 
+    pub fn circuit_breaker(self, value: Arc<Mutex<CircuitBreaker>>) -> Self {
+        Self {
+            circuit_breaker: Some(value),
+            ..self
+        }
+    }
+
+    pub fn funding_schedule(self, value: FundingSchedule) -> Self {
+        Self {
+            funding_schedule: Some(value),
+            ..self
+        }
+    }
+
+    /// Historical [`MarketEvent`]s (oldest first, eg/ recorded to file or backfilled klines)
+    /// fed to the Strategy before trading, with order generation suppressed. Live
+    /// [`MarketEvent`]s no newer than the warm-up are not fed to the Strategy again.
+    pub fn warm_up(self, value: Vec<MarketEvent<Instrument, DataKind>>) -> Self {
+        Self {
+            warm_up: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(
         self,
     ) -> Result<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
+        let market = self
+            .market
+            .ok_or(EngineError::BuilderIncomplete("market"))?;
+
+        let mut markets = vec![market.clone()];
+        for other in self.markets.into_iter().flatten() {
+            if !markets.contains(&other) {
+                markets.push(other);
+            }
+        }
+
+        // Every Market traded must be executable, else OrderEvents for it would be dropped
+        let execution = self
+            .execution
+            .ok_or(EngineError::BuilderIncomplete("execution"))?;
+        if let Some(market) = markets.iter().find(|market| !execution.routes(market)) {
+            return Err(EngineError::MarketNotRouted(MarketId::new(
+                &market.exchange,
+                &market.instrument,
+            )));
+        }
+
         Ok(Trader {
             engine_id: self
                 .engine_id
                 .ok_or(EngineError::BuilderIncomplete("engine_id"))?,
-            market: self
-                .market
-                .ok_or(EngineError::BuilderIncomplete("market"))?,
+            market,
+            markets,
             command_rx: self
                 .command_rx
                 .ok_or(EngineError::BuilderIncomplete("command_rx"))?,
@@ -388,10 +831,127 @@ where
             strategy: self
                 .strategy
                 .ok_or(EngineError::BuilderIncomplete("strategy"))?,
-            execution: self
-                .execution
-                .ok_or(EngineError::BuilderIncomplete("execution"))?,
+            execution,
+            circuit_breaker: self.circuit_breaker,
+            circuit_breaker_trips: 0,
+            funding_schedule: self.funding_schedule,
+            warm_up: self.warm_up.unwrap_or_default(),
+            warmed_up_to: HashMap::new(),
+            strategy_state: self.strategy_state,
+            timers: self.timers,
             _statistic_marker: PhantomData,
         })
     }
 }
+
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+    TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + StrategyStateHandler,
+    Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
+    Strategy: MultiMarketSignalGenerator + StrategyState + Send,
+    Execution: ExecutionClient + Send,
+{
+    /// Restore the [`StrategyState`] from the Portfolio repository at startup, and persist
+    /// snapshots to it every interval & on shutdown.
+    pub fn strategy_state(self, interval: Duration) -> Self {
+        Self {
+            strategy_state: Some(StrategyStatePersistence::new(interval)),
+            ..self
+        }
+    }
+}
+
+/// Persists & restores snapshots of a [`StrategyState`] Strategy via a Portfolio that implements
+/// [`StrategyStateHandler`].
+#[derive(Debug)]
+pub struct StrategyStatePersistence<Portfolio, Strategy> {
+    interval: Duration,
+    persisted: Instant,
+    persist: fn(&Strategy, &mut Portfolio, &StrategyStateId) -> Result<(), EngineError>,
+    restore: fn(&mut Strategy, &mut Portfolio, &StrategyStateId) -> Result<bool, EngineError>,
+}
+
+impl<Portfolio, Strategy> StrategyStatePersistence<Portfolio, Strategy>
+where
+    Portfolio: StrategyStateHandler,
+    Strategy: StrategyState,
+{
+    /// Constructs a new [`StrategyStatePersistence`] that persists snapshots every interval.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            persisted: Instant::now(),
+            persist: Self::persist,
+            restore: Self::restore,
+        }
+    }
+
+    /// Persist a snapshot of the Strategy state.
+    fn persist(
+        strategy: &Strategy,
+        portfolio: &mut Portfolio,
+        strategy_state_id: &StrategyStateId,
+    ) -> Result<(), EngineError> {
+        let state = serde_json::to_value(strategy.snapshot()).map_err(RepositoryError::from)?;
+        portfolio.set_strategy_state(strategy_state_id, state)?;
+        Ok(())
+    }
+
+    /// Restore the Strategy state from the latest snapshot, returning true if one exists.
+    fn restore(
+        strategy: &mut Strategy,
+        portfolio: &mut Portfolio,
+        strategy_state_id: &StrategyStateId,
+    ) -> Result<bool, EngineError> {
+        let Some(state) = portfolio.get_strategy_state(strategy_state_id)? else {
+            return Ok(false);
+        };
+
+        strategy.restore(serde_json::from_value(state).map_err(RepositoryError::from)?);
+        Ok(true)
+    }
+}
+
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+    TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
+    Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send,
+    Strategy: MultiMarketSignalGenerator + TimerHandler + Send,
+    Execution: ExecutionClient + Send,
+{
+    /// [`Timer`]s delivered to the [`TimerHandler`] Strategy. Timers run on the exchange time of
+    /// the live [`MarketEvent`]s, firing before the first [`MarketEvent`] at or after each
+    /// scheduled time, so backtests & live trading fire identically.
+    pub fn timers(self, value: Vec<Timer>) -> Self {
+        Self {
+            timers: Some(StrategyTimers::new(value)),
+            ..self
+        }
+    }
+}
+
+/// Fires the [`Timer`]s of a [`TimerHandler`] Strategy.
+#[derive(Debug)]
+pub struct StrategyTimers<Strategy> {
+    timers: Timers,
+    on_timer: fn(&mut Strategy, &TimerEvent, &MarketEvent<Instrument, DataKind>) -> Vec<Signal>,
+}
+
+impl<Strategy> StrategyTimers<Strategy>
+where
+    Strategy: TimerHandler,
+{
+    /// Constructs a new [`StrategyTimers`] from the provided [`Timer`]s.
+    pub fn new(timers: Vec<Timer>) -> Self {
+        Self {
+            timers: Timers::new(timers),
+            on_timer: Strategy::on_timer,
+        }
+    }
+}
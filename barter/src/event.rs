@@ -1,10 +1,15 @@
 use crate::{
+    engine::circuit_breaker::CircuitBreakerTripped,
     execution::FillEvent,
     portfolio::{
+        exit::ExitTriggered,
+        funding::FundingPayment,
+        margin::MarginCall,
         position::{Position, PositionExit, PositionUpdate},
+        risk::OrderRejected,
         Balance, OrderEvent,
     },
-    strategy::{Signal, SignalForceExit},
+    strategy::{timer::TimerEvent, Signal, SignalForceExit},
 };
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::instrument::Instrument;
@@ -20,15 +25,21 @@ use tracing::warn;
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Event {
     Market(MarketEvent<Instrument, DataKind>),
+    Timer(TimerEvent),
     Signal(Signal),
     SignalForceExit(SignalForceExit),
     OrderNew(OrderEvent),
+    OrderRejected(OrderRejected),
     OrderUpdate,
     Fill(FillEvent),
     PositionNew(Position),
     PositionUpdate(PositionUpdate),
     PositionExit(PositionExit),
     Balance(Balance),
+    MarginCall(MarginCall),
+    ExitTriggered(ExitTriggered),
+    FundingPayment(FundingPayment),
+    CircuitBreakerTripped(CircuitBreakerTripped),
 }
 
 /// Message transmitter for sending Barter messages to downstream consumers.
@@ -1,8 +1,12 @@
+use barter_integration::model::MarketId;
 use thiserror::Error;
 
 /// All errors generated in the barter::execution module.
-#[derive(Error, Copy, Clone, Debug)]
+#[derive(Error, Clone, Debug)]
 pub enum ExecutionError {
     #[error("Failed to build struct due to missing attributes: {0}")]
     BuilderIncomplete(&'static str),
+
+    #[error("No execution handler is routed for market: {0}")]
+    MarketNotRouted(MarketId),
 }
@@ -1,5 +1,5 @@
 use crate::{data::MarketMeta, portfolio::OrderEvent, strategy::Decision};
-use barter_integration::model::{instrument::Instrument, Exchange};
+use barter_integration::model::{instrument::Instrument, Exchange, Market};
 use chrono::{DateTime, Utc};
 use error::ExecutionError;
 use serde::{Deserialize, Serialize};
@@ -10,10 +10,20 @@ pub mod error;
 /// Handlers for simulated and live [`OrderEvent`] execution.
 pub mod simulated;
 
+/// [`ExecutionRouter`](router::ExecutionRouter) that routes each [`OrderEvent`] to the execution
+/// handler of it's market.
+pub mod router;
+
 /// Generates a result [`FillEvent`] by executing an [`OrderEvent`].
 pub trait ExecutionClient {
     /// Return a [`FillEvent`] from executing the input [`OrderEvent`].
     fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError>;
+
+    /// Determines if [`OrderEvent`]s of the provided [`Market`] can be executed. Defaults to true
+    /// for [`ExecutionClient`]s that execute every market.
+    fn routes(&self, _: &Market) -> bool {
+        true
+    }
 }
 
 /// Fills are journals of work done by an Execution handler. These are sent back to the portfolio
@@ -0,0 +1,121 @@
+use crate::{
+    execution::{error::ExecutionError, ExecutionClient, FillEvent},
+    portfolio::OrderEvent,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Market, MarketId};
+use std::collections::HashMap;
+
+/// Execution handler that routes each [`OrderEvent`] to the [`ExecutionClient`] of it's market,
+/// enabling a single [`Trader`](crate::engine::trader::Trader) running a multi-market strategy
+/// to execute on many venues (eg/ cross-exchange spreads).
+#[derive(Clone, Debug)]
+pub struct ExecutionRouter<Execution>
+where
+    Execution: ExecutionClient,
+{
+    executions: HashMap<MarketId, Execution>,
+}
+
+impl<Execution> Default for ExecutionRouter<Execution>
+where
+    Execution: ExecutionClient,
+{
+    fn default() -> Self {
+        Self {
+            executions: HashMap::new(),
+        }
+    }
+}
+
+impl<Execution> ExecutionClient for ExecutionRouter<Execution>
+where
+    Execution: ExecutionClient,
+{
+    fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
+        let market_id = MarketId::new(&order.exchange, &order.instrument);
+
+        self.executions
+            .get(&market_id)
+            .ok_or(ExecutionError::MarketNotRouted(market_id))?
+            .generate_fill(order)
+    }
+
+    fn routes(&self, market: &Market) -> bool {
+        self.executions
+            .contains_key(&MarketId::new(&market.exchange, &market.instrument))
+    }
+}
+
+impl<Execution> ExecutionRouter<Execution>
+where
+    Execution: ExecutionClient,
+{
+    /// Constructs a new [`ExecutionRouter`] with no markets routed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Routes every [`OrderEvent`] of the provided market to the provided [`ExecutionClient`].
+    pub fn route(
+        mut self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+        execution: Execution,
+    ) -> Self {
+        self.executions
+            .insert(MarketId::new(exchange, instrument), execution);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        execution::{
+            simulated::{Config, SimulatedExecution},
+            Fees,
+        },
+        test_util::order_event,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_execution_router_generate_fill() {
+        let execution = |exchange_fee| {
+            SimulatedExecution::new(Config {
+                simulated_fees_pct: Fees {
+                    exchange: exchange_fee,
+                    slippage: 0.0,
+                    network: 0.0,
+                },
+            })
+        };
+
+        let instrument = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+        let router = ExecutionRouter::new()
+            .route(&Exchange::from("binance"), &instrument, execution(0.5))
+            .route(&Exchange::from("kraken"), &instrument, execution(0.25));
+
+        let mut order = order_event();
+        order.instrument = instrument.clone();
+        order.quantity = 1.0;
+        order.market_meta.close = 100.0;
+
+        // OrderEvent for each market is executed by it's routed ExecutionClient
+        order.exchange = Exchange::from("binance");
+        assert!(router.routes(&Market::new(order.exchange.clone(), instrument.clone())));
+        assert_eq!(router.generate_fill(&order).unwrap().fees.exchange, 50.0);
+
+        order.exchange = Exchange::from("kraken");
+        assert_eq!(router.generate_fill(&order).unwrap().fees.exchange, 25.0);
+
+        // OrderEvent for an un-routed market is rejected
+        order.exchange = Exchange::from("coinbase");
+        assert!(!router.routes(&Market::new(order.exchange.clone(), instrument.clone())));
+        assert!(matches!(
+            router.generate_fill(&order),
+            Err(ExecutionError::MarketNotRouted(_))
+        ));
+    }
+}
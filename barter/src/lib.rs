@@ -21,7 +21,8 @@
 //! heartbeat. For example, a LiveCandleHandler implementation is provided utilising [`Barter-Data`]'s WebSocket functionality to
 //! provide a live market Candle data feed to the system.
 //! * **Strategy**: The SignalGenerator trait governs potential generation of SignalEvents after analysing incoming
-//! MarketEvents. SignalEvents are advisory signals sent to the Portfolio for analysis.
+//! MarketEvents. SignalEvents are advisory signals sent to the Portfolio for analysis. The MultiMarketSignalGenerator
+//! trait governs portfolio-level strategies (eg/ pairs trading) that observe a merged multi-market MarketEvent feed.
 //! * **Portfolio**: MarketUpdater, OrderGenerator, and FillUpdater govern global state Portfolio implementations. A
 //! Portfolio may generate OrderEvents after receiving advisory SignalEvents from a Strategy. The Portfolio's state
 //! updates after receiving MarketEvents and FillEvents.
@@ -31,7 +32,8 @@
 //! * **Statistic**: Provides metrics such as Sharpe Ratio, Calmar Ratio, and Max Drawdown to analyse trading session
 //! performance. One-pass dispersion algorithms analyse each closed Position and efficiently calculates a trading summary.
 //! * **Trader**: Capable of trading a single market pair using a customisable selection of it's own Data, Strategy &
-//! Execution instances, as well as shared access to a global Portfolio.
+//! Execution instances, as well as shared access to a global Portfolio. A Trader running a multi-market strategy may
+//! route each market's OrderEvents to it's own Execution instance via an ExecutionRouter.
 //! * **Engine**: Multi-threaded trading Engine capable of trading with an arbitrary number of Trader market pairs. Each
 //! contained Trader instance operates on its own thread.
 //!
@@ -101,10 +103,14 @@
 //! let components = PortfolioLego {
 //!     engine_id: Uuid::new_v4(),
 //!     markets: vec![Market::new("binance", ("btc", "usdt", InstrumentKind::Spot))],
+//!     margin: Default::default(),
+//!     exit_rules: Default::default(),
+//!     rebalance_tolerance: 0.0,
 //!     repository: InMemoryRepository::new(),
 //!     allocator: DefaultAllocator{ default_order_value: 100.0 },
 //!     risk: DefaultRisk{},
 //!     starting_cash: 10000.0,
+//!     reporting_currency: None,
 //!     statistic_config: StatisticConfig {
 //!         starting_equity: 10000.0 ,
 //!         trading_days_per_year: 365,
@@ -308,6 +314,9 @@ pub mod test_util {
             instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
             signals: Default::default(),
             market_meta: Default::default(),
+            exit_rules: None,
+            target: None,
+            attribution: vec![],
         }
     }
 
@@ -359,6 +368,12 @@ pub mod test_util {
             current_value_gross: 100.0,
             unrealised_profit_loss: 0.0,
             realised_profit_loss: 0.0,
+            margin: 100.0,
+            liquidation_price: None,
+            funding_total: 0.0,
+            exit_rules: Default::default(),
+            peak_price: None,
+            attribution: vec![],
         }
     }
 }
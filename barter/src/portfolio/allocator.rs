@@ -1,34 +1,47 @@
 use crate::{
-    portfolio::{position::Position, OrderEvent},
+    portfolio::{position::Position, OrderEvent, PortfolioSnapshot},
+    statistic::summary::pnl::PnLReturnSummary,
     strategy::{Decision, SignalStrength},
 };
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::{instrument::Instrument, MarketId};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 
-/// Allocates an appropriate [`OrderEvent`] quantity.
-pub trait OrderAllocator {
+/// Allocates an appropriate [`OrderEvent`] quantity, given a read-only [`PortfolioSnapshot`]
+/// containing the Portfolio `Statistic` of every market.
+pub trait OrderAllocator<Statistic> {
     /// Returns an [`OrderEvent`] with a calculated order quantity based on the input order,
-    /// [`SignalStrength`] and potential existing [`Position`].
+    /// [`SignalStrength`], potential existing [`Position`] and the [`PortfolioSnapshot`] (eg/
+    /// equity, open positions across markets).
     fn allocate_order(
         &self,
         order: &mut OrderEvent,
         position: Option<&Position>,
         signal_strength: SignalStrength,
+        portfolio: &PortfolioSnapshot<Statistic>,
     );
+
+    /// Updates any allocation state derived from market data (eg/ volatility estimates). Called
+    /// by the Portfolio for every [`MarketEvent`].
+    fn update_from_market(&mut self, _: &MarketEvent<Instrument, DataKind>) {}
 }
 
 /// Default allocation manager that implements [`OrderAllocator`]. Order size is calculated by
-/// using the default_order_value, symbol close value, and [`SignalStrength`].
+/// using the default_order_value (denominated in the quote currency), symbol close value, and
+/// [`SignalStrength`].
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct DefaultAllocator {
     pub default_order_value: f64,
 }
 
-impl OrderAllocator for DefaultAllocator {
+impl<Statistic> OrderAllocator<Statistic> for DefaultAllocator {
     fn allocate_order(
         &self,
         order: &mut OrderEvent,
         position: Option<&Position>,
         signal_strength: SignalStrength,
+        _: &PortfolioSnapshot<Statistic>,
     ) {
         // Calculate exact order_size, then round it to a more appropriate decimal place
         let default_order_size = self.default_order_value / order.market_meta.close;
@@ -47,10 +60,410 @@ impl OrderAllocator for DefaultAllocator {
     }
 }
 
+/// Allocation manager that implements [`OrderAllocator`] by sizing orders as a fixed fraction of
+/// the current Portfolio [`equity`](PortfolioSnapshot::equity), scaled by the [`SignalStrength`].
+///
+/// Like every equity-based allocator, the order value is denominated in the currency the
+/// [`PortfolioSnapshot`] is valued in, so is sized using the close price converted from the quote
+/// currency (see [`PortfolioSnapshot::to_reporting`]). Markets without a conversion rate are
+/// allocated a zero quantity.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct FixedFractionalAllocator {
+    /// Fraction of equity allocated to each entry order (eg/ 0.02 for 2%).
+    pub fraction: f64,
+}
+
+impl<Statistic> OrderAllocator<Statistic> for FixedFractionalAllocator {
+    fn allocate_order(
+        &self,
+        order: &mut OrderEvent,
+        position: Option<&Position>,
+        signal_strength: SignalStrength,
+        portfolio: &PortfolioSnapshot<Statistic>,
+    ) {
+        let order_value = portfolio.equity() * self.fraction * signal_strength.0;
+        allocate_order_value(order, position, order_value, portfolio)
+    }
+}
+
+/// Allocation manager that implements [`OrderAllocator`] by sizing orders so that each
+/// [`Position`] contributes the target volatility to the Portfolio equity.
+///
+/// Order value = equity * target_volatility / market volatility, capped at
+/// equity * max_leverage and scaled by the [`SignalStrength`]. Markets without enough data to
+/// estimate their volatility are allocated a zero quantity.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct VolatilityTargetAllocator {
+    /// Target volatility of each [`Position`], as a fraction of equity per estimator period.
+    pub target_volatility: f64,
+    /// Maximum order value as a multiple of equity.
+    pub max_leverage: f64,
+    pub estimator: VolatilityEstimator,
+    #[serde(skip)]
+    volatility: HashMap<MarketId, MarketVolatility>,
+}
+
+impl VolatilityTargetAllocator {
+    /// Constructs a new [`VolatilityTargetAllocator`].
+    pub fn new(target_volatility: f64, max_leverage: f64, estimator: VolatilityEstimator) -> Self {
+        Self {
+            target_volatility,
+            max_leverage,
+            estimator,
+            volatility: HashMap::new(),
+        }
+    }
+}
+
+impl<Statistic> OrderAllocator<Statistic> for VolatilityTargetAllocator {
+    fn allocate_order(
+        &self,
+        order: &mut OrderEvent,
+        position: Option<&Position>,
+        signal_strength: SignalStrength,
+        portfolio: &PortfolioSnapshot<Statistic>,
+    ) {
+        let order_value = self
+            .volatility
+            .get(&MarketId::new(&order.exchange, &order.instrument))
+            .and_then(|volatility| volatility.volatility(self.estimator))
+            .map_or(0.0, |volatility| {
+                let order_value = portfolio.equity() * self.target_volatility / volatility;
+                order_value.min(portfolio.equity() * self.max_leverage) * signal_strength.0
+            });
+
+        allocate_order_value(order, position, order_value, portfolio)
+    }
+
+    fn update_from_market(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        self.volatility
+            .entry(MarketId::new(&market.exchange, &market.instrument))
+            .or_default()
+            .update(self.estimator, &market.kind);
+    }
+}
+
+/// Allocation manager that implements [`OrderAllocator`] by sizing orders as a fraction of the
+/// Kelly criterion, using the win rate & payoff ratio of each market's exited [`Position`]
+/// returns, read from the [`PnLReturnSummary`] of the market statistics persisted by the
+/// Portfolio.
+///
+/// Kelly fraction = win_rate - (1 - win_rate) / payoff_ratio. Markets with fewer than
+/// `min_trades` exited [`Position`]s, or a non-positive Kelly fraction, are allocated a zero
+/// quantity.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KellyAllocator {
+    /// Multiplier applied to the full Kelly fraction (eg/ 0.5 for "half Kelly").
+    pub kelly_multiplier: f64,
+    /// Maximum fraction of equity allocated to a single entry order.
+    pub max_fraction: f64,
+    /// Minimum number of exited [`Position`]s in a market before allocating to it.
+    pub min_trades: u64,
+}
+
+impl KellyAllocator {
+    /// Constructs a new [`KellyAllocator`].
+    pub fn new(kelly_multiplier: f64, max_fraction: f64, min_trades: u64) -> Self {
+        Self {
+            kelly_multiplier,
+            max_fraction,
+            min_trades,
+        }
+    }
+
+    /// Calculates the full Kelly fraction from a [`PnLReturnSummary`], if it contains at least
+    /// `min_trades` and both winning & losing returns.
+    pub fn kelly_fraction(&self, summary: &PnLReturnSummary) -> Option<f64> {
+        let trades = summary.total.count;
+        let losses = summary.losses.count;
+        if trades < self.min_trades.max(1) || losses == 0 || losses == trades {
+            return None;
+        }
+
+        let win_rate = (trades - losses) as f64 / trades as f64;
+        let average_win = (summary.total.sum - summary.losses.sum) / (trades - losses) as f64;
+        let average_loss = -summary.losses.mean;
+        let payoff_ratio = average_win / average_loss;
+
+        Some(win_rate - (1.0 - win_rate) / payoff_ratio)
+    }
+}
+
+impl<Statistic> OrderAllocator<Statistic> for KellyAllocator
+where
+    Statistic: AsRef<PnLReturnSummary>,
+{
+    fn allocate_order(
+        &self,
+        order: &mut OrderEvent,
+        position: Option<&Position>,
+        signal_strength: SignalStrength,
+        portfolio: &PortfolioSnapshot<Statistic>,
+    ) {
+        let fraction = portfolio
+            .statistic(&order.exchange, &order.instrument)
+            .and_then(|statistic| self.kelly_fraction(statistic.as_ref()))
+            .map_or(0.0, |kelly| {
+                (kelly * self.kelly_multiplier).clamp(0.0, self.max_fraction)
+            });
+
+        allocate_order_value(
+            order,
+            position,
+            portfolio.equity() * fraction * signal_strength.0,
+            portfolio,
+        )
+    }
+}
+
+/// Allocation manager that implements [`OrderAllocator`] by sizing orders so that every market
+/// contributes equal volatility (risk parity), using inverse volatility weights across every
+/// market with a volatility estimate.
+///
+/// Order value = equity * gross_leverage * weight, scaled by the [`SignalStrength`], where
+/// weight = (1 / market volatility) / sum(1 / volatility of every market).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct RiskParityAllocator {
+    /// Total value allocated across every market, as a multiple of equity.
+    pub gross_leverage: f64,
+    pub estimator: VolatilityEstimator,
+    #[serde(skip)]
+    volatility: HashMap<MarketId, MarketVolatility>,
+}
+
+impl RiskParityAllocator {
+    /// Constructs a new [`RiskParityAllocator`].
+    pub fn new(gross_leverage: f64, estimator: VolatilityEstimator) -> Self {
+        Self {
+            gross_leverage,
+            estimator,
+            volatility: HashMap::new(),
+        }
+    }
+
+    /// Calculates the inverse volatility weight of a market, if its volatility can be estimated.
+    pub fn weight(&self, market_id: &MarketId) -> Option<f64> {
+        let inverse_volatility =
+            1.0 / self.volatility.get(market_id)?.volatility(self.estimator)?;
+
+        let total_inverse_volatility = self
+            .volatility
+            .values()
+            .filter_map(|volatility| volatility.volatility(self.estimator))
+            .map(|volatility| 1.0 / volatility)
+            .sum::<f64>();
+
+        Some(inverse_volatility / total_inverse_volatility)
+    }
+}
+
+impl<Statistic> OrderAllocator<Statistic> for RiskParityAllocator {
+    fn allocate_order(
+        &self,
+        order: &mut OrderEvent,
+        position: Option<&Position>,
+        signal_strength: SignalStrength,
+        portfolio: &PortfolioSnapshot<Statistic>,
+    ) {
+        let order_value = self
+            .weight(&MarketId::new(&order.exchange, &order.instrument))
+            .map_or(0.0, |weight| {
+                portfolio.equity() * self.gross_leverage * weight * signal_strength.0
+            });
+
+        allocate_order_value(order, position, order_value, portfolio)
+    }
+
+    fn update_from_market(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        self.volatility
+            .entry(MarketId::new(&market.exchange, &market.instrument))
+            .or_default()
+            .update(self.estimator, &market.kind);
+    }
+}
+
+/// Allocation manager that wraps another [`OrderAllocator`], capping the quantity of entry orders
+/// so the Portfolio gross exposure (ie/ the absolute value of every open [`Position`] plus the
+/// new order) never exceeds `max_gross_leverage` multiplied by the Portfolio
+/// [`equity`](PortfolioSnapshot::equity).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ExposureCappedAllocator<Allocator> {
+    pub allocator: Allocator,
+    /// Maximum gross exposure, as a multiple of equity.
+    pub max_gross_leverage: f64,
+}
+
+impl<Allocator, Statistic> OrderAllocator<Statistic> for ExposureCappedAllocator<Allocator>
+where
+    Allocator: OrderAllocator<Statistic>,
+{
+    fn allocate_order(
+        &self,
+        order: &mut OrderEvent,
+        position: Option<&Position>,
+        signal_strength: SignalStrength,
+        portfolio: &PortfolioSnapshot<Statistic>,
+    ) {
+        self.allocator
+            .allocate_order(order, position, signal_strength, portfolio);
+
+        // Exits always reduce exposure, so are never capped
+        if order.decision.is_exit() {
+            return;
+        }
+
+        let remaining_exposure =
+            (portfolio.equity() * self.max_gross_leverage - portfolio.gross_exposure()).max(0.0);
+        let max_quantity = portfolio
+            .to_reporting(&order.instrument, order.market_meta.close)
+            .map_or(0.0, |price| remaining_exposure / price);
+        let max_quantity = (max_quantity * 10000.0).floor() / 10000.0;
+
+        order.quantity = order.quantity.clamp(-max_quantity, max_quantity);
+    }
+
+    fn update_from_market(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        self.allocator.update_from_market(market)
+    }
+}
+
+/// Sets the [`OrderEvent`] quantity to enter with the provided order value at the
+/// `market_meta.close` price (converted into the currency the [`PortfolioSnapshot`] is valued
+/// in), or to exit the entire existing [`Position`].
+fn allocate_order_value<Statistic>(
+    order: &mut OrderEvent,
+    position: Option<&Position>,
+    order_value: f64,
+    portfolio: &PortfolioSnapshot<Statistic>,
+) {
+    // Calculate exact order_size, then round it to a more appropriate decimal place
+    let order_size = portfolio
+        .to_reporting(&order.instrument, order.market_meta.close)
+        .map_or(0.0, |price| order_value.max(0.0) / price);
+    let order_size = (order_size * 10000.0).floor() / 10000.0;
+
+    match order.decision {
+        // Entry
+        Decision::Long => order.quantity = order_size,
+
+        // Entry
+        Decision::Short => order.quantity = -order_size,
+
+        // Exit
+        _ => order.quantity = 0.0 - position.as_ref().unwrap().quantity,
+    }
+}
+
+/// Method of estimating a market's volatility as a fraction of its price, over a rolling
+/// window of market events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum VolatilityEstimator {
+    /// Sample standard deviation of the log returns between consecutive closes.
+    Realised { window: usize },
+    /// Average True Range divided by the latest close. The true range of a non-candle market
+    /// event is the absolute change from the previous close.
+    Atr { window: usize },
+}
+
+impl VolatilityEstimator {
+    fn window(&self) -> usize {
+        match self {
+            VolatilityEstimator::Realised { window } | VolatilityEstimator::Atr { window } => {
+                *window
+            }
+        }
+    }
+}
+
+/// Rolling state used to estimate a single market's volatility with a [`VolatilityEstimator`].
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MarketVolatility {
+    close: Option<f64>,
+    samples: VecDeque<f64>,
+}
+
+impl MarketVolatility {
+    /// Updates the rolling window of samples from the next market event.
+    pub fn update(&mut self, estimator: VolatilityEstimator, kind: &DataKind) {
+        let (close, high, low) = match kind {
+            DataKind::Trade(trade) => (trade.price, trade.price, trade.price),
+            DataKind::Candle(candle) => (candle.close, candle.high, candle.low),
+            DataKind::OrderBookL1(book_l1) => {
+                let mid = book_l1.volume_weighed_mid_price();
+                (mid, mid, mid)
+            }
+            DataKind::OrderBook(book) => match book.volume_weighed_mid_price() {
+                Some(mid) => (mid, mid, mid),
+                None => return,
+            },
+            DataKind::Liquidation(_) => return,
+        };
+
+        if let Some(previous) = self.close.replace(close) {
+            let sample = match estimator {
+                VolatilityEstimator::Realised { .. } => (close / previous).ln(),
+                VolatilityEstimator::Atr { .. } => (high - low)
+                    .max((high - previous).abs())
+                    .max((low - previous).abs()),
+            };
+
+            if self.samples.len() == estimator.window() {
+                self.samples.pop_front();
+            }
+            self.samples.push_back(sample);
+        }
+    }
+
+    /// Estimates the volatility as a fraction of price, if the rolling window is full.
+    pub fn volatility(&self, estimator: VolatilityEstimator) -> Option<f64> {
+        let window = estimator.window();
+        if window == 0 || self.samples.len() < window {
+            return None;
+        }
+
+        let count = self.samples.len() as f64;
+        let mean = self.samples.iter().sum::<f64>() / count;
+        let volatility = match estimator {
+            VolatilityEstimator::Realised { .. } if window < 2 => return None,
+            VolatilityEstimator::Realised { .. } => {
+                let variance = self
+                    .samples
+                    .iter()
+                    .map(|sample| (sample - mean).powi(2))
+                    .sum::<f64>()
+                    / (count - 1.0);
+                variance.sqrt()
+            }
+            VolatilityEstimator::Atr { .. } => mean / self.close?,
+        };
+
+        (volatility > 0.0).then_some(volatility)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_util::{order_event, position};
+    use crate::{
+        portfolio::{currency::FxRates, Balance},
+        statistic::summary::PositionSummariser,
+        test_util::{market_event_trade, order_event, position},
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Exchange, Side};
+    use chrono::Utc;
+    use std::collections::HashMap;
+
+    fn snapshot(total: f64, open_positions: Vec<Position>) -> PortfolioSnapshot<()> {
+        PortfolioSnapshot {
+            time: Utc::now(),
+            balance: Balance::new(Utc::now(), total, total),
+            open_positions,
+            day_start_equity: total,
+            prices: HashMap::new(),
+            statistics: HashMap::new(),
+            fx_rates: None,
+        }
+    }
 
     #[test]
     fn should_allocate_order_to_exit_open_long_position() {
@@ -70,6 +483,7 @@ mod tests {
             &mut input_order,
             Some(&input_position),
             input_signal_strength,
+            &snapshot(0.0, vec![]),
         );
 
         let actual_result = input_order.quantity;
@@ -96,6 +510,7 @@ mod tests {
             &mut input_order,
             Some(&input_position),
             input_signal_strength,
+            &snapshot(0.0, vec![]),
         );
 
         let actual_result = input_order.quantity;
@@ -118,7 +533,12 @@ mod tests {
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            input_signal_strength,
+            &snapshot(0.0, vec![]),
+        );
 
         let actual_result = input_order.quantity;
         let expected_result = (default_order_value / order_close) * input_signal_strength.0 as f64;
@@ -140,7 +560,12 @@ mod tests {
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            input_signal_strength,
+            &snapshot(0.0, vec![]),
+        );
 
         let actual_result = input_order.quantity;
         let expected_order_size = ((default_order_value / order_close) * 10000.0).floor() / 10000.0;
@@ -164,7 +589,12 @@ mod tests {
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            input_signal_strength,
+            &snapshot(0.0, vec![]),
+        );
 
         let actual_result = input_order.quantity;
         let expected_result = -(default_order_value / order_close) * input_signal_strength.0 as f64;
@@ -186,7 +616,12 @@ mod tests {
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            input_signal_strength,
+            &snapshot(0.0, vec![]),
+        );
 
         let actual_result = input_order.quantity;
         let expected_order_size = ((default_order_value / order_close) * 10000.0).floor() / 10000.0;
@@ -195,4 +630,379 @@ mod tests {
         assert_ne!(actual_result, 0.0);
         assert_eq!(actual_result, expected_result)
     }
+
+    fn market_event(base: &str, price: f64) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.exchange = Exchange::from("binance");
+        market.instrument = Instrument::from((base, "usdt", InstrumentKind::Spot));
+        if let DataKind::Trade(trade) = &mut market.kind {
+            trade.price = price;
+        }
+        market
+    }
+
+    fn entry_order(base: &str, close: f64) -> OrderEvent {
+        let mut order = order_event();
+        order.instrument = Instrument::from((base, "usdt", InstrumentKind::Spot));
+        order.market_meta.close = close;
+        order.decision = Decision::Long;
+        order
+    }
+
+    #[test]
+    fn test_market_volatility() {
+        struct TestCase {
+            estimator: VolatilityEstimator,
+            prices: Vec<f64>,
+            expected: Option<f64>,
+        }
+
+        let returns = [(110.0f64 / 100.0).ln(), (99.0f64 / 110.0).ln()];
+        let mean = (returns[0] + returns[1]) / 2.0;
+        let realised = ((returns[0] - mean).powi(2) + (returns[1] - mean).powi(2)).sqrt();
+
+        let tests = vec![
+            TestCase {
+                // TC0: Realised window not full
+                estimator: VolatilityEstimator::Realised { window: 2 },
+                prices: vec![100.0, 110.0],
+                expected: None,
+            },
+            TestCase {
+                // TC1: Realised sample std. dev. of log returns
+                estimator: VolatilityEstimator::Realised { window: 2 },
+                prices: vec![100.0, 110.0, 99.0],
+                expected: Some(realised),
+            },
+            TestCase {
+                // TC2: ATR of trades is the mean absolute price change over the latest close
+                estimator: VolatilityEstimator::Atr { window: 2 },
+                prices: vec![90.0, 100.0, 110.0, 99.0],
+                expected: Some(10.5 / 99.0),
+            },
+            TestCase {
+                // TC3: constant prices have no volatility
+                estimator: VolatilityEstimator::Atr { window: 2 },
+                prices: vec![100.0, 100.0, 100.0],
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut volatility = MarketVolatility::default();
+            for price in test.prices {
+                volatility.update(test.estimator, &market_event("eth", price).kind);
+            }
+
+            let actual = volatility.volatility(test.estimator);
+            match (actual, test.expected) {
+                (Some(actual), Some(expected)) => {
+                    assert!((actual - expected).abs() < 1e-12, "TC{} failed", index)
+                }
+                (actual, expected) => assert_eq!(actual, expected, "TC{} failed", index),
+            }
+        }
+    }
+
+    #[test]
+    fn test_equity_based_allocators() {
+        struct TestCase {
+            allocator: Box<dyn OrderAllocator<PnLReturnSummary>>,
+            expected_quantity: f64,
+        }
+
+        let markets = [
+            ("eth", vec![100.0, 110.0, 100.0]),
+            ("btc", vec![100.0, 120.0, 100.0]),
+        ];
+
+        let exits = [10.0, -10.0, 10.0, 10.0].map(|realised_profit_loss| {
+            let mut exit = position();
+            exit.realised_profit_loss = realised_profit_loss;
+            exit
+        });
+
+        let tests = vec![
+            TestCase {
+                // TC0: FixedFractional allocates 10% of equity
+                allocator: Box::new(FixedFractionalAllocator { fraction: 0.1 }),
+                expected_quantity: 1.0,
+            },
+            TestCase {
+                // TC1: VolatilityTarget without enough market data allocates nothing
+                allocator: Box::new(VolatilityTargetAllocator::new(
+                    0.01,
+                    1.0,
+                    VolatilityEstimator::Atr { window: 5 },
+                )),
+                expected_quantity: 0.0,
+            },
+            TestCase {
+                // TC2: VolatilityTarget allocates target / ATR (0.01 / 0.1) of equity
+                allocator: Box::new(VolatilityTargetAllocator::new(
+                    0.01,
+                    1.0,
+                    VolatilityEstimator::Atr { window: 2 },
+                )),
+                expected_quantity: 1.0,
+            },
+            TestCase {
+                // TC3: VolatilityTarget allocation is capped by max_leverage
+                allocator: Box::new(VolatilityTargetAllocator::new(
+                    0.5,
+                    2.0,
+                    VolatilityEstimator::Atr { window: 2 },
+                )),
+                expected_quantity: 20.0,
+            },
+            TestCase {
+                // TC4: half Kelly of (3/4 - (1/4) / 1) = 0.25 of equity
+                allocator: Box::new(KellyAllocator::new(0.5, 1.0, 4)),
+                expected_quantity: 2.5,
+            },
+            TestCase {
+                // TC5: Kelly allocation is capped by max_fraction
+                allocator: Box::new(KellyAllocator::new(0.5, 0.2, 4)),
+                expected_quantity: 2.0,
+            },
+            TestCase {
+                // TC6: Kelly with fewer than min_trades allocates nothing
+                allocator: Box::new(KellyAllocator::new(0.5, 1.0, 5)),
+                expected_quantity: 0.0,
+            },
+            TestCase {
+                // TC7: RiskParity weights eth (ATR 0.1) 2/3 & btc (ATR 0.2) 1/3
+                allocator: Box::new(RiskParityAllocator::new(
+                    1.5,
+                    VolatilityEstimator::Atr { window: 2 },
+                )),
+                expected_quantity: 10.0,
+            },
+        ];
+
+        // Market statistics persisted by the Portfolio for every exited eth Position
+        let mut statistic = PnLReturnSummary::default();
+        statistic.generate_summary(&exits);
+
+        let portfolio = PortfolioSnapshot {
+            time: Utc::now(),
+            balance: Balance::new(Utc::now(), 1000.0, 1000.0),
+            open_positions: vec![],
+            day_start_equity: 1000.0,
+            prices: HashMap::new(),
+            statistics: HashMap::from([(
+                MarketId::new(
+                    &Exchange::from("binance"),
+                    &Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+                ),
+                statistic,
+            )]),
+            fx_rates: None,
+        };
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            for (base, prices) in &markets {
+                for price in prices {
+                    test.allocator
+                        .update_from_market(&market_event(base, *price));
+                }
+            }
+
+            let mut order = entry_order("eth", 100.0);
+            test.allocator
+                .allocate_order(&mut order, None, SignalStrength(1.0), &portfolio);
+            assert_eq!(order.quantity, test.expected_quantity, "TC{} failed", index);
+
+            let mut order = entry_order("eth", 100.0);
+            order.decision = Decision::Short;
+            test.allocator
+                .allocate_order(&mut order, None, SignalStrength(1.0), &portfolio);
+            assert_eq!(
+                order.quantity, -test.expected_quantity,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_equity_based_allocators_value_quote_close_in_reporting_currency() {
+        struct TestCase {
+            allocator: Box<dyn OrderAllocator<PnLReturnSummary>>,
+            expected_quantity: f64,
+        }
+
+        let eth_btc = Instrument::from(("eth", "btc", InstrumentKind::Spot));
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+
+        // eth/btc ATR is 0.1 of price & btc/usdt ATR is 0.2 of price
+        let markets = [
+            (&eth_btc, vec![0.05, 0.055, 0.05]),
+            (&btc_usdt, vec![20000.0, 24000.0, 20000.0]),
+        ];
+
+        let exits = [10.0, -10.0, 10.0, 10.0].map(|realised_profit_loss| {
+            let mut exit = position();
+            exit.instrument = eth_btc.clone();
+            exit.realised_profit_loss = realised_profit_loss;
+            exit
+        });
+
+        // Open btc/usdt Position, already valued in the usdt reporting currency
+        let mut open_position = position();
+        open_position.instrument = btc_usdt.clone();
+        open_position.current_value_gross = 7000.0;
+
+        // eth/btc close of 0.05 btc is valued at 1000 usdt
+        let tests = vec![
+            TestCase {
+                // TC0: FixedFractional allocates 10% of equity
+                allocator: Box::new(FixedFractionalAllocator { fraction: 0.1 }),
+                expected_quantity: 1.0,
+            },
+            TestCase {
+                // TC1: VolatilityTarget allocates target / ATR (0.01 / 0.1) of equity
+                allocator: Box::new(VolatilityTargetAllocator::new(
+                    0.01,
+                    1.0,
+                    VolatilityEstimator::Atr { window: 2 },
+                )),
+                expected_quantity: 1.0,
+            },
+            TestCase {
+                // TC2: half Kelly of (3/4 - (1/4) / 1) = 0.25 of equity
+                allocator: Box::new(KellyAllocator::new(0.5, 1.0, 4)),
+                expected_quantity: 2.5,
+            },
+            TestCase {
+                // TC3: RiskParity weights eth/btc (ATR 0.1) 2/3 & btc/usdt (ATR 0.2) 1/3
+                allocator: Box::new(RiskParityAllocator::new(
+                    1.5,
+                    VolatilityEstimator::Atr { window: 2 },
+                )),
+                expected_quantity: 10.0,
+            },
+            TestCase {
+                // TC4: ExposureCapped caps 50% of equity by the 3000 usdt remaining exposure
+                allocator: Box::new(ExposureCappedAllocator {
+                    allocator: FixedFractionalAllocator { fraction: 0.5 },
+                    max_gross_leverage: 1.0,
+                }),
+                expected_quantity: 3.0,
+            },
+        ];
+
+        let mut statistic = PnLReturnSummary::default();
+        statistic.generate_summary(&exits);
+
+        let mut fx_rates = FxRates::new("usdt");
+        fx_rates.set_price(&btc_usdt.base, &btc_usdt.quote, 20000.0);
+
+        let mut portfolio = PortfolioSnapshot {
+            time: Utc::now(),
+            balance: Balance::new(Utc::now(), 10000.0, 10000.0),
+            open_positions: vec![open_position],
+            day_start_equity: 10000.0,
+            prices: HashMap::new(),
+            statistics: HashMap::from([(
+                MarketId::new(&Exchange::from("binance"), &eth_btc),
+                statistic,
+            )]),
+            fx_rates: Some(fx_rates),
+        };
+
+        let order = || {
+            let mut order = entry_order("eth", 0.05);
+            order.instrument = eth_btc.clone();
+            order
+        };
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            for (instrument, prices) in &markets {
+                for price in prices {
+                    let mut market = market_event("eth", *price);
+                    market.instrument = (*instrument).clone();
+                    test.allocator.update_from_market(&market);
+                }
+            }
+
+            let mut order = order();
+            test.allocator
+                .allocate_order(&mut order, None, SignalStrength(1.0), &portfolio);
+            assert_eq!(order.quantity, test.expected_quantity, "TC{} failed", index);
+        }
+
+        // Without a btc conversion rate, the order cannot be valued so is allocated nothing
+        portfolio.fx_rates = Some(FxRates::new("usdt"));
+        let mut order = order();
+        FixedFractionalAllocator { fraction: 0.1 }.allocate_order(
+            &mut order,
+            None,
+            SignalStrength(1.0),
+            &portfolio,
+        );
+        assert_eq!(order.quantity, 0.0);
+    }
+
+    #[test]
+    fn test_exposure_capped_allocator() {
+        struct TestCase {
+            open_positions: Vec<Position>,
+            decision: Decision,
+            expected_quantity: f64,
+        }
+
+        let open_position = |current_value_gross: f64, unrealised_profit_loss: f64| {
+            let mut position = position();
+            position.instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+            position.current_value_gross = current_value_gross;
+            position.unrealised_profit_loss = unrealised_profit_loss;
+            position
+        };
+
+        let allocator = ExposureCappedAllocator {
+            allocator: FixedFractionalAllocator { fraction: 0.5 },
+            max_gross_leverage: 1.0,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: uncapped entry
+                open_positions: vec![],
+                decision: Decision::Long,
+                expected_quantity: 5.0,
+            },
+            TestCase {
+                // TC1: entry capped by the remaining exposure
+                open_positions: vec![open_position(700.0, 0.0)],
+                decision: Decision::Long,
+                expected_quantity: 3.0,
+            },
+            TestCase {
+                // TC2: short entry capped by the remaining exposure, with equity including
+                // unrealised P&L
+                open_positions: vec![open_position(800.0, -100.0)],
+                decision: Decision::Short,
+                expected_quantity: -1.0,
+            },
+            TestCase {
+                // TC3: no remaining exposure
+                open_positions: vec![open_position(1200.0, 0.0)],
+                decision: Decision::Long,
+                expected_quantity: 0.0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut order = entry_order("eth", 100.0);
+            order.decision = test.decision;
+            allocator.allocate_order(
+                &mut order,
+                None,
+                SignalStrength(1.0),
+                &snapshot(1000.0, test.open_positions),
+            );
+            assert_eq!(order.quantity, test.expected_quantity, "TC{} failed", index);
+        }
+    }
 }
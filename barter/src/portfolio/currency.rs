@@ -0,0 +1,313 @@
+use crate::portfolio::{error::PortfolioError, Balance};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::{
+    instrument::{symbol::Symbol, Instrument},
+    Market,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// [`Balance`] of every [`Symbol`] held by a Portfolio.
+pub type SymbolBalances = HashMap<Symbol, Balance>;
+
+/// Live conversion rates between [`Symbol`]s, derived from the latest price of every market
+/// observed, used to value a multi-currency Portfolio in a single reporting currency.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct FxRates {
+    /// [`Symbol`] every balance & [`Position`](super::position::Position) value is reported in.
+    pub reporting_currency: Symbol,
+    /// Latest price of each base [`Symbol`], keyed by the quote [`Symbol`] it is priced in.
+    prices: HashMap<Symbol, HashMap<Symbol, f64>>,
+}
+
+impl FxRates {
+    /// Constructs a new [`FxRates`] reporting in the provided [`Symbol`], with no observed prices.
+    pub fn new<S>(reporting_currency: S) -> Self
+    where
+        S: Into<Symbol>,
+    {
+        Self {
+            reporting_currency: reporting_currency.into(),
+            prices: HashMap::new(),
+        }
+    }
+
+    /// Updates the conversion rate between the base & quote [`Symbol`] of the input
+    /// [`MarketEvent`] using it's latest price.
+    pub fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        let price = match &market.kind {
+            DataKind::Trade(trade) => trade.price,
+            DataKind::Candle(candle) => candle.close,
+            DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price(),
+            DataKind::OrderBook(book) => match book.volume_weighed_mid_price() {
+                Some(mid) => mid,
+                None => return,
+            },
+            DataKind::Liquidation(_) => return,
+        };
+
+        self.set_price(&market.instrument.base, &market.instrument.quote, price);
+    }
+
+    /// Sets the price of one unit of the base [`Symbol`] denominated in the quote [`Symbol`].
+    pub fn set_price(&mut self, base: &Symbol, quote: &Symbol, price: f64) {
+        if price <= 0.0 || !price.is_finite() {
+            return;
+        }
+
+        self.prices
+            .entry(base.clone())
+            .or_default()
+            .insert(quote.clone(), price);
+    }
+
+    /// Returns the rate to convert one unit of the `from` [`Symbol`] into the `to` [`Symbol`].
+    /// Uses a direct or inverse price if one has been observed, otherwise a cross rate via any
+    /// intermediate [`Symbol`] priced against both.
+    pub fn rate(&self, from: &Symbol, to: &Symbol) -> Option<f64> {
+        if from == to {
+            return Some(1.0);
+        }
+
+        self.direct_rate(from, to).or_else(|| {
+            self.intermediates(from)
+                .find_map(|via| Some(self.direct_rate(from, via)? * self.direct_rate(via, to)?))
+        })
+    }
+
+    /// Validates the quote [`Symbol`] of every provided [`Market`] can be converted into the
+    /// reporting currency using the prices of the provided [`Market`]s alone, either directly or
+    /// via an intermediate [`Symbol`] (see [`Self::rate`]).
+    pub fn validate_markets(&self, markets: &[Market]) -> Result<(), PortfolioError> {
+        let mut tracked = Self::new(self.reporting_currency.clone());
+        for market in markets {
+            tracked.set_price(&market.instrument.base, &market.instrument.quote, 1.0);
+        }
+
+        markets.iter().try_for_each(|market| {
+            match tracked.rate(&market.instrument.quote, &self.reporting_currency) {
+                Some(_) => Ok(()),
+                None => Err(PortfolioError::FxRateUntracked {
+                    from: market.instrument.quote.clone(),
+                    to: self.reporting_currency.clone(),
+                }),
+            }
+        })
+    }
+
+    /// Checks every provided [`Symbol`] can be converted into the reporting currency using the
+    /// latest conversion rates.
+    pub fn check_rates<'a, Symbols>(&self, symbols: Symbols) -> Result<(), PortfolioError>
+    where
+        Symbols: IntoIterator<Item = &'a Symbol>,
+    {
+        symbols.into_iter().try_for_each(|symbol| {
+            match self.rate(symbol, &self.reporting_currency) {
+                Some(_) => Ok(()),
+                None => Err(PortfolioError::FxRateUnavailable {
+                    from: symbol.clone(),
+                    to: self.reporting_currency.clone(),
+                }),
+            }
+        })
+    }
+
+    /// Converts the provided amount of the `from` [`Symbol`] into the reporting currency.
+    pub fn to_reporting(&self, from: &Symbol, amount: f64) -> Result<f64, PortfolioError> {
+        self.rate(from, &self.reporting_currency)
+            .map(|rate| amount * rate)
+            .ok_or_else(|| PortfolioError::FxRateUnavailable {
+                from: from.clone(),
+                to: self.reporting_currency.clone(),
+            })
+    }
+
+    /// Aggregates the provided [`SymbolBalances`] into a single [`Balance`] denominated in the
+    /// reporting currency.
+    pub fn aggregate(
+        &self,
+        time: DateTime<Utc>,
+        balances: &SymbolBalances,
+    ) -> Result<Balance, PortfolioError> {
+        balances.iter().try_fold(
+            Balance::new(time, 0.0, 0.0),
+            |aggregate, (symbol, balance)| {
+                Ok(Balance::new(
+                    time,
+                    aggregate.total + self.to_reporting(symbol, balance.total)?,
+                    aggregate.available + self.to_reporting(symbol, balance.available)?,
+                ))
+            },
+        )
+    }
+
+    /// Rate derived from a single observed price between the two [`Symbol`]s, in either
+    /// direction.
+    fn direct_rate(&self, from: &Symbol, to: &Symbol) -> Option<f64> {
+        let price = |base: &Symbol, quote: &Symbol| self.prices.get(base)?.get(quote).copied();
+
+        price(from, to).or_else(|| price(to, from).map(|price| 1.0 / price))
+    }
+
+    /// Every [`Symbol`] with an observed price against the provided [`Symbol`].
+    fn intermediates<'a>(&'a self, symbol: &'a Symbol) -> impl Iterator<Item = &'a Symbol> + 'a {
+        let quotes = self
+            .prices
+            .get(symbol)
+            .into_iter()
+            .flat_map(|quotes| quotes.keys());
+
+        let bases = self
+            .prices
+            .iter()
+            .filter(move |(_, quotes)| quotes.contains_key(symbol))
+            .map(|(base, _)| base);
+
+        quotes.chain(bases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+
+    #[test]
+    fn test_fx_rates_rate() {
+        let mut rates = FxRates::new("usdt");
+        rates.set_price(&Symbol::from("btc"), &Symbol::from("usdt"), 20000.0);
+        rates.set_price(&Symbol::from("eth"), &Symbol::from("btc"), 0.05);
+
+        struct TestCase {
+            from: &'static str,
+            to: &'static str,
+            expected: Option<f64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: same Symbol
+                from: "usdt",
+                to: "usdt",
+                expected: Some(1.0),
+            },
+            TestCase {
+                // TC1: direct price
+                from: "btc",
+                to: "usdt",
+                expected: Some(20000.0),
+            },
+            TestCase {
+                // TC2: inverse price
+                from: "usdt",
+                to: "btc",
+                expected: Some(1.0 / 20000.0),
+            },
+            TestCase {
+                // TC3: cross rate via an intermediate Symbol
+                from: "eth",
+                to: "usdt",
+                expected: Some(1000.0),
+            },
+            TestCase {
+                // TC4: no observed price
+                from: "sol",
+                to: "usdt",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = rates.rate(&Symbol::from(test.from), &Symbol::from(test.to));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_fx_rates_update_and_aggregate() {
+        let mut rates = FxRates::new("usdt");
+
+        let mut market = market_event_trade(Side::Buy);
+        market.instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        if let DataKind::Trade(trade) = &mut market.kind {
+            trade.price = 20000.0;
+        }
+        rates.update(&market);
+
+        let time = Utc::now();
+        let balances = SymbolBalances::from([
+            (Symbol::from("usdt"), Balance::new(time, 1000.0, 500.0)),
+            (Symbol::from("btc"), Balance::new(time, 0.5, 0.25)),
+        ]);
+        assert_eq!(
+            rates.aggregate(time, &balances).unwrap(),
+            Balance::new(time, 11000.0, 5500.0)
+        );
+
+        // Balances of a Symbol without an observed price cannot be aggregated
+        let balances = SymbolBalances::from([(Symbol::from("sol"), Balance::new(time, 1.0, 1.0))]);
+        assert!(matches!(
+            rates.aggregate(time, &balances),
+            Err(PortfolioError::FxRateUnavailable { .. })
+        ));
+        assert!(rates.check_rates([&Symbol::from("btc")]).is_ok());
+        assert!(matches!(
+            rates.check_rates([&Symbol::from("btc"), &Symbol::from("sol")]),
+            Err(PortfolioError::FxRateUnavailable { from, .. }) if from == Symbol::from("sol")
+        ));
+    }
+
+    #[test]
+    fn test_fx_rates_validate_markets() {
+        let market = |base: &str, quote: &str| {
+            Market::new(
+                "binance",
+                Instrument::from((base, quote, InstrumentKind::Spot)),
+            )
+        };
+
+        struct TestCase {
+            markets: Vec<Market>,
+            expected: Option<Symbol>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: markets quoted in the reporting currency
+                markets: vec![market("btc", "usdt"), market("eth", "usdt")],
+                expected: None,
+            },
+            TestCase {
+                // TC1: quote converted via a tracked market
+                markets: vec![market("eth", "btc"), market("btc", "usdt")],
+                expected: None,
+            },
+            TestCase {
+                // TC2: quote converted via an intermediate Symbol
+                markets: vec![
+                    market("sol", "eth"),
+                    market("eth", "btc"),
+                    market("btc", "usdt"),
+                ],
+                expected: None,
+            },
+            TestCase {
+                // TC3: quote without a tracked market to the reporting currency
+                markets: vec![market("eth", "btc")],
+                expected: Some(Symbol::from("btc")),
+            },
+        ];
+
+        let rates = FxRates::new("usdt");
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = match rates.validate_markets(&test.markets) {
+                Ok(()) => None,
+                Err(PortfolioError::FxRateUntracked { from, .. }) => Some(from),
+                Err(error) => panic!("TC{} failed with unexpected error: {}", index, error),
+            };
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
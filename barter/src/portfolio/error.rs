@@ -1,4 +1,5 @@
-use crate::portfolio::repository::error::RepositoryError;
+use crate::portfolio::{repository::error::RepositoryError, risk::OrderRejected};
+use barter_integration::model::instrument::symbol::Symbol;
 use thiserror::Error;
 
 /// All errors generated in the barter::portfolio module.
@@ -16,9 +17,21 @@ pub enum PortfolioError {
     #[error("Cannot exit Position with an entry decision FillEvent.")]
     CannotExitPositionWithEntryFill,
 
+    #[error("Cannot increase Position with an entry decision FillEvent of the opposite Side.")]
+    CannotIncreasePositionWithOppositeFill,
+
     #[error("Cannot generate PositionExit from Position that has not been exited")]
     PositionExit,
 
+    #[error("OrderEvent rejected by risk manager due to: {:?}", .0.violations)]
+    OrderRejected(Box<OrderRejected>),
+
+    #[error("No conversion rate available from {from} to {to}")]
+    FxRateUnavailable { from: Symbol, to: Symbol },
+
+    #[error("No tracked market converts {from} to {to}")]
+    FxRateUntracked { from: Symbol, to: Symbol },
+
     #[error("Failed to interact with repository")]
     RepositoryInteraction(#[from] RepositoryError),
 }
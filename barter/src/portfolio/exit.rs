@@ -0,0 +1,200 @@
+use crate::portfolio::position::{Position, PositionId};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Rules that automatically exit an open [`Position`], evaluated on every market update. Every
+/// rule is optional, and is disabled when `None`.
+///
+/// Price thresholds are fractions of a price (eg/ 0.05 for 5%), so the same [`ExitRules`] can be
+/// configured for every [`Position`] in a market.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ExitRules {
+    /// Exit once the price moves against the [`Position`] by this fraction of the enter price.
+    pub stop_loss: Option<f64>,
+    /// Exit once the price moves in favour of the [`Position`] by this fraction of the enter
+    /// price.
+    pub take_profit: Option<f64>,
+    /// Exit once the price retraces by this fraction from the most favourable price reached
+    /// since entry (see [`Position::peak_price`]).
+    pub trailing_stop: Option<f64>,
+    /// Exit once the [`Position`] has been open for this long.
+    pub max_holding_time: Option<Duration>,
+}
+
+impl ExitRules {
+    /// Determines the first [`ExitTrigger`] of the provided [`Position`] at it's current price
+    /// & the provided time, if any. Adverse price moves take precedence over favourable ones.
+    pub fn evaluate(&self, position: &Position, time: DateTime<Utc>) -> Option<ExitTrigger> {
+        let enter = position.enter_avg_price_gross;
+        let price = position.current_symbol_price;
+        let peak = position.peak_price.unwrap_or(enter);
+
+        // Fractional price move in favour (+ve) or against (-ve) the Position
+        let favourable_move = match position.side {
+            Side::Buy => (price - enter) / enter,
+            Side::Sell => (enter - price) / enter,
+        };
+
+        let stop_loss = self
+            .stop_loss
+            .filter(|stop_loss| favourable_move <= -stop_loss)
+            .map(|_| ExitTrigger::StopLoss);
+
+        let trailing_stop = self
+            .trailing_stop
+            .filter(|trailing_stop| match position.side {
+                Side::Buy => price <= peak * (1.0 - trailing_stop),
+                Side::Sell => price >= peak * (1.0 + trailing_stop),
+            })
+            .map(|_| ExitTrigger::TrailingStop);
+
+        let take_profit = self
+            .take_profit
+            .filter(|take_profit| favourable_move >= *take_profit)
+            .map(|_| ExitTrigger::TakeProfit);
+
+        let max_holding_time = self
+            .max_holding_time
+            .and_then(|max_holding_time| chrono::Duration::from_std(max_holding_time).ok())
+            .filter(|max_holding_time| time - position.meta.enter_time >= *max_holding_time)
+            .map(|_| ExitTrigger::MaxHoldingTime);
+
+        stop_loss
+            .or(trailing_stop)
+            .or(take_profit)
+            .or(max_holding_time)
+    }
+}
+
+/// [`ExitRules`] rule that triggered the exit of a [`Position`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum ExitTrigger {
+    StopLoss,
+    TakeProfit,
+    TrailingStop,
+    MaxHoldingTime,
+}
+
+/// Communicates that an [`ExitRules`] rule has been triggered for an open [`Position`], which
+/// must now be exited.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct ExitTriggered {
+    pub time: DateTime<Utc>,
+    pub position_id: PositionId,
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    pub trigger: ExitTrigger,
+    /// Price of the [`Position`] when the rule was triggered.
+    pub price: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::position;
+
+    #[test]
+    fn test_exit_rules_evaluate() {
+        let rules = ExitRules {
+            stop_loss: Some(0.1),
+            take_profit: Some(0.2),
+            trailing_stop: Some(0.05),
+            max_holding_time: Some(Duration::from_secs(60)),
+        };
+
+        struct TestCase {
+            side: Side,
+            price: f64,
+            peak_price: Option<f64>,
+            held_secs: i64,
+            expected: Option<ExitTrigger>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: long within every rule
+                side: Side::Buy,
+                price: 105.0,
+                peak_price: Some(105.0),
+                held_secs: 30,
+                expected: None,
+            },
+            TestCase {
+                // TC1: long stop loss
+                side: Side::Buy,
+                price: 90.0,
+                peak_price: None,
+                held_secs: 30,
+                expected: Some(ExitTrigger::StopLoss),
+            },
+            TestCase {
+                // TC2: long take profit
+                side: Side::Buy,
+                price: 120.0,
+                peak_price: Some(120.0),
+                held_secs: 30,
+                expected: Some(ExitTrigger::TakeProfit),
+            },
+            TestCase {
+                // TC3: long trailing stop after retracing 5% from peak, while still in profit
+                side: Side::Buy,
+                price: 104.0,
+                peak_price: Some(110.0),
+                held_secs: 30,
+                expected: Some(ExitTrigger::TrailingStop),
+            },
+            TestCase {
+                // TC4: short stop loss
+                side: Side::Sell,
+                price: 110.0,
+                peak_price: None,
+                held_secs: 30,
+                expected: Some(ExitTrigger::StopLoss),
+            },
+            TestCase {
+                // TC5: short take profit
+                side: Side::Sell,
+                price: 80.0,
+                peak_price: Some(80.0),
+                held_secs: 30,
+                expected: Some(ExitTrigger::TakeProfit),
+            },
+            TestCase {
+                // TC6: short trailing stop after retracing 5% from peak
+                side: Side::Sell,
+                price: 95.0,
+                peak_price: Some(90.0),
+                held_secs: 30,
+                expected: Some(ExitTrigger::TrailingStop),
+            },
+            TestCase {
+                // TC7: max holding time
+                side: Side::Buy,
+                price: 100.0,
+                peak_price: None,
+                held_secs: 60,
+                expected: Some(ExitTrigger::MaxHoldingTime),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut position = position();
+            position.side = test.side;
+            position.enter_avg_price_gross = 100.0;
+            position.current_symbol_price = test.price;
+            position.peak_price = test.peak_price;
+
+            let time = position.meta.enter_time + chrono::Duration::seconds(test.held_secs);
+            let actual = rules.evaluate(&position, time);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+
+        // No rules never trigger an exit
+        let mut position = position();
+        position.current_symbol_price = 0.0;
+        assert_eq!(ExitRules::default().evaluate(&position, Utc::now()), None);
+    }
+}
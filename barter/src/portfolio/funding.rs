@@ -0,0 +1,136 @@
+use crate::portfolio::position::PositionId;
+use barter_integration::model::{instrument::Instrument, Exchange, MarketId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+/// Funding rate of a perpetual market at a funding timestamp. A positive rate means longs pay
+/// shorts, and a negative rate means shorts pay longs.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FundingRate {
+    pub time: DateTime<Utc>,
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    pub rate: f64,
+}
+
+/// Funding paid (-ve) or received (+ve) by an open perpetual
+/// [`Position`](super::position::Position) at a funding timestamp.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FundingPayment {
+    pub time: DateTime<Utc>,
+    pub position_id: PositionId,
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    pub rate: f64,
+    /// Funding amount, being -ve if paid & +ve if received.
+    pub amount: f64,
+    /// Cumulative funding of the [`Position`](super::position::Position) after this payment.
+    pub funding_total: f64,
+}
+
+/// Historical schedule of [`FundingRate`]s for one or more perpetual markets, used to apply
+/// funding to open [`Position`](super::position::Position)s when backtesting.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct FundingSchedule {
+    rates: HashMap<MarketId, VecDeque<FundingRate>>,
+}
+
+impl FundingSchedule {
+    /// Constructs a new [`FundingSchedule`] from the provided [`FundingRate`]s, in any order.
+    pub fn new<Rates>(rates: Rates) -> Self
+    where
+        Rates: IntoIterator<Item = FundingRate>,
+    {
+        let mut schedule = HashMap::<MarketId, Vec<FundingRate>>::new();
+        for rate in rates {
+            schedule
+                .entry(MarketId::new(&rate.exchange, &rate.instrument))
+                .or_default()
+                .push(rate);
+        }
+
+        Self {
+            rates: schedule
+                .into_iter()
+                .map(|(market_id, mut rates)| {
+                    rates.sort_by_key(|rate| rate.time);
+                    (market_id, VecDeque::from(rates))
+                })
+                .collect(),
+        }
+    }
+
+    /// Removes & returns every [`FundingRate`] of the provided market with a funding timestamp
+    /// at or before the provided time.
+    pub fn due(
+        &mut self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+        time: DateTime<Utc>,
+    ) -> Vec<FundingRate> {
+        let Some(rates) = self.rates.get_mut(&MarketId::new(exchange, instrument)) else {
+            return vec![];
+        };
+
+        let due = rates.iter().take_while(|rate| rate.time <= time).count();
+        rates.drain(..due).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_funding_schedule_due() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let rate = |base: &str, hours: i64| FundingRate {
+            time: start + Duration::hours(hours),
+            exchange: Exchange::from("binance"),
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Perpetual)),
+            rate: 0.0001,
+        };
+
+        let mut schedule = FundingSchedule::new([
+            rate("btc", 16),
+            rate("btc", 0),
+            rate("eth", 0),
+            rate("btc", 8),
+        ]);
+
+        struct TestCase {
+            hours: i64,
+            expected: Vec<FundingRate>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first funding timestamp due
+                hours: 4,
+                expected: vec![rate("btc", 0)],
+            },
+            TestCase {
+                // TC1: no new funding timestamps due
+                hours: 7,
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: every remaining funding timestamp due, in time order
+                hours: 16,
+                expected: vec![rate("btc", 8), rate("btc", 16)],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = schedule.due(
+                &Exchange::from("binance"),
+                &Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                start + Duration::hours(test.hours),
+            );
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
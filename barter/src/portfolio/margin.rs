@@ -0,0 +1,193 @@
+use crate::portfolio::position::PositionId;
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Determines how a [`Position`](super::position::Position) is collateralised.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum MarginMode {
+    /// Every [`Position`](super::position::Position) is fully funded by cash (ie/ no leverage),
+    /// and can never be liquidated.
+    #[default]
+    Cash,
+    /// Each [`Position`](super::position::Position) is collateralised only by the initial
+    /// margin posted to open it.
+    Isolated,
+    /// Every [`Position`](super::position::Position) shares the entire Portfolio equity as
+    /// collateral.
+    Cross,
+}
+
+/// Margin requirements & leverage setting of an instrument traded on margin (eg/ perpetuals &
+/// futures).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MarginConfig {
+    pub mode: MarginMode,
+    /// Leverage used to determine the initial margin rate (1 / leverage). Ignored in
+    /// [`MarginMode::Cash`].
+    pub leverage: f64,
+    /// Fraction of a [`Position`](super::position::Position) current value that must be
+    /// maintained as equity to avoid liquidation (eg/ 0.005 for 0.5%).
+    pub maintenance_margin_rate: f64,
+}
+
+impl Default for MarginConfig {
+    fn default() -> Self {
+        Self {
+            mode: MarginMode::Cash,
+            leverage: 1.0,
+            maintenance_margin_rate: 0.0,
+        }
+    }
+}
+
+impl MarginConfig {
+    /// Fraction of a [`Position`](super::position::Position) enter value that must be posted as
+    /// collateral to open it.
+    pub fn initial_margin_rate(&self) -> f64 {
+        match self.mode {
+            MarginMode::Cash => 1.0,
+            MarginMode::Isolated | MarginMode::Cross => 1.0 / self.leverage,
+        }
+    }
+
+    /// Collateral that must be posted to open a [`Position`](super::position::Position) with the
+    /// provided gross value.
+    pub fn initial_margin(&self, value_gross: f64) -> f64 {
+        value_gross * self.initial_margin_rate()
+    }
+
+    /// Equity that must be maintained to keep a [`Position`](super::position::Position) with the
+    /// provided current gross value open.
+    pub fn maintenance_margin(&self, value_gross: f64) -> f64 {
+        match self.mode {
+            MarginMode::Cash => 0.0,
+            MarginMode::Isolated | MarginMode::Cross => value_gross * self.maintenance_margin_rate,
+        }
+    }
+
+    /// Calculates the price at which a [`Position`](super::position::Position) backed by the
+    /// provided collateral is liquidated, ie/ where collateral + unrealised P&L equals the
+    /// maintenance margin. Returns `None` in [`MarginMode::Cash`].
+    ///
+    /// In [`MarginMode::Cross`] the collateral should include the free Portfolio equity, so the
+    /// result is an estimate that assumes every other open Position remains unchanged.
+    pub fn liquidation_price(
+        &self,
+        side: Side,
+        enter_avg_price: f64,
+        quantity: f64,
+        collateral: f64,
+    ) -> Option<f64> {
+        if self.mode == MarginMode::Cash {
+            return None;
+        }
+
+        let quantity = quantity.abs();
+        let price = match side {
+            Side::Buy => {
+                (enter_avg_price * quantity - collateral)
+                    / (quantity * (1.0 - self.maintenance_margin_rate))
+            }
+            Side::Sell => {
+                (enter_avg_price * quantity + collateral)
+                    / (quantity * (1.0 + self.maintenance_margin_rate))
+            }
+        };
+
+        Some(price.max(0.0))
+    }
+}
+
+/// Communicates that the equity backing a [`Position`](super::position::Position) has fallen to
+/// it's maintenance margin, and it must be liquidated.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MarginCall {
+    pub time: DateTime<Utc>,
+    pub position_id: PositionId,
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    pub mode: MarginMode,
+    /// Equity backing the [`Position`](super::position::Position). In [`MarginMode::Isolated`]
+    /// this is the posted margin plus unrealised P&L, and in [`MarginMode::Cross`] it is the
+    /// entire Portfolio equity.
+    pub equity: f64,
+    /// Maintenance margin of the [`Position`](super::position::Position), or of every open
+    /// Position in [`MarginMode::Cross`].
+    pub maintenance_margin: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_margin_config_liquidation_price() {
+        struct TestCase {
+            config: MarginConfig,
+            side: Side,
+            collateral: f64,
+            expected: Option<f64>,
+        }
+
+        let isolated = MarginConfig {
+            mode: MarginMode::Isolated,
+            leverage: 10.0,
+            maintenance_margin_rate: 0.0,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: cash positions are never liquidated
+                config: MarginConfig::default(),
+                side: Side::Buy,
+                collateral: 1000.0,
+                expected: None,
+            },
+            TestCase {
+                // TC1: isolated 10x long without maintenance margin loses all collateral at -10%
+                config: isolated,
+                side: Side::Buy,
+                collateral: 100.0,
+                expected: Some(90.0),
+            },
+            TestCase {
+                // TC2: isolated 10x short without maintenance margin loses all collateral at +10%
+                config: isolated,
+                side: Side::Sell,
+                collateral: 100.0,
+                expected: Some(110.0),
+            },
+            TestCase {
+                // TC3: long with maintenance margin is liquidated before losing all collateral
+                config: MarginConfig {
+                    maintenance_margin_rate: 0.5,
+                    ..isolated
+                },
+                side: Side::Buy,
+                collateral: 750.0,
+                expected: Some(50.0),
+            },
+            TestCase {
+                // TC4: cross long backed by more collateral than the position value
+                config: MarginConfig {
+                    mode: MarginMode::Cross,
+                    ..isolated
+                },
+                side: Side::Buy,
+                collateral: 2000.0,
+                expected: Some(0.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            // Position of 10 contracts entered at 100.0
+            let actual = test
+                .config
+                .liquidation_price(test.side, 100.0, 10.0, test.collateral);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -2,13 +2,24 @@ use crate::{
     data::MarketMeta,
     event::Event,
     execution::FillEvent,
-    portfolio::{error::PortfolioError, position::PositionUpdate},
+    portfolio::{
+        currency::FxRates,
+        error::PortfolioError,
+        exit::ExitTriggered,
+        funding::{FundingPayment, FundingRate},
+        margin::MarginCall,
+        position::{Position, PositionUpdate},
+    },
     strategy::{Decision, Signal, SignalForceExit},
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{instrument::Instrument, Exchange};
+use barter_integration::model::{
+    instrument::{symbol::Symbol, Instrument},
+    Exchange, MarketId, Side,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 /// Logic for [`OrderEvent`] quantity allocation.
@@ -32,6 +43,21 @@ pub mod repository;
 /// Logic for evaluating the risk associated with a proposed [`OrderEvent`].
 pub mod risk;
 
+/// Stop-loss, take-profit, trailing stop & max holding time rules that automatically exit open
+/// [`Position`]s.
+pub mod exit;
+
+/// Funding rates & payments of perpetual [`Position`]s.
+pub mod funding;
+
+/// Per [`Symbol`](barter_integration::model::instrument::symbol::Symbol) balances & the
+/// conversion rates used to value them in a single reporting currency.
+pub mod currency;
+
+/// Margin requirements, leverage & liquidation of [`Position`]s traded on margin (eg/ perpetuals
+/// & futures).
+pub mod margin;
+
 /// Updates the Portfolio from an input [`MarketEvent`].
 pub trait MarketUpdater {
     /// Determines if the Portfolio has an open Position relating to the input [`MarketEvent`]. If
@@ -41,6 +67,36 @@ pub trait MarketUpdater {
         &mut self,
         market: &MarketEvent<Instrument, DataKind>,
     ) -> Result<Option<PositionUpdate>, PortfolioError>;
+
+    /// Determines if the equity backing the open Position relating to the input [`MarketEvent`]
+    /// has fallen to it's maintenance margin, returning a [`MarginCall`] if it must be
+    /// liquidated. Defaults to `None` for Portfolios that do not trade on margin.
+    fn margin_call(
+        &mut self,
+        _: &MarketEvent<Instrument, DataKind>,
+    ) -> Result<Option<MarginCall>, PortfolioError> {
+        Ok(None)
+    }
+
+    /// Determines if an [`ExitRules`](exit::ExitRules) rule has been triggered for the open
+    /// Position relating to the input [`MarketEvent`], returning an [`ExitTriggered`] if it must
+    /// be exited. Defaults to `None` for Portfolios that do not manage exits.
+    fn exit_trigger(
+        &mut self,
+        _: &MarketEvent<Instrument, DataKind>,
+    ) -> Result<Option<ExitTriggered>, PortfolioError> {
+        Ok(None)
+    }
+
+    /// Applies the input [`FundingRate`] to the open perpetual Position relating to it's market,
+    /// returning a [`FundingPayment`] detailing the funding paid or received. Defaults to `None`
+    /// for Portfolios that do not trade perpetuals.
+    fn update_from_funding(
+        &mut self,
+        _: &FundingRate,
+    ) -> Result<Option<FundingPayment>, PortfolioError> {
+        Ok(None)
+    }
 }
 
 /// May generate an [`OrderEvent`] from an input advisory [`Signal`].
@@ -48,6 +104,18 @@ pub trait OrderGenerator {
     /// May generate an [`OrderEvent`] after analysing an input advisory [`Signal`].
     fn generate_order(&mut self, signal: &Signal) -> Result<Option<OrderEvent>, PortfolioError>;
 
+    /// May generate an [`OrderEvent`] after analysing an input advisory [`Signal`], but only if
+    /// it reduces an open [`Position`](position::Position) (eg/ whilst entries are halted).
+    /// Defaults to discarding any entry [`OrderEvent`] generated by
+    /// [`generate_order`](OrderGenerator::generate_order).
+    fn generate_reducing_order(
+        &mut self,
+        signal: &Signal,
+    ) -> Result<Option<OrderEvent>, PortfolioError> {
+        self.generate_order(signal)
+            .map(|order| order.filter(|order| order.decision.is_exit()))
+    }
+
     /// Generates an exit [`OrderEvent`] if there is an open [`Position`](position::Position)
     /// associated with the input [`SignalForceExit`]'s [`PositionId`](position::PositionId).
     fn generate_exit_order(
@@ -231,4 +299,123 @@ impl Balance {
     pub fn balance_id(engine_id: Uuid) -> BalanceId {
         format!("{}_balance", engine_id)
     }
+
+    /// Returns the unique identifier for an Engine's per
+    /// [`Symbol`](barter_integration::model::instrument::symbol::Symbol) [`Balance`]s.
+    pub fn symbol_balances_id(engine_id: Uuid) -> BalanceId {
+        format!("{}_symbol_balances", engine_id)
+    }
+}
+
+/// Portfolio equity (the [`Balance`] total plus the unrealised P&L of every open [`Position`])
+/// marked at a point in time.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct EquityMark {
+    pub time: DateTime<Utc>,
+    pub equity: f64,
+}
+
+impl EquityMark {
+    /// Construct a new [`EquityMark`] using the provided time & equity.
+    pub fn new(time: DateTime<Utc>, equity: f64) -> Self {
+        Self { time, equity }
+    }
+
+    /// Returns the unique identifier for an Engine's [`EquityMark`] at the start of the UTC day.
+    pub fn day_start_id(engine_id: Uuid) -> BalanceId {
+        format!("{}_day_start_equity", engine_id)
+    }
+}
+
+/// Read-only snapshot of a Portfolio's state at a point in time, used to size & evaluate the risk
+/// of a proposed [`OrderEvent`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PortfolioSnapshot<Statistic> {
+    pub time: DateTime<Utc>,
+    pub balance: Balance,
+    /// Every open [`Position`] across the Portfolio's tracked markets.
+    pub open_positions: Vec<Position>,
+    /// Equity at the start of the UTC day of `time`, being the current equity if it has not
+    /// been marked.
+    pub day_start_equity: f64,
+    /// Latest price of every tracked market.
+    pub prices: HashMap<MarketId, f64>,
+    /// Statistics of every tracked market, updated by each exited [`Position`].
+    pub statistics: HashMap<MarketId, Statistic>,
+    /// Live conversion rates of a multi-currency Portfolio, `None` if every value is denominated
+    /// in a single currency.
+    pub fx_rates: Option<FxRates>,
+}
+
+impl<Statistic> PortfolioSnapshot<Statistic> {
+    /// Current equity, being the [`Balance`] total plus the unrealised P&L of every open
+    /// [`Position`].
+    pub fn equity(&self) -> f64 {
+        self.balance.total
+            + self
+                .open_positions
+                .iter()
+                .map(|position| position.unrealised_profit_loss)
+                .sum::<f64>()
+    }
+
+    /// Returns the statistics associated with the provided [`Exchange`] & [`Instrument`].
+    pub fn statistic(&self, exchange: &Exchange, instrument: &Instrument) -> Option<&Statistic> {
+        self.statistics.get(&MarketId::new(exchange, instrument))
+    }
+
+    /// Returns the latest price of the market associated with the provided [`Exchange`] &
+    /// [`Instrument`].
+    pub fn price(&self, exchange: &Exchange, instrument: &Instrument) -> Option<f64> {
+        self.prices
+            .get(&MarketId::new(exchange, instrument))
+            .copied()
+    }
+
+    /// Returns the rate to convert one unit of the provided quote [`Symbol`] into the currency
+    /// the snapshot is valued in, always 1.0 for a single currency Portfolio.
+    pub fn quote_rate(&self, quote: &Symbol) -> Option<f64> {
+        match &self.fx_rates {
+            Some(fx_rates) => fx_rates.rate(quote, &fx_rates.reporting_currency),
+            None => Some(1.0),
+        }
+    }
+
+    /// Converts the provided price or value of an [`Instrument`], denominated in it's quote
+    /// [`Symbol`], into the currency the snapshot is valued in.
+    pub fn to_reporting(&self, instrument: &Instrument, value: f64) -> Option<f64> {
+        self.quote_rate(&instrument.quote).map(|rate| value * rate)
+    }
+
+    /// Returns the open [`Position`] associated with the provided [`Exchange`] & [`Instrument`].
+    pub fn open_position(&self, exchange: &Exchange, instrument: &Instrument) -> Option<&Position> {
+        self.open_positions
+            .iter()
+            .find(|position| &position.exchange == exchange && &position.instrument == instrument)
+    }
+
+    /// Sum of the absolute current value of every open [`Position`].
+    pub fn gross_exposure(&self) -> f64 {
+        self.open_positions
+            .iter()
+            .map(|position| position.current_value_gross)
+            .sum()
+    }
+
+    /// Sum of the current value of every open [`Position`], with short positions negative.
+    pub fn net_exposure(&self) -> f64 {
+        self.open_positions
+            .iter()
+            .map(|position| match position.side {
+                Side::Buy => position.current_value_gross,
+                Side::Sell => -position.current_value_gross,
+            })
+            .sum()
+    }
+
+    /// Change in equity since the start of the UTC day, including both realised & unrealised
+    /// P&L.
+    pub fn profit_loss_today(&self) -> f64 {
+        self.equity() - self.day_start_equity
+    }
 }
@@ -1,24 +1,35 @@
 use super::{
     allocator::OrderAllocator,
+    currency::{FxRates, SymbolBalances},
     error::PortfolioError,
+    exit::{ExitRules, ExitTriggered},
+    funding::{FundingPayment, FundingRate},
+    margin::{MarginCall, MarginConfig, MarginMode},
     position::{
-        determine_position_id, Position, PositionEnterer, PositionExiter, PositionId,
+        determine_position_id, Position, PositionEnterer, PositionExit, PositionExiter, PositionId,
         PositionUpdate, PositionUpdater,
     },
-    repository::{error::RepositoryError, BalanceHandler, PositionHandler, StatisticHandler},
+    repository::{
+        error::RepositoryError, BalanceHandler, PositionHandler, StatisticHandler,
+        StrategyStateHandler, StrategyStateId,
+    },
     risk::OrderEvaluator,
-    Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator, OrderType,
+    Balance, EquityMark, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator, OrderType,
+    PortfolioSnapshot,
 };
 use crate::{
     data::MarketMeta,
     event::Event,
     execution::FillEvent,
     statistic::summary::{Initialiser, PositionSummariser},
-    strategy::{Decision, Signal, SignalForceExit, SignalStrength},
+    strategy::{Attribution, Decision, Signal, SignalForceExit, SignalStrength, TargetPosition},
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{instrument::Instrument, Market, MarketId, Side};
-use chrono::Utc;
+use barter_integration::model::{
+    instrument::{symbol::Symbol, Instrument},
+    Exchange, Market, MarketId, Side,
+};
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 use std::{collections::HashMap, marker::PhantomData};
 use tracing::info;
@@ -30,7 +41,7 @@ use uuid::Uuid;
 pub struct PortfolioLego<Repository, Allocator, RiskManager, Statistic>
 where
     Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
-    Allocator: OrderAllocator,
+    Allocator: OrderAllocator<Statistic>,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
 {
@@ -39,6 +50,16 @@ where
     pub engine_id: Uuid,
     /// [`Market`]s being tracked by a [`MetaPortfolio`].
     pub markets: Vec<Market>,
+    /// [`MarginConfig`] of each [`Market`] traded on margin. Markets without a [`MarginConfig`]
+    /// are fully funded by cash.
+    pub margin: HashMap<MarketId, MarginConfig>,
+    /// [`ExitRules`] of each [`Market`] attached to every [`Position`] it enters, unless
+    /// overridden by the entry [`Signal`].
+    pub exit_rules: HashMap<MarketId, ExitRules>,
+    /// Minimum change in quantity, as a fraction of the open [`Position`] quantity, required for
+    /// a [`TargetPosition`] [`Signal`] to generate a rebalancing [`OrderEvent`]. Entering &
+    /// exiting to flat are always ordered.
+    pub rebalance_tolerance: f64,
     /// Repository for a [`MetaPortfolio`] to persist it's state in. Implements
     /// [`PositionHandler`], [`BalanceHandler`], and [`StatisticHandler`]
     pub repository: Repository,
@@ -46,8 +67,13 @@ where
     pub allocator: Allocator,
     /// Risk manager implements [`OrderEvaluator`].
     pub risk: RiskManager,
-    /// Cash balance a [`MetaPortfolio`] starts with.
+    /// Cash balance a [`MetaPortfolio`] starts with, denominated in the reporting currency if
+    /// one is provided.
     pub starting_cash: f64,
+    /// Optional [`Symbol`] to report a multi-currency [`MetaPortfolio`] in. If provided, balances
+    /// are held per [`Symbol`] and valued in the reporting currency using live conversion rates,
+    /// so the quote [`Symbol`] of every [`Market`] must be convertible via the tracked markets.
+    pub reporting_currency: Option<Symbol>,
     /// Configuration used to initialise the Statistics for every Market's performance tracked by a
     /// [`MetaPortfolio`].
     pub statistic_config: Statistic::Config,
@@ -60,12 +86,33 @@ where
 pub struct MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
     Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
-    Allocator: OrderAllocator,
+    Allocator: OrderAllocator<Statistic>,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
 {
     /// Identifier for the [`Engine`](crate::engine::Engine) this Portfolio is associated with (1-to-1 relationship).
     engine_id: Uuid,
+    /// [`Market`]s being tracked by this [`MetaPortfolio`].
+    markets: Vec<Market>,
+    /// [`MarginConfig`] of each [`Market`] traded on margin.
+    margin: HashMap<MarketId, MarginConfig>,
+    /// [`ExitRules`] of each [`Market`] attached to every [`Position`] it enters.
+    exit_rules: HashMap<MarketId, ExitRules>,
+    /// [`ExitRules`] of entry [`Signal`]s awaiting the [`FillEvent`] that enters their
+    /// [`Position`].
+    pending_exit_rules: HashMap<PositionId, ExitRules>,
+    /// [`Attribution`] of entry [`Signal`]s awaiting the [`FillEvent`] that enters their
+    /// [`Position`].
+    pending_attribution: HashMap<PositionId, Vec<Attribution>>,
+    /// Minimum fractional change in [`Position`] quantity ordered when rebalancing to a
+    /// [`TargetPosition`].
+    rebalance_tolerance: f64,
+    /// Live conversion rates into the reporting currency of a multi-currency [`MetaPortfolio`].
+    fx_rates: Option<FxRates>,
+    /// Latest price of every tracked [`Market`], denominated in it's quote [`Symbol`].
+    prices: HashMap<MarketId, f64>,
+    /// Cached [`EquityMark`] at the start of the latest UTC day, persisted in the repository.
+    day_start_equity: Option<EquityMark>,
     /// Repository for the [`MetaPortfolio`] to persist it's state in. Implements
     /// [`PositionHandler`], [`BalanceHandler`], and [`StatisticHandler`]
     repository: Repository,
@@ -80,7 +127,7 @@ impl<Repository, Allocator, RiskManager, Statistic> MarketUpdater
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
     Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
-    Allocator: OrderAllocator,
+    Allocator: OrderAllocator<Statistic>,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
 {
@@ -88,6 +135,19 @@ where
         &mut self,
         market: &MarketEvent<Instrument, DataKind>,
     ) -> Result<Option<PositionUpdate>, PortfolioError> {
+        // Mark the equity at the start of each UTC day before it is re-valued by the MarketEvent
+        self.mark_day_start_equity(market.exchange_time)?;
+
+        // Update any allocation state, conversion rates & prices derived from market data
+        self.allocation_manager.update_from_market(market);
+        if let Some(fx_rates) = &mut self.fx_rates {
+            fx_rates.update(market);
+        }
+        if let Some(price) = market_price(market) {
+            self.prices
+                .insert(MarketId::new(&market.exchange, &market.instrument), price);
+        }
+
         // Determine the position_id associated to the input MarketEvent
         let position_id =
             determine_position_id(self.engine_id, &market.exchange, &market.instrument);
@@ -104,52 +164,122 @@ where
 
         Ok(None)
     }
+
+    fn margin_call(
+        &mut self,
+        market: &MarketEvent<Instrument, DataKind>,
+    ) -> Result<Option<MarginCall>, PortfolioError> {
+        let margin = self.margin_config(&market.exchange, &market.instrument);
+        if margin.mode == MarginMode::Cash {
+            return Ok(None);
+        }
+
+        // Determine the position_id associated to the input MarketEvent
+        let position_id =
+            determine_position_id(self.engine_id, &market.exchange, &market.instrument);
+
+        let Some(position) = self.repository.get_open_position(&position_id)? else {
+            return Ok(None);
+        };
+
+        let (equity, maintenance_margin) = match margin.mode {
+            MarginMode::Isolated => (
+                position.margin + position.unrealised_profit_loss,
+                margin.maintenance_margin(position.current_value_gross),
+            ),
+            // Cross margin Positions share the entire Portfolio equity as collateral
+            // valued in the reporting currency if the MetaPortfolio is multi-currency
+            _ => {
+                let (balance, open_positions) = self.valued_balance_and_open_positions()?;
+
+                open_positions.iter().fold(
+                    (balance.total, 0.0),
+                    |(equity, maintenance_margin), position| {
+                        let margin = self.margin_config(&position.exchange, &position.instrument);
+                        (
+                            equity + position.unrealised_profit_loss,
+                            maintenance_margin
+                                + margin.maintenance_margin(position.current_value_gross),
+                        )
+                    },
+                )
+            }
+        };
+
+        Ok((equity <= maintenance_margin).then_some(MarginCall {
+            time: market.exchange_time,
+            position_id,
+            exchange: position.exchange,
+            instrument: position.instrument,
+            mode: margin.mode,
+            equity,
+            maintenance_margin,
+        }))
+    }
+
+    fn exit_trigger(
+        &mut self,
+        market: &MarketEvent<Instrument, DataKind>,
+    ) -> Result<Option<ExitTriggered>, PortfolioError> {
+        // Determine the position_id associated to the input MarketEvent
+        let position_id =
+            determine_position_id(self.engine_id, &market.exchange, &market.instrument);
+
+        let Some(position) = self.repository.get_open_position(&position_id)? else {
+            return Ok(None);
+        };
+
+        Ok(position
+            .exit_rules
+            .evaluate(&position, market.exchange_time)
+            .map(|trigger| ExitTriggered {
+                time: market.exchange_time,
+                position_id,
+                price: position.current_symbol_price,
+                exchange: position.exchange,
+                instrument: position.instrument,
+                trigger,
+            }))
+    }
+
+    fn update_from_funding(
+        &mut self,
+        funding: &FundingRate,
+    ) -> Result<Option<FundingPayment>, PortfolioError> {
+        // Determine the position_id associated to the input FundingRate
+        let position_id =
+            determine_position_id(self.engine_id, &funding.exchange, &funding.instrument);
+
+        // Apply funding if Portfolio has an open Position for that Symbol-Exchange combination
+        if let Some(mut position) = self.repository.get_open_position(&position_id)? {
+            if let Some(funding_payment) = position.apply_funding(funding) {
+                // Save updated open Position in the repository
+                self.repository.set_open_position(position)?;
+                return Ok(Some(funding_payment));
+            }
+        }
+
+        Ok(None)
+    }
 }
 
 impl<Repository, Allocator, RiskManager, Statistic> OrderGenerator
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
     Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
-    Allocator: OrderAllocator,
+    Allocator: OrderAllocator<Statistic>,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
 {
     fn generate_order(&mut self, signal: &Signal) -> Result<Option<OrderEvent>, PortfolioError> {
-        // Determine the position_id & associated Option<Position> related to input SignalEvent
-        let position_id =
-            determine_position_id(self.engine_id, &signal.exchange, &signal.instrument);
-        let position = self.repository.get_open_position(&position_id)?;
-
-        // If signal is advising to open a new Position rather than close one, check we have cash
-        if position.is_none() && self.no_cash_to_enter_new_position()? {
-            return Ok(None);
-        }
-
-        // Parse signals from Strategy to determine net signal decision & associated strength
-        let position = position.as_ref();
-        let (signal_decision, signal_strength) =
-            match parse_signal_decisions(&position, &signal.signals) {
-                None => return Ok(None),
-                Some(net_signal) => net_signal,
-            };
-
-        // Construct mutable OrderEvent that can be modified by Allocation & Risk management
-        let mut order = OrderEvent {
-            time: Utc::now(),
-            exchange: signal.exchange.clone(),
-            instrument: signal.instrument.clone(),
-            market_meta: signal.market_meta,
-            decision: *signal_decision,
-            quantity: 0.0,
-            order_type: OrderType::default(),
-        };
-
-        // Manage OrderEvent size allocation
-        self.allocation_manager
-            .allocate_order(&mut order, position, *signal_strength);
+        self.generate_signal_order(signal, false)
+    }
 
-        // Manage global risk when evaluating OrderEvent - keep the same, refine or cancel
-        Ok(self.risk_manager.evaluate_order(order))
+    fn generate_reducing_order(
+        &mut self,
+        signal: &Signal,
+    ) -> Result<Option<OrderEvent>, PortfolioError> {
+        self.generate_signal_order(signal, true)
     }
 
     fn generate_exit_order(
@@ -192,62 +322,112 @@ impl<Repository, Allocator, RiskManager, Statistic> FillUpdater
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
     Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
-    Allocator: OrderAllocator,
+    Allocator: OrderAllocator<Statistic>,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser + Serialize,
 {
     fn update_from_fill(&mut self, fill: &FillEvent) -> Result<Vec<Event>, PortfolioError> {
+        // Multi-currency Portfolios re-value every Symbol balance in the reporting currency after
+        // the FillEvent, so check the conversion rates are available before any state is changed
+        if let Some(fx_rates) = &self.fx_rates {
+            let balances = self.repository.get_symbol_balances(self.engine_id)?;
+            fx_rates.check_rates(balances.keys().chain([&fill.instrument.quote]))?;
+        }
+
         // Allocate Vector<Event> to contain any update_from_fill generated events
         let mut generated_events: Vec<Event> = Vec::with_capacity(2);
 
         // Get the Portfolio Balance from Repository & update timestamp
         let mut balance = self.repository.get_balance(self.engine_id)?;
         balance.time = fill.time;
+        let previous = balance;
 
         // Determine the position_id that is related to the input FillEvent
         let position_id = determine_position_id(self.engine_id, &fill.exchange, &fill.instrument);
 
         // Determine FillEvent context based on existence or absence of an open Position
         match self.repository.remove_position(&position_id)? {
-            // EXIT SCENARIO - FillEvent for Symbol-Exchange combination with open Position
-            Some(mut position) => {
-                // Exit Position (in place mutation), & add the PositionExit event to Vec<Event>
-                let position_exit = position.exit(balance, fill)?;
-                generated_events.push(Event::PositionExit(position_exit));
-
-                // Update Portfolio balance on Position exit
-                // '--> available balance adds enter_total_fees since included in result PnL calc
-                balance.available += position.enter_value_gross
-                    + position.realised_profit_loss
-                    + position.enter_fees_total;
-                balance.total += position.realised_profit_loss;
+            // INCREASE SCENARIO - entry FillEvent for Symbol-Exchange with open Position
+            Some(mut position) if fill.decision.is_entry() => {
+                // Increase Position, posting the additional initial margin required
+                position.increase(fill)?;
+                let margin = self
+                    .margin_config(&fill.exchange, &fill.instrument)
+                    .initial_margin(fill.fill_value_gross);
+                position.margin += margin;
+
+                // Update Portfolio Balance.available on Position increase
+                balance.available += -margin - fill.fees.calculate_total_fees();
+                position.liquidation_price = self.liquidation_price(&position, balance.available);
+
+                // Add the PositionUpdate event to Vec<Event> & persist the increased Position
+                generated_events.push(Event::PositionUpdate(PositionUpdate::from(&mut position)));
+                self.repository.set_open_position(position)?;
+            }
 
-                // Update statistics for exited Position market
-                let market_id = MarketId::new(&fill.exchange, &fill.instrument);
+            // REDUCE SCENARIO - exit FillEvent for part of an open Position
+            Some(mut position) if fill.quantity.abs() < position.quantity.abs() => {
+                // Exit the filled part of the Position, & add the PositionExit event to Vec<Event>
+                let position_exit =
+                    self.exit_position(position.split(fill.quantity), &mut balance, fill)?;
+                generated_events.push(Event::PositionExit(position_exit));
 
-                let mut stats = self.repository.get_statistics(&market_id)?;
-                stats.update(&position);
+                // Add the PositionUpdate event to Vec<Event> & persist the remaining Position
+                position.meta.update_time = fill.time;
+                position.liquidation_price = self.liquidation_price(&position, balance.available);
+                generated_events.push(Event::PositionUpdate(PositionUpdate::from(&mut position)));
+                self.repository.set_open_position(position)?;
+            }
 
-                // Persist exited Position & Updated Market statistics in Repository
-                self.repository.set_statistics(market_id, stats)?;
-                self.repository
-                    .set_exited_position(self.engine_id, position)?;
+            // EXIT SCENARIO - FillEvent for Symbol-Exchange combination with open Position
+            Some(position) => {
+                let position_exit = self.exit_position(position, &mut balance, fill)?;
+                generated_events.push(Event::PositionExit(position_exit));
             }
 
             // ENTRY SCENARIO - FillEvent for Symbol-Exchange with no Position
             None => {
-                // Enter new Position, & add the PositionNew event to Vec<Event>
-                let position = Position::enter(self.engine_id, fill)?;
-                generated_events.push(Event::PositionNew(position.clone()));
+                // Enter new Position, posting the initial margin required by it's MarginConfig
+                let mut position = Position::enter(self.engine_id, fill)?;
+                position.exit_rules = self
+                    .pending_exit_rules
+                    .remove(&position_id)
+                    .unwrap_or_else(|| self.exit_rules_config(&fill.exchange, &fill.instrument));
+                position.attribution = self
+                    .pending_attribution
+                    .remove(&position_id)
+                    .unwrap_or_default();
+                let margin = self.margin_config(&fill.exchange, &fill.instrument);
+                position.margin = margin.initial_margin(position.enter_value_gross);
 
                 // Update Portfolio Balance.available on Position entry
-                balance.available += -position.enter_value_gross - position.enter_fees_total;
+                balance.available += -position.margin - position.enter_fees_total;
+                position.liquidation_price = self.liquidation_price(&position, balance.available);
+
+                // Add the PositionNew event to Vec<Event>
+                generated_events.push(Event::PositionNew(position.clone()));
 
                 // Add to current Positions in Repository
                 self.repository.set_open_position(position)?;
             }
         };
 
+        // Multi-currency Portfolios book the change in balance against the fill quote Symbol,
+        // and re-value the aggregate Balance in the reporting currency
+        if let Some(fx_rates) = &self.fx_rates {
+            let mut balances = self.repository.get_symbol_balances(self.engine_id)?;
+            let quote_balance = balances
+                .entry(fill.instrument.quote.clone())
+                .or_insert_with(|| Balance::new(fill.time, 0.0, 0.0));
+            quote_balance.time = fill.time;
+            quote_balance.total += balance.total - previous.total;
+            quote_balance.available += balance.available - previous.available;
+
+            balance = fx_rates.aggregate(fill.time, &balances)?;
+            self.repository
+                .set_symbol_balances(self.engine_id, balances)?;
+        }
+
         // Add new Balance event to the Vec<Event>
         generated_events.push(Event::Balance(balance));
 
@@ -262,7 +442,7 @@ impl<Repository, Allocator, RiskManager, Statistic> PositionHandler
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
     Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
-    Allocator: OrderAllocator,
+    Allocator: OrderAllocator<Statistic>,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
 {
@@ -306,7 +486,7 @@ impl<Repository, Allocator, RiskManager, Statistic> StatisticHandler<Statistic>
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
     Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
-    Allocator: OrderAllocator,
+    Allocator: OrderAllocator<Statistic>,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
 {
@@ -323,11 +503,36 @@ where
     }
 }
 
+impl<Repository, Allocator, RiskManager, Statistic> StrategyStateHandler
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+where
+    Repository:
+        PositionHandler + BalanceHandler + StatisticHandler<Statistic> + StrategyStateHandler,
+    Allocator: OrderAllocator<Statistic>,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser,
+{
+    fn set_strategy_state(
+        &mut self,
+        strategy_state_id: &StrategyStateId,
+        state: serde_json::Value,
+    ) -> Result<(), RepositoryError> {
+        self.repository.set_strategy_state(strategy_state_id, state)
+    }
+
+    fn get_strategy_state(
+        &mut self,
+        strategy_state_id: &StrategyStateId,
+    ) -> Result<Option<serde_json::Value>, RepositoryError> {
+        self.repository.get_strategy_state(strategy_state_id)
+    }
+}
+
 impl<Repository, Allocator, RiskManager, Statistic>
     MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
     Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
-    Allocator: OrderAllocator,
+    Allocator: OrderAllocator<Statistic>,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
 {
@@ -339,14 +544,33 @@ where
         // Construct MetaPortfolio instance
         let mut portfolio = Self {
             engine_id: lego.engine_id,
+            markets: lego.markets,
+            margin: lego.margin,
+            exit_rules: lego.exit_rules,
+            pending_exit_rules: HashMap::new(),
+            pending_attribution: HashMap::new(),
+            rebalance_tolerance: lego.rebalance_tolerance,
+            fx_rates: lego.reporting_currency.map(FxRates::new),
+            prices: HashMap::new(),
+            day_start_equity: None,
             repository: lego.repository,
             allocation_manager: lego.allocator,
             risk_manager: lego.risk,
             _statistic_marker: PhantomData,
         };
 
+        // Multi-currency Portfolios must be able to value every market in the reporting currency
+        if let Some(fx_rates) = &portfolio.fx_rates {
+            fx_rates.validate_markets(&portfolio.markets)?;
+        }
+
         // Persist initial state in the repository
-        portfolio.bootstrap_repository(lego.starting_cash, &lego.markets, lego.statistic_config)?;
+        let markets = portfolio
+            .markets
+            .iter()
+            .map(|market| MarketId::new(&market.exchange, &market.instrument))
+            .collect::<Vec<_>>();
+        portfolio.bootstrap_repository(lego.starting_cash, markets, lego.statistic_config)?;
 
         Ok(portfolio)
     }
@@ -364,14 +588,20 @@ where
         Id: Into<MarketId>,
     {
         // Persist initial Balance (total & available)
-        self.repository.set_balance(
-            self.engine_id,
-            Balance {
-                time: Utc::now(),
-                total: starting_cash,
-                available: starting_cash,
-            },
-        )?;
+        let balance = Balance {
+            time: Utc::now(),
+            total: starting_cash,
+            available: starting_cash,
+        };
+        self.repository.set_balance(self.engine_id, balance)?;
+
+        // Multi-currency Portfolios start with the cash held in the reporting currency
+        if let Some(fx_rates) = &self.fx_rates {
+            self.repository.set_symbol_balances(
+                self.engine_id,
+                SymbolBalances::from([(fx_rates.reporting_currency.clone(), balance)]),
+            )?;
+        }
 
         // Persist initial MetaPortfolio Statistics for every Market
         markets.into_iter().try_for_each(|market| {
@@ -386,6 +616,332 @@ where
         MetaPortfolioBuilder::new()
     }
 
+    /// Generates a read-only [`PortfolioSnapshot`] of the current [`MetaPortfolio`] state, with
+    /// the equity marked at the start of the UTC day of the provided `time`.
+    ///
+    /// Multi-currency Portfolios report the [`Balance`] & every [`Position`] value in the
+    /// reporting currency, converted using the latest conversion rates.
+    pub fn snapshot(
+        &mut self,
+        time: DateTime<Utc>,
+    ) -> Result<PortfolioSnapshot<Statistic>, PortfolioError> {
+        let (balance, open_positions) = self.valued_balance_and_open_positions()?;
+
+        let day_start_equity = match self.day_start_equity(time)? {
+            Some(mark) => mark.equity,
+            None => equity(&balance, &open_positions),
+        };
+
+        let statistics = self
+            .markets
+            .iter()
+            .map(|market| {
+                let market_id = MarketId::new(&market.exchange, &market.instrument);
+                self.repository
+                    .get_statistics(&market_id)
+                    .map(|statistic| (market_id, statistic))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+
+        Ok(PortfolioSnapshot {
+            time,
+            balance,
+            open_positions,
+            day_start_equity,
+            prices: self.prices.clone(),
+            statistics,
+            fx_rates: self.fx_rates.clone(),
+        })
+    }
+
+    /// Returns the [`Balance`] & every open [`Position`], valued in the reporting currency if the
+    /// [`MetaPortfolio`] is multi-currency.
+    fn valued_balance_and_open_positions(
+        &mut self,
+    ) -> Result<(Balance, Vec<Position>), PortfolioError> {
+        let balance = self.repository.get_balance(self.engine_id)?;
+        let open_positions = self
+            .repository
+            .get_open_positions(self.engine_id, self.markets.iter())?;
+
+        let Some(fx_rates) = &self.fx_rates else {
+            return Ok((balance, open_positions));
+        };
+
+        let symbol_balances = self.repository.get_symbol_balances(self.engine_id)?;
+        let balance = fx_rates.aggregate(balance.time, &symbol_balances)?;
+        let open_positions = open_positions
+            .into_iter()
+            .map(|position| to_reporting_currency(fx_rates, position))
+            .collect::<Result<_, _>>()?;
+
+        Ok((balance, open_positions))
+    }
+
+    /// Returns the [`EquityMark`] at the start of the UTC day of the provided time, if it has
+    /// been marked.
+    fn day_start_equity(
+        &mut self,
+        time: DateTime<Utc>,
+    ) -> Result<Option<EquityMark>, PortfolioError> {
+        let marked_today = |mark: &EquityMark| mark.time.date_naive() == time.date_naive();
+
+        if !self.day_start_equity.as_ref().is_some_and(marked_today) {
+            self.day_start_equity = self.repository.get_day_start_equity(self.engine_id)?;
+        }
+
+        Ok(self.day_start_equity.filter(marked_today))
+    }
+
+    /// Marks the current equity as the start of the UTC day of the provided time, unless that
+    /// day has already been marked.
+    fn mark_day_start_equity(&mut self, time: DateTime<Utc>) -> Result<(), PortfolioError> {
+        if self.day_start_equity(time)?.is_some() {
+            return Ok(());
+        }
+
+        let (balance, open_positions) = match self.valued_balance_and_open_positions() {
+            Ok(valued) => valued,
+            // Conversion rates are derived from market data, so mark once they are observed
+            Err(PortfolioError::FxRateUnavailable { .. }) => return Ok(()),
+            Err(error) => return Err(error),
+        };
+
+        let mark = EquityMark::new(time, equity(&balance, &open_positions));
+        self.repository.set_day_start_equity(self.engine_id, mark)?;
+        self.day_start_equity = Some(mark);
+
+        Ok(())
+    }
+
+    /// Returns the [`MarginConfig`] of the provided market, defaulting to fully funded by cash.
+    fn margin_config(&self, exchange: &Exchange, instrument: &Instrument) -> MarginConfig {
+        self.margin
+            .get(&MarketId::new(exchange, instrument))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// May generate an [`OrderEvent`] after analysing an input advisory [`Signal`]. If
+    /// `reduce_only`, an [`OrderEvent`] that would enter or increase a [`Position`] is not
+    /// generated.
+    fn generate_signal_order(
+        &mut self,
+        signal: &Signal,
+        reduce_only: bool,
+    ) -> Result<Option<OrderEvent>, PortfolioError> {
+        // Determine the position_id & associated Option<Position> related to input SignalEvent
+        let position_id =
+            determine_position_id(self.engine_id, &signal.exchange, &signal.instrument);
+        let position = self.repository.get_open_position(&position_id)?;
+
+        // Rebalance towards the TargetPosition if the Signal provides one
+        if let Some(target) = signal.target {
+            return self.generate_target_order(signal, target, position, reduce_only);
+        }
+
+        // If signal is advising to open a new Position rather than close one, check we have cash
+        if position.is_none() && self.no_cash_to_enter_new_position()? {
+            return Ok(None);
+        }
+
+        // Parse signals from Strategy to determine net signal decision & associated strength
+        let position = position.as_ref();
+        let (signal_decision, signal_strength) =
+            match parse_signal_decisions(&position, &signal.signals) {
+                None => return Ok(None),
+                Some(net_signal) => net_signal,
+            };
+
+        // Halt OrderEvents entering or increasing a Position if only reductions are permitted
+        if reduce_only && signal_decision.is_entry() {
+            return Ok(None);
+        }
+
+        // Construct mutable OrderEvent that can be modified by Allocation & Risk management
+        let mut order = OrderEvent {
+            time: Utc::now(),
+            exchange: signal.exchange.clone(),
+            instrument: signal.instrument.clone(),
+            market_meta: signal.market_meta,
+            decision: *signal_decision,
+            quantity: 0.0,
+            order_type: OrderType::default(),
+        };
+
+        // Manage OrderEvent size allocation given a read-only snapshot of the Portfolio state
+        let snapshot = self.snapshot(signal.market_meta.time)?;
+        self.allocation_manager
+            .allocate_order(&mut order, position, *signal_strength, &snapshot);
+
+        // Allocation manager may size an OrderEvent to zero (eg/ insufficient data)
+        if order.quantity == 0.0 {
+            return Ok(None);
+        }
+
+        // Manage global risk when evaluating OrderEvent - keep the same, refine or reject
+        let order = self
+            .risk_manager
+            .evaluate_order_with_snapshot(order, &snapshot)
+            .map_err(PortfolioError::OrderRejected)?;
+
+        // Attach any Signal ExitRules & Attribution to the Position entered by this OrderEvent
+        if position.is_none() {
+            self.attach_entry_signal(position_id, signal, order.decision);
+        }
+
+        Ok(Some(order))
+    }
+
+    /// Generates an [`OrderEvent`] rebalancing the open [`Position`] (if any) of the [`Signal`]
+    /// market towards the provided [`TargetPosition`]. The delta quantity is ordered as is, so
+    /// the allocation manager is bypassed, but the [`OrderEvent`] is still evaluated by the risk
+    /// manager.
+    ///
+    /// A [`TargetPosition`] on the opposite [`Side`] of an open [`Position`] first exits it, and
+    /// the remainder is entered by the next [`Signal`].
+    fn generate_target_order(
+        &mut self,
+        signal: &Signal,
+        target: TargetPosition,
+        position: Option<Position>,
+        reduce_only: bool,
+    ) -> Result<Option<OrderEvent>, PortfolioError> {
+        // Determine the delta between the current & target Position quantity
+        let snapshot = self.snapshot(signal.market_meta.time)?;
+
+        // Weights are a fraction of equity, so the price must be valued in the same currency
+        let Some(price) = snapshot.to_reporting(&signal.instrument, signal.market_meta.close)
+        else {
+            return Ok(None);
+        };
+        let target = target.quantity(snapshot.equity(), price);
+        let (decision, quantity) =
+            match parse_target_delta(position.as_ref(), target, self.rebalance_tolerance) {
+                None => return Ok(None),
+                Some(delta) => delta,
+            };
+
+        // Halt OrderEvents entering or increasing a Position if only reductions are permitted
+        if reduce_only && decision.is_entry() {
+            return Ok(None);
+        }
+
+        // If increasing exposure rather than reducing it, check we have cash
+        if decision.is_entry() && self.no_cash_to_enter_new_position()? {
+            return Ok(None);
+        }
+
+        // Manage global risk when evaluating OrderEvent - keep the same, refine or reject
+        let order = self
+            .risk_manager
+            .evaluate_order_with_snapshot(
+                OrderEvent {
+                    time: Utc::now(),
+                    exchange: signal.exchange.clone(),
+                    instrument: signal.instrument.clone(),
+                    market_meta: signal.market_meta,
+                    decision,
+                    quantity,
+                    order_type: OrderType::default(),
+                },
+                &snapshot,
+            )
+            .map_err(PortfolioError::OrderRejected)?;
+
+        // Attach any Signal ExitRules & Attribution to the Position entered by this OrderEvent
+        if position.is_none() {
+            let position_id =
+                determine_position_id(self.engine_id, &signal.exchange, &signal.instrument);
+            self.attach_entry_signal(position_id, signal, order.decision);
+        }
+
+        Ok(Some(order))
+    }
+
+    /// Records the [`ExitRules`] & entry [`Decision`] [`Attribution`] of the provided entry
+    /// [`Signal`], to be attached to the [`Position`] entered by it's [`OrderEvent`].
+    fn attach_entry_signal(
+        &mut self,
+        position_id: PositionId,
+        signal: &Signal,
+        decision: Decision,
+    ) {
+        if let Some(exit_rules) = signal.exit_rules {
+            self.pending_exit_rules
+                .insert(position_id.clone(), exit_rules);
+        }
+
+        let attribution = signal
+            .attribution
+            .iter()
+            .filter(|attribution| attribution.decision == decision)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if !attribution.is_empty() {
+            self.pending_attribution.insert(position_id, attribution);
+        }
+    }
+
+    /// Exits the provided [`Position`] using the exit [`FillEvent`], updating the Portfolio
+    /// [`Balance`], market statistics & exited [`Position`]s.
+    fn exit_position(
+        &mut self,
+        mut position: Position,
+        balance: &mut Balance,
+        fill: &FillEvent,
+    ) -> Result<PositionExit, PortfolioError> {
+        // Exit Position (in place mutation)
+        let position_exit = position.exit(*balance, fill)?;
+
+        // Update Portfolio balance on Position exit
+        // '--> available balance releases the margin posted to enter the Position
+        // '--> available balance adds enter_total_fees since included in result PnL calc
+        balance.available +=
+            position.margin + position.realised_profit_loss + position.enter_fees_total;
+        balance.total += position.realised_profit_loss;
+
+        // Update statistics for exited Position market
+        let market_id = MarketId::new(&fill.exchange, &fill.instrument);
+
+        let mut stats = self.repository.get_statistics(&market_id)?;
+        stats.update(&position);
+
+        // Persist exited Position & Updated Market statistics in Repository
+        self.repository.set_statistics(market_id, stats)?;
+        self.repository
+            .set_exited_position(self.engine_id, position)?;
+
+        Ok(position_exit)
+    }
+
+    /// Determines the liquidation price of the provided open [`Position`], with Cross margin
+    /// also backed by the available balance.
+    fn liquidation_price(&self, position: &Position, available: f64) -> Option<f64> {
+        let margin = self.margin_config(&position.exchange, &position.instrument);
+
+        let collateral = match margin.mode {
+            MarginMode::Cross => position.margin + available,
+            MarginMode::Cash | MarginMode::Isolated => position.margin,
+        };
+
+        margin.liquidation_price(
+            position.side,
+            position.enter_avg_price_gross,
+            position.quantity,
+            collateral,
+        )
+    }
+
+    /// Returns the [`ExitRules`] of the provided market, defaulting to no rules.
+    fn exit_rules_config(&self, exchange: &Exchange, instrument: &Instrument) -> ExitRules {
+        self.exit_rules
+            .get(&MarketId::new(exchange, instrument))
+            .copied()
+            .unwrap_or_default()
+    }
+
     /// Determines if the Portfolio has any cash to enter a new [`Position`].
     fn no_cash_to_enter_new_position(&mut self) -> Result<bool, PortfolioError> {
         self.repository
@@ -399,12 +955,16 @@ where
 pub struct MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic>
 where
     Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
-    Allocator: OrderAllocator,
+    Allocator: OrderAllocator<Statistic>,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
 {
     engine_id: Option<Uuid>,
     markets: Option<Vec<Market>>,
+    margin: HashMap<MarketId, MarginConfig>,
+    exit_rules: HashMap<MarketId, ExitRules>,
+    rebalance_tolerance: f64,
+    reporting_currency: Option<Symbol>,
     starting_cash: Option<f64>,
     repository: Option<Repository>,
     allocation_manager: Option<Allocator>,
@@ -417,7 +977,7 @@ impl<Repository, Allocator, RiskManager, Statistic>
     MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic>
 where
     Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
-    Allocator: OrderAllocator,
+    Allocator: OrderAllocator<Statistic>,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
 {
@@ -425,6 +985,10 @@ where
         Self {
             engine_id: None,
             markets: None,
+            margin: HashMap::new(),
+            exit_rules: HashMap::new(),
+            rebalance_tolerance: 0.0,
+            reporting_currency: None,
             starting_cash: None,
             repository: None,
             allocation_manager: None,
@@ -448,6 +1012,34 @@ where
         }
     }
 
+    pub fn margin(self, value: HashMap<MarketId, MarginConfig>) -> Self {
+        Self {
+            margin: value,
+            ..self
+        }
+    }
+
+    pub fn exit_rules(self, value: HashMap<MarketId, ExitRules>) -> Self {
+        Self {
+            exit_rules: value,
+            ..self
+        }
+    }
+
+    pub fn rebalance_tolerance(self, value: f64) -> Self {
+        Self {
+            rebalance_tolerance: value,
+            ..self
+        }
+    }
+
+    pub fn reporting_currency(self, value: Symbol) -> Self {
+        Self {
+            reporting_currency: Some(value),
+            ..self
+        }
+    }
+
     pub fn starting_cash(self, value: f64) -> Self {
         Self {
             starting_cash: Some(value),
@@ -491,6 +1083,17 @@ where
             engine_id: self
                 .engine_id
                 .ok_or(PortfolioError::BuilderIncomplete("engine_id"))?,
+            markets: self
+                .markets
+                .ok_or(PortfolioError::BuilderIncomplete("markets"))?,
+            margin: self.margin,
+            exit_rules: self.exit_rules,
+            pending_exit_rules: HashMap::new(),
+            pending_attribution: HashMap::new(),
+            rebalance_tolerance: self.rebalance_tolerance,
+            fx_rates: self.reporting_currency.map(FxRates::new),
+            prices: HashMap::new(),
+            day_start_equity: None,
             repository: self
                 .repository
                 .ok_or(PortfolioError::BuilderIncomplete("repository"))?,
@@ -503,13 +1106,21 @@ where
             _statistic_marker: PhantomData,
         };
 
+        // Multi-currency Portfolios must be able to value every market in the reporting currency
+        if let Some(fx_rates) = &portfolio.fx_rates {
+            fx_rates.validate_markets(&portfolio.markets)?;
+        }
+
         // Persist initial state in the Repository
+        let markets = portfolio
+            .markets
+            .iter()
+            .map(|market| MarketId::new(&market.exchange, &market.instrument))
+            .collect::<Vec<_>>();
         portfolio.bootstrap_repository(
             self.starting_cash
                 .ok_or(PortfolioError::BuilderIncomplete("starting_cash"))?,
-            &self
-                .markets
-                .ok_or(PortfolioError::BuilderIncomplete("markets"))?,
+            markets,
             self.statistic_config
                 .ok_or(PortfolioError::BuilderIncomplete("statistic_config"))?,
         )?;
@@ -518,6 +1129,49 @@ where
     }
 }
 
+/// Determines the latest price of the market from the input [`MarketEvent`], if it has one.
+fn market_price(market: &MarketEvent<Instrument, DataKind>) -> Option<f64> {
+    match &market.kind {
+        DataKind::Trade(trade) => Some(trade.price),
+        DataKind::Candle(candle) => Some(candle.close),
+        DataKind::OrderBookL1(book_l1) => Some(book_l1.volume_weighed_mid_price()),
+        DataKind::OrderBook(book) => book.volume_weighed_mid_price(),
+        DataKind::Liquidation(_) => None,
+    }
+}
+
+/// Equity of a Portfolio, being the [`Balance`] total plus the unrealised P&L of every open
+/// [`Position`].
+fn equity(balance: &Balance, open_positions: &[Position]) -> f64 {
+    balance.total
+        + open_positions
+            .iter()
+            .map(|position| position.unrealised_profit_loss)
+            .sum::<f64>()
+}
+
+/// Converts the monetary values of the provided [`Position`], denominated in it's quote
+/// [`Symbol`], into the reporting currency of the provided [`FxRates`].
+fn to_reporting_currency(
+    fx_rates: &FxRates,
+    mut position: Position,
+) -> Result<Position, PortfolioError> {
+    let quote = position.instrument.quote.clone();
+    let convert = |value: f64| fx_rates.to_reporting(&quote, value);
+
+    position.enter_value_gross = convert(position.enter_value_gross)?;
+    position.enter_fees_total = convert(position.enter_fees_total)?;
+    position.exit_value_gross = convert(position.exit_value_gross)?;
+    position.exit_fees_total = convert(position.exit_fees_total)?;
+    position.current_value_gross = convert(position.current_value_gross)?;
+    position.unrealised_profit_loss = convert(position.unrealised_profit_loss)?;
+    position.realised_profit_loss = convert(position.realised_profit_loss)?;
+    position.funding_total = convert(position.funding_total)?;
+    position.margin = convert(position.margin)?;
+
+    Ok(position)
+}
+
 /// Parses an incoming [`Signal`]'s signals map. Determines what the net signal [`Decision`]
 /// will be, and it's associated [`SignalStrength`].
 pub fn parse_signal_decisions<'a>(
@@ -547,6 +1201,35 @@ pub fn parse_signal_decisions<'a>(
     }
 }
 
+/// Determines the [`Decision`] & signed quantity of the [`OrderEvent`] that moves the provided
+/// open [`Position`] (if any) towards the target quantity. Returns `None` if the change is within
+/// the rebalance tolerance, expressed as a fraction of the open [`Position`] quantity.
+///
+/// An [`OrderEvent`] never crosses from long to short (or vice versa) in one step, since a
+/// [`Position`] is exited before one on the opposite [`Side`] can be entered.
+pub fn parse_target_delta(
+    position: Option<&Position>,
+    target: f64,
+    rebalance_tolerance: f64,
+) -> Option<(Decision, f64)> {
+    let current = position.map_or(0.0, |position| position.quantity);
+    let delta = target - current;
+
+    // Ignore changes within tolerance, unless exiting to flat
+    if delta == 0.0 || (target != 0.0 && delta.abs() <= rebalance_tolerance * current.abs()) {
+        return None;
+    }
+
+    match position.map(|position| position.side) {
+        None if delta > 0.0 => Some((Decision::Long, delta)),
+        None => Some((Decision::Short, delta)),
+        Some(Side::Buy) if delta > 0.0 => Some((Decision::Long, delta)),
+        Some(Side::Buy) => Some((Decision::CloseLong, delta.max(-current))),
+        Some(Side::Sell) if delta < 0.0 => Some((Decision::Short, delta)),
+        Some(Side::Sell) => Some((Decision::CloseShort, delta.min(-current))),
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -554,11 +1237,11 @@ pub mod tests {
     use crate::{
         execution::Fees,
         portfolio::{
-            allocator::DefaultAllocator, position::PositionBuilder,
+            allocator::DefaultAllocator, exit::ExitTrigger, position::PositionBuilder,
             repository::error::RepositoryError, risk::DefaultRisk,
         },
         statistic::summary::pnl::PnLReturnSummary,
-        strategy::SignalForceExit,
+        strategy::{Attribution, SignalForceExit, TargetPosition},
         test_util::{fill_event, market_event_trade, position, signal},
     };
     use barter_integration::model::{
@@ -586,24 +1269,31 @@ pub mod tests {
         get_statistics: Option<fn(market_id: &MarketId) -> Result<Statistic, RepositoryError>>,
         position: Option<PositionBuilder>,
         balance: Option<Balance>,
+        symbol_balances: SymbolBalances,
+        day_start_equity: Option<EquityMark>,
     }
 
     impl<Statistic> PositionHandler for MockRepository<Statistic> {
         fn set_open_position(&mut self, position: Position) -> Result<(), RepositoryError> {
-            self.position = Some(
-                Position::builder()
-                    .side(position.side.clone())
-                    .current_symbol_price(position.current_symbol_price)
-                    .current_value_gross(position.current_value_gross)
-                    .enter_fees_total(position.enter_fees_total)
-                    .enter_value_gross(position.enter_value_gross)
-                    .enter_avg_price_gross(position.enter_avg_price_gross)
-                    .exit_fees_total(position.exit_fees_total)
-                    .exit_value_gross(position.exit_value_gross)
-                    .exit_avg_price_gross(position.exit_avg_price_gross)
-                    .unrealised_profit_loss(position.unrealised_profit_loss)
-                    .realised_profit_loss(position.realised_profit_loss),
-            );
+            let builder = Position::builder()
+                .side(position.side)
+                .quantity(position.quantity)
+                .current_symbol_price(position.current_symbol_price)
+                .current_value_gross(position.current_value_gross)
+                .enter_fees_total(position.enter_fees_total)
+                .enter_value_gross(position.enter_value_gross)
+                .enter_avg_price_gross(position.enter_avg_price_gross)
+                .exit_fees_total(position.exit_fees_total)
+                .exit_value_gross(position.exit_value_gross)
+                .exit_avg_price_gross(position.exit_avg_price_gross)
+                .unrealised_profit_loss(position.unrealised_profit_loss)
+                .realised_profit_loss(position.realised_profit_loss)
+                .margin(position.margin)
+                .attribution(position.attribution.clone());
+            self.position = Some(PositionBuilder {
+                liquidation_price: position.liquidation_price,
+                ..builder
+            });
             self.set_open_position.unwrap()(position)
         }
 
@@ -655,8 +1345,34 @@ pub mod tests {
             self.set_balance.unwrap()(engine_id, balance)
         }
 
-        fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError> {
-            self.get_balance.unwrap()(engine_id)
+        fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError> {
+            self.get_balance.unwrap()(engine_id)
+        }
+
+        fn set_symbol_balances(
+            &mut self,
+            _: Uuid,
+            balances: SymbolBalances,
+        ) -> Result<(), RepositoryError> {
+            self.symbol_balances = balances;
+            Ok(())
+        }
+
+        fn get_symbol_balances(&mut self, _: Uuid) -> Result<SymbolBalances, RepositoryError> {
+            Ok(self.symbol_balances.clone())
+        }
+
+        fn set_day_start_equity(
+            &mut self,
+            _: Uuid,
+            mark: EquityMark,
+        ) -> Result<(), RepositoryError> {
+            self.day_start_equity = Some(mark);
+            Ok(())
+        }
+
+        fn get_day_start_equity(&mut self, _: Uuid) -> Result<Option<EquityMark>, RepositoryError> {
+            Ok(self.day_start_equity)
         }
     }
 
@@ -704,6 +1420,15 @@ pub mod tests {
             engine_id: builder
                 .engine_id
                 .ok_or(PortfolioError::BuilderIncomplete("engine_id"))?,
+            markets: builder.markets.unwrap_or_default(),
+            margin: builder.margin,
+            exit_rules: builder.exit_rules,
+            pending_exit_rules: HashMap::new(),
+            pending_attribution: HashMap::new(),
+            rebalance_tolerance: builder.rebalance_tolerance,
+            fx_rates: builder.reporting_currency.map(FxRates::new),
+            prices: HashMap::new(),
+            day_start_equity: None,
             repository: builder
                 .repository
                 .ok_or(PortfolioError::BuilderIncomplete("repository"))?,
@@ -742,6 +1467,8 @@ pub mod tests {
             }))
         });
         mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.get_balance = Some(|_| Ok(Balance::default()));
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input MarketEvent
@@ -791,6 +1518,8 @@ pub mod tests {
             }))
         });
         mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.get_balance = Some(|_| Ok(Balance::default()));
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input MarketEvent
@@ -835,6 +1564,8 @@ pub mod tests {
             }))
         });
         mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.get_balance = Some(|_| Ok(Balance::default()));
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input MarketEvent
@@ -880,6 +1611,8 @@ pub mod tests {
             }))
         });
         mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.get_balance = Some(|_| Ok(Balance::default()));
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input MarketEvent
@@ -967,6 +1700,7 @@ pub mod tests {
                 available: 100.0,
             })
         });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input SignalEvent
@@ -992,6 +1726,7 @@ pub mod tests {
                 available: 100.0,
             })
         });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input SignalEvent
@@ -1024,6 +1759,7 @@ pub mod tests {
                 available: 100.0,
             })
         });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input SignalEvent
@@ -1056,6 +1792,7 @@ pub mod tests {
                 available: 100.0,
             })
         });
+        mock_repository.get_open_positions = Some(|_, _| Ok(vec![]));
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
 
         // Input SignalEvent
@@ -1180,6 +1917,60 @@ pub mod tests {
         assert_eq!(updated_cash, 200.0 - 100.0 - 3.0); // cash += enter_value_gross - enter_fees
     }
 
+    #[test]
+    fn update_from_fill_entering_position_attributed_to_entry_signal() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| Ok(None)),
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: 200.0,
+                    available: 200.0,
+                })
+            }),
+            get_open_positions: Some(|_, _| Ok(vec![])),
+            remove_position: Some(|_| Ok(None)),
+            set_open_position: Some(|_| Ok(())),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input SignalEvent combined from many models
+        let attribution = |model: &str, decision| Attribution {
+            model: model.to_owned(),
+            decision,
+            strength: SignalStrength(1.0),
+            weight: 1.0,
+        };
+        let mut input_signal = signal();
+        input_signal
+            .signals
+            .insert(Decision::Long, SignalStrength(1.0));
+        input_signal.attribution = vec![
+            attribution("a", Decision::Long),
+            attribution("b", Decision::CloseShort),
+        ];
+        let order = portfolio.generate_order(&input_signal).unwrap().unwrap();
+
+        // Input FillEvent of the OrderEvent
+        let mut input_fill = fill_event();
+        input_fill.exchange = order.exchange;
+        input_fill.instrument = order.instrument;
+        input_fill.decision = order.decision;
+        input_fill.quantity = order.quantity;
+
+        portfolio.update_from_fill(&input_fill).unwrap();
+        let entered_position = portfolio.repository.position.unwrap();
+
+        // Only the Attribution of the entry Decision is recorded
+        assert_eq!(
+            entered_position.attribution,
+            Some(vec![attribution("a", Decision::Long)])
+        );
+    }
+
     #[test]
     fn update_from_fill_entering_short_position() {
         // Build Portfolio
@@ -1219,6 +2010,255 @@ pub mod tests {
         assert_eq!(updated_cash, 200.0 - 100.0 - 3.0); // cash += enter_value_gross - enter_fees
     }
 
+    #[test]
+    fn update_from_fill_entering_long_position_on_isolated_margin() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: 200.0,
+                    available: 200.0,
+                })
+            }),
+            remove_position: Some(|_| Ok(None)),
+            set_open_position: Some(|_| Ok(())),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+        portfolio.margin = HashMap::from([(
+            MarketId::new(
+                &Exchange::from("binance"),
+                &Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+            ),
+            MarginConfig {
+                mode: MarginMode::Isolated,
+                leverage: 10.0,
+                maintenance_margin_rate: 0.0,
+            },
+        )]);
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 100.0;
+        input_fill.fees = Fees {
+            exchange: 1.0,
+            slippage: 1.0,
+            network: 1.0,
+        };
+
+        let result = portfolio.update_from_fill(&input_fill);
+        let updated_repository = portfolio.repository;
+        let entered_position = updated_repository.position.unwrap();
+        let updated_cash = updated_repository.balance.unwrap().available;
+
+        assert!(result.is_ok());
+        assert_eq!(entered_position.enter_value_gross.unwrap(), 100.0);
+        assert_eq!(entered_position.margin.unwrap(), 10.0);
+        assert_eq!(entered_position.liquidation_price, Some(90.0));
+        assert_eq!(updated_cash, 200.0 - 10.0 - 3.0); // cash += initial_margin - enter_fees
+    }
+
+    #[test]
+    fn update_from_fill_entering_position_quoted_in_non_reporting_currency() {
+        // Build Portfolio reporting in usdt, holding 1000.0 usdt
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: 1000.0,
+                    available: 1000.0,
+                })
+            }),
+            remove_position: Some(|_| Ok(None)),
+            set_open_position: Some(|_| Ok(())),
+            set_balance: Some(|_, _| Ok(())),
+            symbol_balances: SymbolBalances::from([(
+                Symbol::from("usdt"),
+                Balance::new(Utc::now(), 1000.0, 1000.0),
+            )]),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Conversion rate of 1 btc = 1000.0 usdt
+        let mut fx_rates = FxRates::new("usdt");
+        fx_rates.set_price(&Symbol::from("btc"), &Symbol::from("usdt"), 1000.0);
+        portfolio.fx_rates = Some(fx_rates);
+
+        // Input FillEvent entering eth-btc Position worth 0.5 btc, with 0.25 btc fees
+        let mut input_fill = fill_event();
+        input_fill.instrument = Instrument::from(("eth", "btc", InstrumentKind::Spot));
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 0.5;
+        input_fill.fees = Fees {
+            exchange: 0.25,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let result = portfolio.update_from_fill(&input_fill);
+        let updated_repository = portfolio.repository;
+        let updated_balance = updated_repository.balance.unwrap();
+        let btc_balance = updated_repository.symbol_balances[&Symbol::from("btc")];
+
+        assert!(result.is_ok());
+        // btc balance posts the enter value & fees, usdt balance is untouched
+        assert_eq!(btc_balance.total, 0.0);
+        assert_eq!(btc_balance.available, -0.75);
+        assert_eq!(
+            updated_repository.symbol_balances[&Symbol::from("usdt")].available,
+            1000.0
+        );
+        // Aggregate balance is reported in usdt
+        assert_eq!(updated_balance.total, 1000.0);
+        assert_eq!(updated_balance.available, 1000.0 - 0.75 * 1000.0);
+    }
+
+    #[test]
+    fn update_from_fill_without_conversion_rate_changes_no_state() {
+        // Build Portfolio reporting in usdt, without a conversion rate from btc to usdt
+        let mock_repository = MockRepository::<PnLReturnSummary>::default();
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+        portfolio.fx_rates = Some(FxRates::new("usdt"));
+
+        // Input FillEvent entering eth-btc Position
+        let mut input_fill = fill_event();
+        input_fill.instrument = Instrument::from(("eth", "btc", InstrumentKind::Spot));
+
+        // Fails before any Position or Balance is read or written
+        let result = portfolio.update_from_fill(&input_fill);
+        assert!(matches!(
+            result,
+            Err(PortfolioError::FxRateUnavailable { from, .. }) if from == Symbol::from("btc")
+        ));
+        assert!(portfolio.repository.position.is_none());
+        assert!(portfolio.repository.balance.is_none());
+    }
+
+    #[test]
+    fn margin_call_with_isolated_position_at_maintenance_margin() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| {
+                Ok(Some({
+                    let mut input_position = position();
+                    input_position.margin = 10.0;
+                    input_position.current_value_gross = 90.5;
+                    input_position.unrealised_profit_loss = -9.5;
+                    input_position
+                }))
+            }),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        let mut config = MarginConfig {
+            mode: MarginMode::Isolated,
+            leverage: 10.0,
+            maintenance_margin_rate: 0.0,
+        };
+        let market_id = MarketId::new(
+            &Exchange::from("binance"),
+            &Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+        );
+        let mut input_market = market_event_trade(Side::Buy);
+        input_market.exchange = Exchange::from("binance");
+        input_market.instrument = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        // Equity of 10.0 - 9.5 = 0.5 is above the maintenance margin of 90.5 * 0.005
+        config.maintenance_margin_rate = 0.005;
+        portfolio.margin = HashMap::from([(market_id.clone(), config)]);
+        assert!(portfolio.margin_call(&input_market).unwrap().is_none());
+
+        // Equity of 0.5 is below the maintenance margin of 90.5 * 0.01
+        config.maintenance_margin_rate = 0.01;
+        portfolio.margin = HashMap::from([(market_id, config)]);
+        let margin_call = portfolio.margin_call(&input_market).unwrap().unwrap();
+        assert_eq!(margin_call.equity, 0.5);
+        assert_eq!(margin_call.maintenance_margin, 90.5 * 0.01);
+    }
+
+    #[test]
+    fn margin_call_with_cross_position_quoted_in_non_reporting_currency() {
+        // Build Portfolio reporting in usdt, holding 400.0 usdt & an eth-btc Position worth
+        // 0.5 btc with an unrealised loss of 0.1 btc
+        fn cross_position() -> Position {
+            let mut input_position = position();
+            input_position.instrument = Instrument::from(("eth", "btc", InstrumentKind::Spot));
+            input_position.current_value_gross = 0.5;
+            input_position.unrealised_profit_loss = -0.1;
+            input_position
+        }
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| Ok(Some(cross_position()))),
+            get_open_positions: Some(|_, _| Ok(vec![cross_position()])),
+            get_balance: Some(|_| Ok(Balance::new(Utc::now(), 400.0, 400.0))),
+            symbol_balances: SymbolBalances::from([(
+                Symbol::from("usdt"),
+                Balance::new(Utc::now(), 400.0, 400.0),
+            )]),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Conversion rate of 1 btc = 1000.0 usdt
+        let mut fx_rates = FxRates::new("usdt");
+        fx_rates.set_price(&Symbol::from("btc"), &Symbol::from("usdt"), 1000.0);
+        portfolio.fx_rates = Some(fx_rates);
+
+        let instrument = Instrument::from(("eth", "btc", InstrumentKind::Spot));
+        portfolio.margin = HashMap::from([(
+            MarketId::new(&Exchange::from("binance"), &instrument),
+            MarginConfig {
+                mode: MarginMode::Cross,
+                leverage: 1.0,
+                maintenance_margin_rate: 1.0,
+            },
+        )]);
+        let mut input_market = market_event_trade(Side::Buy);
+        input_market.exchange = Exchange::from("binance");
+        input_market.instrument = instrument;
+
+        // Equity of 400.0 - 100.0 usdt is below the maintenance margin of 500.0 usdt
+        let margin_call = portfolio.margin_call(&input_market).unwrap().unwrap();
+        assert_eq!(margin_call.equity, 300.0);
+        assert_eq!(margin_call.maintenance_margin, 500.0);
+    }
+
+    #[test]
+    fn exit_trigger_with_open_position_beyond_stop_loss() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| {
+                Ok(Some({
+                    let mut input_position = position();
+                    input_position.current_symbol_price = 90.0;
+                    input_position.exit_rules = ExitRules {
+                        stop_loss: Some(0.1),
+                        ..Default::default()
+                    };
+                    input_position
+                }))
+            }),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        let mut input_market = market_event_trade(Side::Buy);
+        input_market.exchange = Exchange::from("binance");
+        input_market.instrument = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        let exit_triggered = portfolio.exit_trigger(&input_market).unwrap().unwrap();
+        assert_eq!(exit_triggered.trigger, ExitTrigger::StopLoss);
+        assert_eq!(exit_triggered.price, 90.0);
+        assert_eq!(exit_triggered.time, input_market.exchange_time);
+    }
+
     #[test]
     fn update_from_fill_exiting_long_position_in_profit() {
         // Build Portfolio
@@ -1431,6 +2471,405 @@ pub mod tests {
         assert_eq!(updated_value, 200.0 + (100.0 - 150.0 - 6.0));
     }
 
+    #[test]
+    fn generate_order_with_target_position_increasing_long_position() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| Ok(Some(position()))),
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: 1000.0,
+                    available: 900.0,
+                })
+            }),
+            get_open_positions: Some(|_, _| Ok(vec![position()])),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input SignalEvent with a TargetPosition of 3.0 contracts, ignoring the signals map
+        let mut input_signal = signal();
+        input_signal
+            .signals
+            .insert(Decision::Short, SignalStrength(1.0));
+        input_signal.target = Some(TargetPosition::Quantity(3.0));
+
+        let actual = portfolio.generate_order(&input_signal).unwrap().unwrap();
+
+        assert_eq!(actual.decision, Decision::Long);
+        assert_eq!(actual.quantity, 2.0);
+    }
+
+    #[test]
+    fn generate_order_with_target_weight_exiting_long_position_before_entering_short() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| Ok(Some(position()))),
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: 1000.0,
+                    available: 900.0,
+                })
+            }),
+            get_open_positions: Some(|_, _| Ok(vec![position()])),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input SignalEvent with a TargetPosition short 50% of equity
+        let mut input_signal = signal();
+        input_signal.target = Some(TargetPosition::Weight(-0.5));
+
+        let actual = portfolio.generate_order(&input_signal).unwrap().unwrap();
+
+        assert_eq!(actual.decision, Decision::CloseLong);
+        assert_eq!(actual.quantity, -1.0);
+    }
+
+    #[test]
+    fn generate_order_with_target_weight_quoted_in_non_reporting_currency() {
+        // Build Portfolio reporting in usdt, holding 10000.0 usdt
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| Ok(None)),
+            get_balance: Some(|_| Ok(Balance::new(Utc::now(), 10000.0, 10000.0))),
+            get_open_positions: Some(|_, _| Ok(vec![])),
+            symbol_balances: SymbolBalances::from([(
+                Symbol::from("usdt"),
+                Balance::new(Utc::now(), 10000.0, 10000.0),
+            )]),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input SignalEvent targeting long 10% of equity in eth-btc, priced at 0.05 btc
+        let mut input_signal = signal();
+        input_signal.instrument = Instrument::from(("eth", "btc", InstrumentKind::Spot));
+        input_signal.market_meta.close = 0.05;
+        input_signal.target = Some(TargetPosition::Weight(0.1));
+
+        // Without a btc conversion rate, the target cannot be valued so no order is generated
+        portfolio.fx_rates = Some(FxRates::new("usdt"));
+        assert!(portfolio.generate_order(&input_signal).unwrap().is_none());
+
+        // Conversion rate of 1 btc = 20000.0 usdt values the close at 1000.0 usdt
+        let mut fx_rates = FxRates::new("usdt");
+        fx_rates.set_price(&Symbol::from("btc"), &Symbol::from("usdt"), 20000.0);
+        portfolio.fx_rates = Some(fx_rates);
+
+        let actual = portfolio.generate_order(&input_signal).unwrap().unwrap();
+
+        assert_eq!(actual.decision, Decision::Long);
+        assert_eq!(actual.quantity, 1.0);
+    }
+
+    #[test]
+    fn generate_reducing_order_with_target_position_exiting_long_position() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| Ok(Some(position()))),
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: 1000.0,
+                    available: 900.0,
+                })
+            }),
+            get_open_positions: Some(|_, _| Ok(vec![position()])),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input SignalEvent with a flat TargetPosition & no signals
+        let mut input_signal = signal();
+        input_signal.target = Some(TargetPosition::Quantity(0.0));
+
+        let actual = portfolio
+            .generate_reducing_order(&input_signal)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(actual.decision, Decision::CloseLong);
+        assert_eq!(actual.quantity, -1.0);
+    }
+
+    #[test]
+    fn generate_reducing_order_with_target_position_increasing_long_position() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| Ok(Some(position()))),
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: 1000.0,
+                    available: 900.0,
+                })
+            }),
+            get_open_positions: Some(|_, _| Ok(vec![position()])),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input SignalEvent with an exit decision, but a TargetPosition increasing the Position
+        let mut input_signal = signal();
+        input_signal
+            .signals
+            .insert(Decision::CloseLong, SignalStrength(1.0));
+        input_signal.target = Some(TargetPosition::Quantity(3.0));
+
+        let actual = portfolio.generate_reducing_order(&input_signal).unwrap();
+
+        assert!(actual.is_none());
+    }
+
+    #[test]
+    fn update_from_fill_increasing_long_position() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: 200.0,
+                    available: 97.0,
+                })
+            }),
+            remove_position: Some(|_| {
+                Ok(Some({
+                    let mut input_position = position();
+                    input_position.enter_fees.exchange = 3.0;
+                    input_position.enter_fees_total = 3.0;
+                    input_position
+                }))
+            }),
+            set_open_position: Some(|_| Ok(())),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 120.0;
+        input_fill.fees = Fees {
+            exchange: 3.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let result = portfolio.update_from_fill(&input_fill).unwrap();
+        assert!(matches!(result[0], Event::PositionUpdate(_)));
+
+        let updated_repository = portfolio.repository;
+        let updated_position = updated_repository.position.unwrap();
+        assert_eq!(updated_position.quantity, Some(2.0));
+        assert_eq!(updated_position.enter_value_gross, Some(220.0));
+        assert_eq!(updated_position.enter_avg_price_gross, Some(110.0));
+        assert_eq!(updated_position.enter_fees_total, Some(6.0));
+        assert_eq!(updated_position.margin, Some(220.0));
+
+        // cash -= fill_value_gross + fill fees
+        let updated_balance = updated_repository.balance.unwrap();
+        assert_eq!(updated_balance.available, 97.0 - 120.0 - 3.0);
+        assert_eq!(updated_balance.total, 200.0);
+    }
+
+    #[test]
+    fn update_from_fill_reducing_long_position() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: 300.0,
+                    available: 96.0,
+                })
+            }),
+            remove_position: Some(|_| {
+                Ok(Some({
+                    let mut input_position = position();
+                    input_position.quantity = 2.0;
+                    input_position.enter_fees.exchange = 4.0;
+                    input_position.enter_fees_total = 4.0;
+                    input_position.enter_value_gross = 200.0;
+                    input_position.margin = 200.0;
+                    input_position
+                }))
+            }),
+            get_statistics: Some(|_| Ok(PnLReturnSummary::default())),
+            set_statistics: Some(|_, _| Ok(())),
+            set_exited_position: Some(|_, _| Ok(())),
+            set_open_position: Some(|_| Ok(())),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input FillEvent exiting half of the Position
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseLong;
+        input_fill.quantity = -1.0;
+        input_fill.fill_value_gross = 150.0;
+        input_fill.fees = Fees {
+            exchange: 2.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let result = portfolio.update_from_fill(&input_fill).unwrap();
+        assert!(matches!(result[0], Event::PositionExit(_)));
+        assert!(matches!(result[1], Event::PositionUpdate(_)));
+
+        // Remaining half of the Position stays open
+        let updated_repository = portfolio.repository;
+        let updated_position = updated_repository.position.unwrap();
+        assert_eq!(updated_position.quantity, Some(1.0));
+        assert_eq!(updated_position.enter_value_gross, Some(100.0));
+        assert_eq!(updated_position.enter_fees_total, Some(2.0));
+        assert_eq!(updated_position.margin, Some(100.0));
+
+        // LONG result_profit_loss = exit_value_gross - exited enter_value_gross - total_fees
+        // cash += exited margin + result_profit_loss + exited enter_fees_total
+        let updated_balance = updated_repository.balance.unwrap();
+        assert_eq!(
+            updated_balance.available,
+            96.0 + 100.0 + (150.0 - 100.0 - 4.0) + 2.0
+        );
+        assert_eq!(updated_balance.total, 300.0 + (150.0 - 100.0 - 4.0));
+    }
+
+    #[test]
+    fn snapshot_profit_loss_today_is_relative_to_day_start_equity_mark() {
+        // Build Portfolio with equity of 1000.0 marked at 1100.0 at the start of the day
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| Ok(Balance::new(Utc::now(), 1000.0, 1000.0))),
+            get_open_positions: Some(|_, _| Ok(vec![])),
+            get_open_position: Some(|_| Ok(None)),
+            day_start_equity: Some(EquityMark::new(Utc::now(), 1100.0)),
+            ..Default::default()
+        };
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        let snapshot = portfolio.snapshot(Utc::now()).unwrap();
+        assert_eq!(snapshot.profit_loss_today(), -100.0);
+
+        // Snapshot of a day not yet marked has no profit & loss
+        let tomorrow = Utc::now() + chrono::Duration::days(1);
+        let snapshot = portfolio.snapshot(tomorrow).unwrap();
+        assert_eq!(snapshot.profit_loss_today(), 0.0);
+
+        // First MarketEvent of the next day marks the day start equity & latest price
+        let mut input_market = market_event_trade(Side::Buy);
+        input_market.exchange_time = tomorrow;
+        portfolio.update_from_market(&input_market).unwrap();
+        assert_eq!(
+            portfolio.repository.day_start_equity,
+            Some(EquityMark::new(tomorrow, 1000.0))
+        );
+
+        let snapshot = portfolio.snapshot(tomorrow).unwrap();
+        assert_eq!(snapshot.day_start_equity, 1000.0);
+        assert_eq!(
+            snapshot.price(&input_market.exchange, &input_market.instrument),
+            market_price(&input_market)
+        );
+    }
+
+    #[test]
+    fn test_parse_target_delta() {
+        let long = Position {
+            quantity: 2.0,
+            ..position()
+        };
+        let short = Position {
+            side: Side::Sell,
+            quantity: -2.0,
+            ..position()
+        };
+
+        struct TestCase {
+            position: Option<Position>,
+            target: f64,
+            expected: Option<(Decision, f64)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no Position & flat target
+                position: None,
+                target: 0.0,
+                expected: None,
+            },
+            TestCase {
+                // TC1: no Position & long target enters long
+                position: None,
+                target: 1.5,
+                expected: Some((Decision::Long, 1.5)),
+            },
+            TestCase {
+                // TC2: no Position & short target enters short
+                position: None,
+                target: -1.5,
+                expected: Some((Decision::Short, -1.5)),
+            },
+            TestCase {
+                // TC3: long Position & larger long target increases long
+                position: Some(long.clone()),
+                target: 3.0,
+                expected: Some((Decision::Long, 1.0)),
+            },
+            TestCase {
+                // TC4: long Position & smaller long target reduces long
+                position: Some(long.clone()),
+                target: 0.5,
+                expected: Some((Decision::CloseLong, -1.5)),
+            },
+            TestCase {
+                // TC5: long Position & change within tolerance
+                position: Some(long.clone()),
+                target: 2.1,
+                expected: None,
+            },
+            TestCase {
+                // TC6: long Position & flat target exits long
+                position: Some(long.clone()),
+                target: 0.0,
+                expected: Some((Decision::CloseLong, -2.0)),
+            },
+            TestCase {
+                // TC7: long Position & short target exits long first
+                position: Some(long),
+                target: -1.0,
+                expected: Some((Decision::CloseLong, -2.0)),
+            },
+            TestCase {
+                // TC8: short Position & larger short target increases short
+                position: Some(short.clone()),
+                target: -3.0,
+                expected: Some((Decision::Short, -1.0)),
+            },
+            TestCase {
+                // TC9: short Position & smaller short target reduces short
+                position: Some(short.clone()),
+                target: -1.0,
+                expected: Some((Decision::CloseShort, 1.0)),
+            },
+            TestCase {
+                // TC10: short Position & long target exits short first
+                position: Some(short),
+                target: 1.0,
+                expected: Some((Decision::CloseShort, 2.0)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = parse_target_delta(test.position.as_ref(), test.target, 0.1);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
     #[test]
     fn parse_signal_decisions_to_net_close_long() {
         // Some(Position)
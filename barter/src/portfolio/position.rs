@@ -1,10 +1,18 @@
 use crate::{
     execution::{FeeAmount, Fees, FillEvent},
-    portfolio::{error::PortfolioError, Balance},
-    strategy::Decision,
+    portfolio::{
+        error::PortfolioError,
+        exit::ExitRules,
+        funding::{FundingPayment, FundingRate},
+        Balance,
+    },
+    strategy::{Attribution, Decision},
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Exchange, Side,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
@@ -102,6 +110,34 @@ pub struct Position {
 
     /// Realised P&L after the [`Position`] has closed.
     pub realised_profit_loss: f64,
+
+    /// Collateral posted to open the [`Position`]. Equal to the enter_value_gross when the
+    /// [`Position`] is fully funded by cash.
+    #[serde(default)]
+    pub margin: f64,
+
+    /// Price at which the [`Position`] is liquidated, if it is traded on margin.
+    #[serde(default)]
+    pub liquidation_price: Option<f64>,
+
+    /// Cumulative funding paid (-ve) or received (+ve) whilst a perpetual [`Position`] is open.
+    #[serde(default)]
+    pub funding_total: f64,
+
+    /// [`ExitRules`] evaluated against the [`Position`] on every market update.
+    #[serde(default)]
+    pub exit_rules: ExitRules,
+
+    /// Most favourable price reached since entry (highest if long, lowest if short), used by the
+    /// [`ExitRules`] trailing stop.
+    #[serde(default)]
+    pub peak_price: Option<f64>,
+
+    /// Contribution of each model to the [`Signal`](crate::strategy::Signal) that entered this
+    /// [`Position`], if it was combined from many (eg/ by an
+    /// [`Ensemble`](crate::strategy::ensemble::Ensemble)).
+    #[serde(default)]
+    pub attribution: Vec<Attribution>,
 }
 
 impl PositionEnterer for Position {
@@ -141,6 +177,12 @@ impl PositionEnterer for Position {
             current_value_gross: fill.fill_value_gross,
             unrealised_profit_loss,
             realised_profit_loss: 0.0,
+            margin: fill.fill_value_gross,
+            liquidation_price: None,
+            funding_total: 0.0,
+            exit_rules: ExitRules::default(),
+            peak_price: None,
+            attribution: vec![],
         })
     }
 }
@@ -160,6 +202,13 @@ impl PositionUpdater for Position {
 
         self.current_symbol_price = close;
 
+        // Most favourable price since entry
+        let peak = self.peak_price.unwrap_or(self.enter_avg_price_gross);
+        self.peak_price = Some(match self.side {
+            Side::Buy => peak.max(close),
+            Side::Sell => peak.min(close),
+        });
+
         // Market value gross
         self.current_value_gross = close * self.quantity.abs();
 
@@ -234,24 +283,120 @@ impl Position {
         }
     }
 
-    /// Calculate the approximate [`Position::unrealised_profit_loss`] of a [`Position`].
+    /// Calculate the approximate [`Position::unrealised_profit_loss`] of a [`Position`],
+    /// including any [`Position::funding_total`].
     pub fn calculate_unrealised_profit_loss(&self) -> f64 {
         let approx_total_fees = self.enter_fees_total * 2.0;
 
-        match self.side {
+        let profit_loss = match self.side {
             Side::Buy => self.current_value_gross - self.enter_value_gross - approx_total_fees,
             Side::Sell => self.enter_value_gross - self.current_value_gross - approx_total_fees,
-        }
+        };
+
+        profit_loss + self.funding_total
     }
 
-    /// Calculate the exact [`Position::realised_profit_loss`] of a [`Position`].
+    /// Calculate the exact [`Position::realised_profit_loss`] of a [`Position`], including any
+    /// [`Position::funding_total`].
     pub fn calculate_realised_profit_loss(&self) -> f64 {
         let total_fees = self.enter_fees_total + self.exit_fees_total;
 
-        match self.side {
+        let profit_loss = match self.side {
             Side::Buy => self.exit_value_gross - self.enter_value_gross - total_fees,
             Side::Sell => self.enter_value_gross - self.exit_value_gross - total_fees,
+        };
+
+        profit_loss + self.funding_total
+    }
+
+    /// Applies a [`FundingRate`] to an open perpetual [`Position`], debiting or crediting the
+    /// [`Position::funding_total`] based on it's current value. Returns `None` if the
+    /// [`Position`] is not for an [`InstrumentKind::Perpetual`].
+    pub fn apply_funding(&mut self, funding: &FundingRate) -> Option<FundingPayment> {
+        if self.instrument.kind != InstrumentKind::Perpetual {
+            return None;
+        }
+
+        // Longs pay shorts when the funding rate is positive
+        let amount = match self.side {
+            Side::Buy => -funding.rate * self.current_value_gross,
+            Side::Sell => funding.rate * self.current_value_gross,
+        };
+
+        self.funding_total += amount;
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+        self.meta.update_time = funding.time;
+
+        Some(FundingPayment {
+            time: funding.time,
+            position_id: self.position_id.clone(),
+            exchange: self.exchange.clone(),
+            instrument: self.instrument.clone(),
+            rate: funding.rate,
+            amount,
+            funding_total: self.funding_total,
+        })
+    }
+
+    /// Increases this open [`Position`] by an entry [`FillEvent`] of the same [`Side`], updating
+    /// the enter average price, value & fees. The margin posted for the increase is managed by the
+    /// Portfolio.
+    pub fn increase(&mut self, fill: &FillEvent) -> Result<(), PortfolioError> {
+        if Position::parse_entry_side(fill)? != self.side {
+            return Err(PortfolioError::CannotIncreasePositionWithOppositeFill);
         }
+
+        // Enter fees
+        self.enter_fees = Fees {
+            exchange: self.enter_fees.exchange + fill.fees.exchange,
+            slippage: self.enter_fees.slippage + fill.fees.slippage,
+            network: self.enter_fees.network + fill.fees.network,
+        };
+        self.enter_fees_total = self.enter_fees.calculate_total_fees();
+
+        // Enter quantity, value & price
+        self.quantity += fill.quantity;
+        self.enter_value_gross += fill.fill_value_gross;
+        self.enter_avg_price_gross = self.enter_value_gross / self.quantity.abs();
+
+        // Market value gross & unreal profit & loss at the fill price
+        self.current_symbol_price = Position::calculate_avg_price_gross(fill);
+        self.current_value_gross = self.current_symbol_price * self.quantity.abs();
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+
+        self.meta.update_time = fill.time;
+
+        Ok(())
+    }
+
+    /// Splits the provided absolute quantity off this open [`Position`], returning it as a
+    /// [`Position`] of the same [`Side`] that can be exited independently. Quantity dependent
+    /// values (eg/ enter value, fees, margin & funding) are apportioned pro rata, and this
+    /// [`Position`] retains the remainder.
+    pub fn split(&mut self, quantity: f64) -> Position {
+        let fraction = (quantity.abs() / self.quantity.abs()).min(1.0);
+
+        let mut split = self.clone();
+        split.scale(fraction);
+        self.scale(1.0 - fraction);
+
+        split
+    }
+
+    /// Scales every quantity dependent value of this [`Position`] by the provided fraction.
+    fn scale(&mut self, fraction: f64) {
+        self.quantity *= fraction;
+        self.enter_fees = Fees {
+            exchange: self.enter_fees.exchange * fraction,
+            slippage: self.enter_fees.slippage * fraction,
+            network: self.enter_fees.network * fraction,
+        };
+        self.enter_fees_total *= fraction;
+        self.enter_value_gross *= fraction;
+        self.current_value_gross *= fraction;
+        self.margin *= fraction;
+        self.funding_total *= fraction;
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
     }
 
     /// Calculate the PnL return of a closed [`Position`] - assumed [`Position::realised_profit_loss`] is
@@ -282,6 +427,12 @@ pub struct PositionBuilder {
     pub current_value_gross: Option<f64>,
     pub unrealised_profit_loss: Option<f64>,
     pub realised_profit_loss: Option<f64>,
+    pub margin: Option<f64>,
+    pub liquidation_price: Option<f64>,
+    pub funding_total: Option<f64>,
+    pub exit_rules: Option<ExitRules>,
+    pub peak_price: Option<f64>,
+    pub attribution: Option<Vec<Attribution>>,
 }
 
 impl PositionBuilder {
@@ -415,7 +566,53 @@ impl PositionBuilder {
         }
     }
 
+    pub fn margin(self, value: f64) -> Self {
+        Self {
+            margin: Some(value),
+            ..self
+        }
+    }
+
+    pub fn liquidation_price(self, value: f64) -> Self {
+        Self {
+            liquidation_price: Some(value),
+            ..self
+        }
+    }
+
+    pub fn funding_total(self, value: f64) -> Self {
+        Self {
+            funding_total: Some(value),
+            ..self
+        }
+    }
+
+    pub fn exit_rules(self, value: ExitRules) -> Self {
+        Self {
+            exit_rules: Some(value),
+            ..self
+        }
+    }
+
+    pub fn peak_price(self, value: f64) -> Self {
+        Self {
+            peak_price: Some(value),
+            ..self
+        }
+    }
+
+    pub fn attribution(self, value: Vec<Attribution>) -> Self {
+        Self {
+            attribution: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<Position, PortfolioError> {
+        let enter_value_gross = self
+            .enter_value_gross
+            .ok_or(PortfolioError::BuilderIncomplete("enter_value_gross"))?;
+
         Ok(Position {
             position_id: self
                 .position_id
@@ -440,9 +637,7 @@ impl PositionBuilder {
             enter_avg_price_gross: self
                 .enter_avg_price_gross
                 .ok_or(PortfolioError::BuilderIncomplete("enter_avg_price_gross"))?,
-            enter_value_gross: self
-                .enter_value_gross
-                .ok_or(PortfolioError::BuilderIncomplete("enter_value_gross"))?,
+            enter_value_gross,
             exit_fees: self
                 .exit_fees
                 .ok_or(PortfolioError::BuilderIncomplete("exit_fees"))?,
@@ -467,6 +662,13 @@ impl PositionBuilder {
             realised_profit_loss: self
                 .realised_profit_loss
                 .ok_or(PortfolioError::BuilderIncomplete("realised_profit_loss"))?,
+            // Positions are fully funded by cash unless specified
+            margin: self.margin.unwrap_or(enter_value_gross),
+            liquidation_price: self.liquidation_price,
+            funding_total: self.funding_total.unwrap_or_default(),
+            exit_rules: self.exit_rules.unwrap_or_default(),
+            peak_price: self.peak_price,
+            attribution: self.attribution.unwrap_or_default(),
         })
     }
 }
@@ -1640,4 +1842,90 @@ mod tests {
 
         assert!(PositionExit::try_from(&mut exited_position).is_err());
     }
+
+    #[test]
+    fn apply_funding_to_open_perpetual_position() {
+        struct TestCase {
+            kind: InstrumentKind,
+            side: Side,
+            rate: f64,
+            expected_amount: Option<f64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: spot Positions do not pay funding
+                kind: InstrumentKind::Spot,
+                side: Side::Buy,
+                rate: 0.01,
+                expected_amount: None,
+            },
+            TestCase {
+                // TC1: long pays positive funding rate
+                kind: InstrumentKind::Perpetual,
+                side: Side::Buy,
+                rate: 0.01,
+                expected_amount: Some(-2.0),
+            },
+            TestCase {
+                // TC2: short receives positive funding rate
+                kind: InstrumentKind::Perpetual,
+                side: Side::Sell,
+                rate: 0.01,
+                expected_amount: Some(2.0),
+            },
+            TestCase {
+                // TC3: long receives negative funding rate
+                kind: InstrumentKind::Perpetual,
+                side: Side::Buy,
+                rate: -0.01,
+                expected_amount: Some(2.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut position = position();
+            position.instrument = Instrument::from(("eth", "usdt", test.kind));
+            position.side = test.side;
+            position.current_value_gross = 200.0;
+            position.funding_total = 1.0;
+
+            let funding = FundingRate {
+                time: Utc::now(),
+                exchange: position.exchange.clone(),
+                instrument: position.instrument.clone(),
+                rate: test.rate,
+            };
+
+            let payment = position.apply_funding(&funding);
+            let actual_amount = payment.as_ref().map(|payment| payment.amount);
+            assert_eq!(actual_amount, test.expected_amount, "TC{} failed", index);
+
+            let expected_funding_total = 1.0 + test.expected_amount.unwrap_or_default();
+            assert_eq!(
+                position.funding_total, expected_funding_total,
+                "TC{} failed",
+                index
+            );
+            if payment.is_some() {
+                assert_eq!(
+                    position.unrealised_profit_loss,
+                    position.calculate_unrealised_profit_loss(),
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn calculate_realised_profit_loss_includes_funding() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.enter_value_gross = 100.0;
+        position.exit_value_gross = 110.0;
+        position.funding_total = -2.5;
+
+        assert_eq!(position.calculate_realised_profit_loss(), 7.5);
+    }
 }
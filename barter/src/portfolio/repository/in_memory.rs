@@ -1,11 +1,12 @@
 use crate::{
     portfolio::{
+        currency::SymbolBalances,
         position::{determine_position_id, Position, PositionId},
         repository::{
             determine_exited_positions_id, error::RepositoryError, BalanceHandler, PositionHandler,
-            StatisticHandler,
+            StatisticHandler, StrategyStateHandler, StrategyStateId,
         },
-        Balance, BalanceId,
+        Balance, BalanceId, EquityMark,
     },
     statistic::summary::PositionSummariser,
 };
@@ -13,8 +14,8 @@ use barter_integration::model::{Market, MarketId};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-/// In-Memory repository for Proof Of Concepts. Implements [`PositionHandler`], [`BalanceHandler`]
-/// & [`StatisticHandler`]. Used by a Proof Of Concept Portfolio implementation to
+/// In-Memory repository for Proof Of Concepts. Implements [`PositionHandler`], [`BalanceHandler`],
+/// [`StatisticHandler`] & [`StrategyStateHandler`]. Used by a Proof Of Concept Portfolio implementation to
 /// save the current equity, available cash, Positions, and market pair statistics.
 /// **Careful in production - no fault tolerant guarantees!**
 #[derive(Debug, Default)]
@@ -22,7 +23,10 @@ pub struct InMemoryRepository<Statistic: PositionSummariser> {
     open_positions: HashMap<PositionId, Position>,
     closed_positions: HashMap<String, Vec<Position>>,
     current_balances: HashMap<BalanceId, Balance>,
+    symbol_balances: HashMap<BalanceId, SymbolBalances>,
+    day_start_equities: HashMap<BalanceId, EquityMark>,
     statistics: HashMap<MarketId, Statistic>,
+    strategy_states: HashMap<StrategyStateId, serde_json::Value>,
 }
 
 impl<Statistic: PositionSummariser> PositionHandler for InMemoryRepository<Statistic> {
@@ -103,6 +107,44 @@ impl<Statistic: PositionSummariser> BalanceHandler for InMemoryRepository<Statis
             .copied()
             .ok_or(RepositoryError::ExpectedDataNotPresentError)
     }
+
+    fn set_symbol_balances(
+        &mut self,
+        engine_id: Uuid,
+        balances: SymbolBalances,
+    ) -> Result<(), RepositoryError> {
+        self.symbol_balances
+            .insert(Balance::symbol_balances_id(engine_id), balances);
+        Ok(())
+    }
+
+    fn get_symbol_balances(&mut self, engine_id: Uuid) -> Result<SymbolBalances, RepositoryError> {
+        Ok(self
+            .symbol_balances
+            .get(&Balance::symbol_balances_id(engine_id))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn set_day_start_equity(
+        &mut self,
+        engine_id: Uuid,
+        mark: EquityMark,
+    ) -> Result<(), RepositoryError> {
+        self.day_start_equities
+            .insert(EquityMark::day_start_id(engine_id), mark);
+        Ok(())
+    }
+
+    fn get_day_start_equity(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Option<EquityMark>, RepositoryError> {
+        Ok(self
+            .day_start_equities
+            .get(&EquityMark::day_start_id(engine_id))
+            .copied())
+    }
 }
 
 impl<Statistic: PositionSummariser> StatisticHandler<Statistic> for InMemoryRepository<Statistic> {
@@ -123,6 +165,25 @@ impl<Statistic: PositionSummariser> StatisticHandler<Statistic> for InMemoryRepo
     }
 }
 
+impl<Statistic: PositionSummariser> StrategyStateHandler for InMemoryRepository<Statistic> {
+    fn set_strategy_state(
+        &mut self,
+        strategy_state_id: &StrategyStateId,
+        state: serde_json::Value,
+    ) -> Result<(), RepositoryError> {
+        self.strategy_states
+            .insert(strategy_state_id.to_owned(), state);
+        Ok(())
+    }
+
+    fn get_strategy_state(
+        &mut self,
+        strategy_state_id: &StrategyStateId,
+    ) -> Result<Option<serde_json::Value>, RepositoryError> {
+        Ok(self.strategy_states.get(strategy_state_id).cloned())
+    }
+}
+
 impl<Statistic: PositionSummariser> InMemoryRepository<Statistic> {
     /// Constructs a new [`InMemoryRepository`] component.
     pub fn new() -> Self {
@@ -130,7 +191,10 @@ impl<Statistic: PositionSummariser> InMemoryRepository<Statistic> {
             open_positions: HashMap::new(),
             closed_positions: HashMap::new(),
             current_balances: HashMap::new(),
+            symbol_balances: HashMap::new(),
+            day_start_equities: HashMap::new(),
             statistics: HashMap::new(),
+            strategy_states: HashMap::new(),
         }
     }
 }
@@ -1,7 +1,8 @@
 use crate::portfolio::{
+    currency::SymbolBalances,
     position::{Position, PositionId},
     repository::error::RepositoryError,
-    Balance,
+    Balance, EquityMark,
 };
 use barter_integration::model::{Market, MarketId};
 use uuid::Uuid;
@@ -56,6 +57,27 @@ pub trait BalanceHandler {
     fn set_balance(&mut self, engine_id: Uuid, balance: Balance) -> Result<(), RepositoryError>;
     /// Get the Portfolio [`Balance`] using the engine_id provided.
     fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError>;
+    /// Upsert the Portfolio [`SymbolBalances`] at the engine_id.
+    fn set_symbol_balances(
+        &mut self,
+        engine_id: Uuid,
+        balances: SymbolBalances,
+    ) -> Result<(), RepositoryError>;
+    /// Get the Portfolio [`SymbolBalances`] using the engine_id provided, being empty if none
+    /// have been set.
+    fn get_symbol_balances(&mut self, engine_id: Uuid) -> Result<SymbolBalances, RepositoryError>;
+    /// Upsert the Portfolio [`EquityMark`] at the start of the UTC day at the engine_id.
+    fn set_day_start_equity(
+        &mut self,
+        engine_id: Uuid,
+        mark: EquityMark,
+    ) -> Result<(), RepositoryError>;
+    /// Get the Portfolio [`EquityMark`] at the start of the UTC day using the engine_id provided,
+    /// being `None` if it has not been set.
+    fn get_day_start_equity(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Option<EquityMark>, RepositoryError>;
 }
 
 /// Handles the reading & writing of a Portfolio's statistics for each of it's
@@ -71,6 +93,23 @@ pub trait StatisticHandler<Statistic> {
     fn get_statistics(&mut self, market_id: &MarketId) -> Result<Statistic, RepositoryError>;
 }
 
+/// Handles the reading & writing of a Strategy's state snapshot to/from the persistence layer.
+/// See [`StrategyState`](crate::strategy::StrategyState).
+pub trait StrategyStateHandler {
+    /// Upsert the Strategy state snapshot at the [`StrategyStateId`] provided.
+    fn set_strategy_state(
+        &mut self,
+        strategy_state_id: &StrategyStateId,
+        state: serde_json::Value,
+    ) -> Result<(), RepositoryError>;
+    /// Get the Strategy state snapshot using the [`StrategyStateId`] provided, being `None` if
+    /// one has never been set.
+    fn get_strategy_state(
+        &mut self,
+        strategy_state_id: &StrategyStateId,
+    ) -> Result<Option<serde_json::Value>, RepositoryError>;
+}
+
 /// Communicates a String represents a unique identifier for all a Portfolio's exited [`Position`]s.
 /// Used to append new exited [`Position`]s to the entry in the [`PositionHandler`].
 pub type ExitedPositionsId = String;
@@ -79,3 +118,14 @@ pub type ExitedPositionsId = String;
 pub fn determine_exited_positions_id(engine_id: Uuid) -> ExitedPositionsId {
     format!("positions_exited_{}", engine_id)
 }
+
+/// Communicates a String represents a unique identifier for the Strategy state snapshot of a
+/// [`Trader`](crate::engine::trader::Trader).
+pub type StrategyStateId = String;
+
+/// Returns the unique identifier for the Strategy state snapshot of the
+/// [`Trader`](crate::engine::trader::Trader) bartering on the provided [`Market`], given an
+/// engine_id.
+pub fn determine_strategy_state_id(engine_id: Uuid, market: &Market) -> StrategyStateId {
+    format!("strategy_state_{}_{}", engine_id, MarketId::from(market))
+}
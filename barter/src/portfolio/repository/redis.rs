@@ -1,12 +1,13 @@
 use crate::{
     portfolio::{
+        currency::SymbolBalances,
         error::PortfolioError,
         position::{determine_position_id, Position, PositionId},
         repository::{
             determine_exited_positions_id, error::RepositoryError, BalanceHandler, PositionHandler,
-            StatisticHandler,
+            StatisticHandler, StrategyStateHandler, StrategyStateId,
         },
-        Balance,
+        Balance, EquityMark,
     },
     statistic::summary::PositionSummariser,
 };
@@ -137,6 +138,58 @@ where
 
         Ok(serde_json::from_str::<Balance>(&balance_value)?)
     }
+
+    fn set_symbol_balances(
+        &mut self,
+        engine_id: Uuid,
+        balances: SymbolBalances,
+    ) -> Result<(), RepositoryError> {
+        let balances_string = serde_json::to_string(&balances)?;
+
+        self.conn
+            .set(Balance::symbol_balances_id(engine_id), balances_string)
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn get_symbol_balances(&mut self, engine_id: Uuid) -> Result<SymbolBalances, RepositoryError> {
+        let balances_value: Option<String> = self
+            .conn
+            .get(Balance::symbol_balances_id(engine_id))
+            .map_err(|_| RepositoryError::ReadError)?;
+
+        balances_value
+            .map(|balances| serde_json::from_str::<SymbolBalances>(&balances))
+            .transpose()
+            .map(Option::unwrap_or_default)
+            .map_err(RepositoryError::JsonSerDeError)
+    }
+
+    fn set_day_start_equity(
+        &mut self,
+        engine_id: Uuid,
+        mark: EquityMark,
+    ) -> Result<(), RepositoryError> {
+        let mark_string = serde_json::to_string(&mark)?;
+
+        self.conn
+            .set(EquityMark::day_start_id(engine_id), mark_string)
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn get_day_start_equity(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Option<EquityMark>, RepositoryError> {
+        let mark_value: Option<String> = self
+            .conn
+            .get(EquityMark::day_start_id(engine_id))
+            .map_err(|_| RepositoryError::ReadError)?;
+
+        mark_value
+            .map(|mark| serde_json::from_str::<EquityMark>(&mark))
+            .transpose()
+            .map_err(RepositoryError::JsonSerDeError)
+    }
 }
 
 impl<Statistic> StatisticHandler<Statistic> for RedisRepository<Statistic>
@@ -163,6 +216,36 @@ where
     }
 }
 
+impl<Statistic> StrategyStateHandler for RedisRepository<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn set_strategy_state(
+        &mut self,
+        strategy_state_id: &StrategyStateId,
+        state: serde_json::Value,
+    ) -> Result<(), RepositoryError> {
+        self.conn
+            .set(strategy_state_id, serde_json::to_string(&state)?)
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn get_strategy_state(
+        &mut self,
+        strategy_state_id: &StrategyStateId,
+    ) -> Result<Option<serde_json::Value>, RepositoryError> {
+        let state_value: Option<String> = self
+            .conn
+            .get(strategy_state_id)
+            .map_err(|_| RepositoryError::ReadError)?;
+
+        state_value
+            .map(|state| serde_json::from_str(&state))
+            .transpose()
+            .map_err(RepositoryError::JsonSerDeError)
+    }
+}
+
 impl<Statistic: PositionSummariser> Debug for RedisRepository<Statistic>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
@@ -1,6 +1,8 @@
+use crate::portfolio::{position::Position, OrderEvent, OrderType, PortfolioSnapshot};
+use barter_integration::model::instrument::spec::InstrumentSpecs;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-
-use crate::portfolio::{OrderEvent, OrderType};
+use thiserror::Error;
 
 /// Evaluates the risk associated with an [`OrderEvent`] to determine if it should be actioned. It
 /// can also amend the order (eg/ [`OrderType`]) to better fit the risk strategy required for
@@ -11,6 +13,20 @@ pub trait OrderEvaluator {
     /// May return an amended [`OrderEvent`] if the associated risk is appropriate. Returns `None`
     /// if the risk is too high.
     fn evaluate_order(&self, order: OrderEvent) -> Option<OrderEvent>;
+
+    /// May return an amended [`OrderEvent`] if the associated risk is appropriate given the
+    /// provided [`PortfolioSnapshot`]. Returns an [`OrderRejected`] detailing every
+    /// [`RiskViolation`] if the risk is too high.
+    ///
+    /// Defaults to [`Self::evaluate_order`], rejecting without any specific [`RiskViolation`]s.
+    fn evaluate_order_with_snapshot<Statistic>(
+        &self,
+        order: OrderEvent,
+        _: &PortfolioSnapshot<Statistic>,
+    ) -> Result<OrderEvent, Box<OrderRejected>> {
+        let rejected = OrderRejected::new(order.clone(), vec![]);
+        self.evaluate_order(order).ok_or_else(|| Box::new(rejected))
+    }
 }
 
 /// Default risk manager that implements [`OrderEvaluator`].
@@ -34,3 +50,722 @@ impl DefaultRisk {
         false
     }
 }
+
+/// Rule based risk manager that implements [`OrderEvaluator`]. Every rule is optional, and is
+/// disabled when `None`.
+///
+/// Notional values are calculated using the [`OrderEvent`] `market_meta.close` price, converted
+/// into the currency the [`PortfolioSnapshot`] is valued in (see
+/// [`PortfolioSnapshot::to_reporting`]), or left in the quote currency if evaluated without a
+/// [`PortfolioSnapshot`]. Exit orders reduce risk, so are never rejected.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RuleBasedRisk {
+    /// Maximum absolute quantity of a single [`OrderEvent`].
+    pub max_order_quantity: Option<f64>,
+    /// Maximum notional value of a single [`OrderEvent`].
+    pub max_order_notional: Option<f64>,
+    /// Maximum notional value of the [`Position`](super::position::Position) in a single
+    /// instrument, including the [`OrderEvent`].
+    pub max_position_notional: Option<f64>,
+    /// Maximum sum of the absolute notional value of every open
+    /// [`Position`](super::position::Position), including the [`OrderEvent`].
+    pub max_gross_exposure: Option<f64>,
+    /// Maximum absolute sum of the long minus short notional value of every open
+    /// [`Position`](super::position::Position), including the [`OrderEvent`].
+    pub max_net_exposure: Option<f64>,
+    /// Maximum number of open [`Position`](super::position::Position)s, including the one
+    /// entered by the [`OrderEvent`].
+    pub max_open_positions: Option<usize>,
+    /// Maximum fractional deviation of the [`OrderEvent`] `market_meta.close` price from the
+    /// latest market price recorded in the [`PortfolioSnapshot`] (eg/ a stale [`Signal`] price).
+    ///
+    /// [`Signal`]: crate::strategy::Signal
+    pub max_price_deviation: Option<f64>,
+    /// Maximum loss (realised & unrealised) since the start of the UTC day, beyond which new
+    /// [`Position`](super::position::Position)s cannot be entered.
+    pub max_daily_loss: Option<f64>,
+}
+
+impl OrderEvaluator for RuleBasedRisk {
+    const DEFAULT_ORDER_TYPE: OrderType = OrderType::Market;
+
+    /// Evaluates the rules that do not require a [`PortfolioSnapshot`] (order size & price).
+    fn evaluate_order(&self, order: OrderEvent) -> Option<OrderEvent> {
+        self.evaluate::<()>(order, None).ok()
+    }
+
+    fn evaluate_order_with_snapshot<Statistic>(
+        &self,
+        order: OrderEvent,
+        portfolio: &PortfolioSnapshot<Statistic>,
+    ) -> Result<OrderEvent, Box<OrderRejected>> {
+        self.evaluate(order, Some(portfolio))
+    }
+}
+
+impl RuleBasedRisk {
+    fn evaluate<Statistic>(
+        &self,
+        mut order: OrderEvent,
+        portfolio: Option<&PortfolioSnapshot<Statistic>>,
+    ) -> Result<OrderEvent, Box<OrderRejected>> {
+        let violations = self.violations(&order, portfolio);
+        if !violations.is_empty() {
+            return Err(Box::new(OrderRejected::new(order, violations)));
+        }
+
+        order.order_type = RuleBasedRisk::DEFAULT_ORDER_TYPE;
+        Ok(order)
+    }
+
+    /// Determine every [`RiskViolation`] of the [`OrderEvent`]. Rules that require a
+    /// [`PortfolioSnapshot`] are skipped if one is not provided.
+    pub fn violations<Statistic>(
+        &self,
+        order: &OrderEvent,
+        portfolio: Option<&PortfolioSnapshot<Statistic>>,
+    ) -> Vec<RiskViolation> {
+        if order.decision.is_exit() {
+            return vec![];
+        }
+
+        let price = order.market_meta.close;
+        if !price.is_finite() || price <= 0.0 {
+            return vec![RiskViolation::InvalidPrice { price }];
+        }
+
+        // Value the OrderEvent in the currency the PortfolioSnapshot (if any) is valued in
+        let notional = order.quantity.abs() * price;
+        let notional = match portfolio {
+            Some(portfolio) => match portfolio.to_reporting(&order.instrument, notional) {
+                Some(notional) => notional,
+                None => return vec![RiskViolation::FxRateUnavailable],
+            },
+            None => notional,
+        };
+
+        let mut violations = Vec::new();
+
+        if let Some(limit) = self.max_order_quantity {
+            if order.quantity.abs() > limit {
+                violations.push(RiskViolation::MaxOrderQuantity {
+                    quantity: order.quantity.abs(),
+                    limit,
+                });
+            }
+        }
+
+        if let Some(limit) = self.max_order_notional {
+            if notional > limit {
+                violations.push(RiskViolation::MaxOrderNotional { notional, limit });
+            }
+        }
+
+        let Some(portfolio) = portfolio else {
+            return violations;
+        };
+        let position = portfolio.open_position(&order.exchange, &order.instrument);
+
+        let reference = portfolio.price(&order.exchange, &order.instrument);
+        if let (Some(limit), Some(reference)) = (self.max_price_deviation, reference) {
+            let deviation = (price - reference).abs() / reference;
+            if deviation > limit {
+                violations.push(RiskViolation::PriceBand {
+                    price,
+                    reference,
+                    deviation,
+                    limit,
+                });
+            }
+        }
+
+        if let Some(limit) = self.max_position_notional {
+            let notional = notional + position.map_or(0.0, |position| position.current_value_gross);
+            if notional > limit {
+                violations.push(RiskViolation::MaxPositionNotional { notional, limit });
+            }
+        }
+
+        if let Some(limit) = self.max_gross_exposure {
+            let exposure = portfolio.gross_exposure() + notional;
+            if exposure > limit {
+                violations.push(RiskViolation::MaxGrossExposure { exposure, limit });
+            }
+        }
+
+        if let Some(limit) = self.max_net_exposure {
+            let signed_notional = match order.decision.is_long() {
+                true => notional,
+                false => -notional,
+            };
+            let exposure = (portfolio.net_exposure() + signed_notional).abs();
+            if exposure > limit {
+                violations.push(RiskViolation::MaxNetExposure { exposure, limit });
+            }
+        }
+
+        if let (Some(limit), None) = (self.max_open_positions, position) {
+            let open = portfolio.open_positions.len();
+            if open + 1 > limit {
+                violations.push(RiskViolation::MaxOpenPositions { open, limit });
+            }
+        }
+
+        if let Some(limit) = self.max_daily_loss {
+            let loss = -portfolio.profit_loss_today();
+            if loss >= limit {
+                violations.push(RiskViolation::MaxDailyLoss { loss, limit });
+            }
+        }
+
+        violations
+    }
+}
+
+/// [`OrderEvaluator`] that normalises every [`OrderEvent`] to the
+/// [`InstrumentSpec`](barter_integration::model::instrument::spec::InstrumentSpec) of it's market
+/// before evaluating it with the wrapped risk manager.
+///
+/// The `market_meta.close` price is rounded to the nearest tick & the quantity is rounded towards
+/// zero to the step size. Orders below the minimum quantity or notional are rejected. Orders for
+/// markets without an `InstrumentSpec` are passed through unchanged, as are exit orders that fully
+/// close the open [`Position`], so the entire quantity is exited. Partial exits (eg/ rebalancing
+/// towards a [`TargetPosition`](crate::strategy::TargetPosition)) are normalised like entries.
+#[derive(Clone, PartialEq, Debug)]
+pub struct OrderNormaliser<RiskManager> {
+    pub specs: InstrumentSpecs,
+    pub risk: RiskManager,
+}
+
+impl<RiskManager> OrderEvaluator for OrderNormaliser<RiskManager>
+where
+    RiskManager: OrderEvaluator,
+{
+    const DEFAULT_ORDER_TYPE: OrderType = RiskManager::DEFAULT_ORDER_TYPE;
+
+    fn evaluate_order(&self, order: OrderEvent) -> Option<OrderEvent> {
+        self.normalise(order, None)
+            .ok()
+            .and_then(|order| self.risk.evaluate_order(order))
+    }
+
+    fn evaluate_order_with_snapshot<Statistic>(
+        &self,
+        order: OrderEvent,
+        portfolio: &PortfolioSnapshot<Statistic>,
+    ) -> Result<OrderEvent, Box<OrderRejected>> {
+        let position = portfolio.open_position(&order.exchange, &order.instrument);
+        self.normalise(order, position)
+            .and_then(|order| self.risk.evaluate_order_with_snapshot(order, portfolio))
+    }
+}
+
+impl<RiskManager> OrderNormaliser<RiskManager> {
+    /// Rounds the [`OrderEvent`] price & quantity to the
+    /// [`InstrumentSpec`](barter_integration::model::instrument::spec::InstrumentSpec) of it's
+    /// market, rejecting it if it is below the minimum quantity or notional.
+    ///
+    /// Exit orders that fully close the provided open [`Position`] are returned unchanged. If no
+    /// [`Position`] is provided, every exit order is assumed to be a full close.
+    pub fn normalise(
+        &self,
+        mut order: OrderEvent,
+        position: Option<&Position>,
+    ) -> Result<OrderEvent, Box<OrderRejected>> {
+        let full_close = order.decision.is_exit()
+            && position.is_none_or(|position| order.quantity.abs() >= position.quantity.abs());
+        if full_close {
+            return Ok(order);
+        }
+
+        let Some(spec) = self.specs.get(&order.exchange, &order.instrument) else {
+            return Ok(order);
+        };
+
+        order.market_meta.close = spec.round_price(order.market_meta.close);
+        order.quantity = spec.round_quantity(order.quantity);
+
+        let quantity = order.quantity.abs();
+        let violation = if quantity == 0.0 || quantity < spec.min_quantity {
+            Some(RiskViolation::MinOrderQuantity {
+                quantity,
+                limit: spec.min_quantity.max(spec.step_size),
+            })
+        } else if quantity * order.market_meta.close < spec.min_notional {
+            Some(RiskViolation::MinOrderNotional {
+                notional: quantity * order.market_meta.close,
+                limit: spec.min_notional,
+            })
+        } else {
+            None
+        };
+
+        match violation {
+            Some(violation) => Err(Box::new(OrderRejected::new(order, vec![violation]))),
+            None => Ok(order),
+        }
+    }
+}
+
+/// Rejection of a proposed [`OrderEvent`] by an [`OrderEvaluator`], detailing every
+/// [`RiskViolation`] that caused it. Also used when no
+/// [`ExecutionClient`](crate::execution::ExecutionClient) can execute the [`OrderEvent`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderRejected {
+    pub time: DateTime<Utc>,
+    pub order: OrderEvent,
+    /// Empty if the [`OrderEvaluator`] did not provide specific reasons.
+    pub violations: Vec<RiskViolation>,
+}
+
+impl OrderRejected {
+    /// Construct a new [`OrderRejected`] for the provided [`OrderEvent`] & [`RiskViolation`]s.
+    pub fn new(order: OrderEvent, violations: Vec<RiskViolation>) -> Self {
+        Self {
+            time: order.time,
+            order,
+            violations,
+        }
+    }
+}
+
+/// Reason an [`OrderEvaluator`] rejected a proposed [`OrderEvent`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize, Error)]
+pub enum RiskViolation {
+    #[error("order price {price} is not a valid positive price")]
+    InvalidPrice { price: f64 },
+
+    #[error("order quantity {quantity} exceeds limit {limit}")]
+    MaxOrderQuantity { quantity: f64, limit: f64 },
+
+    #[error("order notional {notional} exceeds limit {limit}")]
+    MaxOrderNotional { notional: f64, limit: f64 },
+
+    #[error("position notional {notional} exceeds limit {limit}")]
+    MaxPositionNotional { notional: f64, limit: f64 },
+
+    #[error("gross exposure {exposure} exceeds limit {limit}")]
+    MaxGrossExposure { exposure: f64, limit: f64 },
+
+    #[error("net exposure {exposure} exceeds limit {limit}")]
+    MaxNetExposure { exposure: f64, limit: f64 },
+
+    #[error("{open} open positions already at limit {limit}")]
+    MaxOpenPositions { open: usize, limit: usize },
+
+    #[error("order price {price} deviates {deviation} from {reference}, exceeding limit {limit}")]
+    PriceBand {
+        price: f64,
+        reference: f64,
+        deviation: f64,
+        limit: f64,
+    },
+
+    #[error("daily loss {loss} reached limit {limit}")]
+    MaxDailyLoss { loss: f64, limit: f64 },
+
+    #[error("order quantity {quantity} is below minimum {limit}")]
+    MinOrderQuantity { quantity: f64, limit: f64 },
+
+    #[error("order notional {notional} is below minimum {limit}")]
+    MinOrderNotional { notional: f64, limit: f64 },
+
+    #[error("order quote currency cannot be converted into the Portfolio reporting currency")]
+    FxRateUnavailable,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::{currency::FxRates, position::Position, Balance},
+        strategy::Decision,
+        test_util::{order_event, position},
+    };
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, spec::InstrumentSpec, Instrument},
+        Exchange, MarketId, Side,
+    };
+    use std::collections::HashMap;
+
+    fn snapshot(open_positions: Vec<Position>, day_start_equity: f64) -> PortfolioSnapshot<()> {
+        PortfolioSnapshot {
+            time: Utc::now(),
+            balance: Balance::default(),
+            open_positions,
+            day_start_equity,
+            prices: HashMap::new(),
+            statistics: HashMap::new(),
+            fx_rates: None,
+        }
+    }
+
+    fn order(decision: Decision, quantity: f64, close: f64) -> OrderEvent {
+        let mut order = order_event();
+        order.decision = decision;
+        order.quantity = quantity;
+        order.market_meta.close = close;
+        order
+    }
+
+    fn eth_btc_order(quantity: f64, close: f64) -> OrderEvent {
+        let mut order = order(Decision::Long, quantity, close);
+        order.instrument = ("eth", "btc", InstrumentKind::Spot).into();
+        order
+    }
+
+    fn fx_rates(btc_usdt: Option<f64>) -> FxRates {
+        let mut fx_rates = FxRates::new("usdt");
+        if let Some(price) = btc_usdt {
+            fx_rates.set_price(&"btc".into(), &"usdt".into(), price);
+        }
+        fx_rates
+    }
+
+    fn open_position(base: &str, side: Side, current_value_gross: f64) -> Position {
+        let mut position = position();
+        position.instrument = (base, "usdt", InstrumentKind::Spot).into();
+        position.side = side;
+        position.current_value_gross = current_value_gross;
+        position
+    }
+
+    #[test]
+    fn test_rule_based_risk_violations() {
+        struct TestCase {
+            risk: RuleBasedRisk,
+            order: OrderEvent,
+            portfolio: Option<PortfolioSnapshot<()>>,
+            expected: Vec<RiskViolation>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no rules configured
+                risk: RuleBasedRisk::default(),
+                order: order(Decision::Long, 1000.0, 100.0),
+                portfolio: Some(snapshot(vec![], 0.0)),
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: invalid price
+                risk: RuleBasedRisk::default(),
+                order: order(Decision::Long, 1.0, 0.0),
+                portfolio: None,
+                expected: vec![RiskViolation::InvalidPrice { price: 0.0 }],
+            },
+            TestCase {
+                // TC2: order size rules evaluated without a PortfolioSnapshot
+                risk: RuleBasedRisk {
+                    max_order_quantity: Some(1.0),
+                    max_order_notional: Some(150.0),
+                    max_open_positions: Some(0),
+                    ..Default::default()
+                },
+                order: order(Decision::Short, -2.0, 100.0),
+                portfolio: None,
+                expected: vec![
+                    RiskViolation::MaxOrderQuantity {
+                        quantity: 2.0,
+                        limit: 1.0,
+                    },
+                    RiskViolation::MaxOrderNotional {
+                        notional: 200.0,
+                        limit: 150.0,
+                    },
+                ],
+            },
+            TestCase {
+                // TC3: exit orders are never rejected
+                risk: RuleBasedRisk {
+                    max_order_quantity: Some(1.0),
+                    max_daily_loss: Some(0.0),
+                    ..Default::default()
+                },
+                order: order(Decision::CloseLong, -2.0, 0.0),
+                portfolio: Some(snapshot(vec![], 100.0)),
+                expected: vec![],
+            },
+            TestCase {
+                // TC4: exposure & open position rules including the order
+                risk: RuleBasedRisk {
+                    max_gross_exposure: Some(250.0),
+                    max_net_exposure: Some(250.0),
+                    max_open_positions: Some(2),
+                    ..Default::default()
+                },
+                order: order(Decision::Long, 1.0, 100.0),
+                portfolio: Some(snapshot(
+                    vec![
+                        open_position("btc", Side::Buy, 100.0),
+                        open_position("sol", Side::Buy, 100.0),
+                    ],
+                    0.0,
+                )),
+                expected: vec![
+                    RiskViolation::MaxGrossExposure {
+                        exposure: 300.0,
+                        limit: 250.0,
+                    },
+                    RiskViolation::MaxNetExposure {
+                        exposure: 300.0,
+                        limit: 250.0,
+                    },
+                    RiskViolation::MaxOpenPositions { open: 2, limit: 2 },
+                ],
+            },
+            TestCase {
+                // TC5: short order reduces net exposure
+                risk: RuleBasedRisk {
+                    max_net_exposure: Some(50.0),
+                    ..Default::default()
+                },
+                order: order(Decision::Short, -1.0, 100.0),
+                portfolio: Some(snapshot(vec![open_position("btc", Side::Buy, 100.0)], 0.0)),
+                expected: vec![],
+            },
+            TestCase {
+                // TC6: position notional relative to open position in instrument
+                risk: RuleBasedRisk {
+                    max_position_notional: Some(200.0),
+                    ..Default::default()
+                },
+                order: order(Decision::Long, 1.0, 120.0),
+                portfolio: Some(snapshot(vec![open_position("eth", Side::Buy, 100.0)], 0.0)),
+                expected: vec![RiskViolation::MaxPositionNotional {
+                    notional: 220.0,
+                    limit: 200.0,
+                }],
+            },
+            TestCase {
+                // TC7: entry price band relative to the latest market price
+                risk: RuleBasedRisk {
+                    max_price_deviation: Some(0.1),
+                    ..Default::default()
+                },
+                order: order(Decision::Long, 1.0, 120.0),
+                portfolio: Some({
+                    let mut snapshot = snapshot(vec![], 0.0);
+                    snapshot.prices.insert(
+                        MarketId::new(
+                            &Exchange::from("binance"),
+                            &Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+                        ),
+                        100.0,
+                    );
+                    snapshot
+                }),
+                expected: vec![RiskViolation::PriceBand {
+                    price: 120.0,
+                    reference: 100.0,
+                    deviation: 0.2,
+                    limit: 0.1,
+                }],
+            },
+            TestCase {
+                // TC8: price band disabled until a market price is recorded
+                risk: RuleBasedRisk {
+                    max_price_deviation: Some(0.1),
+                    ..Default::default()
+                },
+                order: order(Decision::Long, 1.0, 120.0),
+                portfolio: Some(snapshot(vec![], 0.0)),
+                expected: vec![],
+            },
+            TestCase {
+                // TC9: daily loss is the change in equity (including unrealised P&L) since the
+                // start of the day
+                risk: RuleBasedRisk {
+                    max_daily_loss: Some(50.0),
+                    ..Default::default()
+                },
+                order: order(Decision::Long, 1.0, 100.0),
+                portfolio: Some(snapshot(
+                    vec![{
+                        let mut position = open_position("btc", Side::Buy, 100.0);
+                        position.unrealised_profit_loss = -20.0;
+                        position
+                    }],
+                    30.0,
+                )),
+                expected: vec![RiskViolation::MaxDailyLoss {
+                    loss: 50.0,
+                    limit: 50.0,
+                }],
+            },
+            TestCase {
+                // TC10: eth/btc order notional of 0.05 btc is valued at 1000 usdt before being
+                // compared against limits & the exposure of the usdt valued Portfolio
+                risk: RuleBasedRisk {
+                    max_order_notional: Some(900.0),
+                    max_position_notional: Some(900.0),
+                    max_gross_exposure: Some(2500.0),
+                    max_net_exposure: Some(2500.0),
+                    ..Default::default()
+                },
+                order: eth_btc_order(1.0, 0.05),
+                portfolio: Some(PortfolioSnapshot {
+                    fx_rates: Some(fx_rates(Some(20000.0))),
+                    ..snapshot(vec![open_position("btc", Side::Buy, 2000.0)], 0.0)
+                }),
+                expected: vec![
+                    RiskViolation::MaxOrderNotional {
+                        notional: 1000.0,
+                        limit: 900.0,
+                    },
+                    RiskViolation::MaxPositionNotional {
+                        notional: 1000.0,
+                        limit: 900.0,
+                    },
+                    RiskViolation::MaxGrossExposure {
+                        exposure: 3000.0,
+                        limit: 2500.0,
+                    },
+                    RiskViolation::MaxNetExposure {
+                        exposure: 3000.0,
+                        limit: 2500.0,
+                    },
+                ],
+            },
+            TestCase {
+                // TC11: eth/btc order cannot be valued without a btc conversion rate
+                risk: RuleBasedRisk::default(),
+                order: eth_btc_order(1.0, 0.05),
+                portfolio: Some(PortfolioSnapshot {
+                    fx_rates: Some(fx_rates(None)),
+                    ..snapshot(vec![], 0.0)
+                }),
+                expected: vec![RiskViolation::FxRateUnavailable],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.risk.violations(&test.order, test.portfolio.as_ref());
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_rule_based_risk_evaluate_order_with_snapshot() {
+        let risk = RuleBasedRisk {
+            max_open_positions: Some(1),
+            ..Default::default()
+        };
+        let mut input = order(Decision::Long, 1.0, 100.0);
+        input.order_type = OrderType::Limit;
+
+        let accepted = risk
+            .evaluate_order_with_snapshot(input.clone(), &snapshot(vec![], 0.0))
+            .unwrap();
+        assert_eq!(accepted.order_type, RuleBasedRisk::DEFAULT_ORDER_TYPE);
+
+        let rejected = risk
+            .evaluate_order_with_snapshot(
+                input.clone(),
+                &snapshot(vec![open_position("btc", Side::Buy, 100.0)], 0.0),
+            )
+            .unwrap_err();
+        assert_eq!(
+            *rejected,
+            OrderRejected {
+                time: input.time,
+                order: input,
+                violations: vec![RiskViolation::MaxOpenPositions { open: 1, limit: 1 }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_order_normaliser() {
+        let mut specs = InstrumentSpecs::default();
+        specs.insert(
+            &Exchange::from("binance"),
+            &Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+            InstrumentSpec {
+                tick_size: 0.01,
+                step_size: 0.001,
+                min_quantity: 0.002,
+                min_notional: 5.0,
+            },
+        );
+        let normaliser = OrderNormaliser {
+            specs,
+            risk: DefaultRisk {},
+        };
+
+        struct TestCase {
+            input: OrderEvent,
+            expected: Result<(f64, f64), RiskViolation>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: entry price & quantity rounded
+                input: order(Decision::Long, 0.123456, 1000.0049),
+                expected: Ok((0.123, 1000.0)),
+            },
+            TestCase {
+                // TC1: short entry quantity rounded towards zero
+                input: order(Decision::Short, -0.123456, 1000.0),
+                expected: Ok((-0.123, 1000.0)),
+            },
+            TestCase {
+                // TC2: entry below minimum quantity
+                input: order(Decision::Long, 0.0019, 1000.0),
+                expected: Err(RiskViolation::MinOrderQuantity {
+                    quantity: 0.001,
+                    limit: 0.002,
+                }),
+            },
+            TestCase {
+                // TC3: entry below minimum notional
+                input: order(Decision::Long, 0.004, 1000.0),
+                expected: Err(RiskViolation::MinOrderNotional {
+                    notional: 4.0,
+                    limit: 5.0,
+                }),
+            },
+            TestCase {
+                // TC4: full close of the open Position is not normalised
+                input: order(Decision::CloseLong, -1.0001234, 1000.0049),
+                expected: Ok((-1.0001234, 1000.0049)),
+            },
+            TestCase {
+                // TC5: markets without an InstrumentSpec are passed through unchanged
+                input: {
+                    let mut order = order(Decision::Long, 0.123456, 1000.0049);
+                    order.instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+                    order
+                },
+                expected: Ok((0.123456, 1000.0049)),
+            },
+            TestCase {
+                // TC6: partial exit price & quantity rounded
+                input: order(Decision::CloseLong, -0.123456, 1000.0049),
+                expected: Ok((-0.123, 1000.0)),
+            },
+            TestCase {
+                // TC7: partial exit below minimum quantity
+                input: order(Decision::CloseLong, -0.0019, 1000.0),
+                expected: Err(RiskViolation::MinOrderQuantity {
+                    quantity: 0.001,
+                    limit: 0.002,
+                }),
+            },
+        ];
+
+        // Open long Position with a quantity that is not a multiple of the step size
+        let mut position = position();
+        position.quantity = 1.0001234;
+        let snapshot = snapshot(vec![position], 0.0);
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = normaliser
+                .evaluate_order_with_snapshot(test.input, &snapshot)
+                .map(|order| (order.quantity, order.market_meta.close))
+                .map_err(|rejected| rejected.violations[0]);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -21,6 +21,9 @@ pub struct PnLReturnSummary {
     pub trades_per_day: f64,
     pub total: DataSummary,
     pub losses: DataSummary,
+    /// Cumulative funding paid (-ve) or received (+ve), included in the PnL Returns.
+    #[serde(default)]
+    pub total_funding: f64,
 }
 
 impl Initialiser for PnLReturnSummary {
@@ -39,6 +42,7 @@ impl Default for PnLReturnSummary {
             trades_per_day: 0.0,
             total: DataSummary::default(),
             losses: DataSummary::default(),
+            total_funding: 0.0,
         }
     }
 }
@@ -64,6 +68,15 @@ impl PositionSummariser for PnLReturnSummary {
         if pnl_return.is_sign_negative() {
             self.losses.update(pnl_return);
         }
+
+        // Update cumulative funding
+        self.total_funding += position.funding_total;
+    }
+}
+
+impl AsRef<PnLReturnSummary> for PnLReturnSummary {
+    fn as_ref(&self) -> &PnLReturnSummary {
+        self
     }
 }
 
@@ -80,6 +93,7 @@ impl TableBuilder for PnLReturnSummary {
             "Loss Mean Return",
             "Biggest Win",
             "Biggest Loss",
+            "Total Funding",
         ]
     }
 
@@ -96,6 +110,7 @@ impl TableBuilder for PnLReturnSummary {
             format!("{:.3}", self.losses.mean),
             format!("{:.3}", self.total.dispersion.range.high),
             format!("{:.3}", self.total.dispersion.range.low),
+            format!("{:.3}", self.total_funding),
         ]
     }
 }
@@ -110,6 +125,7 @@ impl PnLReturnSummary {
             trades_per_day: 0.0,
             total: Default::default(),
             losses: Default::default(),
+            total_funding: 0.0,
         }
     }
 
@@ -140,6 +156,9 @@ pub struct ProfitLossSummary {
     pub total_contracts: f64,
     pub total_pnl: f64,
     pub total_pnl_per_contract: f64,
+    /// Cumulative funding paid (-ve) or received (+ve), included in the PnL.
+    #[serde(default)]
+    pub total_funding: f64,
 }
 
 impl PositionSummariser for ProfitLossSummary {
@@ -147,6 +166,7 @@ impl PositionSummariser for ProfitLossSummary {
         self.total_contracts += position.quantity.abs();
         self.total_pnl += position.realised_profit_loss;
         self.total_pnl_per_contract = self.total_pnl / self.total_contracts;
+        self.total_funding += position.funding_total;
 
         match position.side {
             Side::Buy => {
@@ -175,6 +195,7 @@ impl TableBuilder for ProfitLossSummary {
             "Total Contracts",
             "Total PnL",
             "Total PnL Per Contract",
+            "Total Funding",
         ]
     }
 
@@ -189,6 +210,7 @@ impl TableBuilder for ProfitLossSummary {
             format!("{:.3}", self.total_contracts),
             format!("{:.3}", self.total_pnl),
             format!("{:.3}", self.total_pnl_per_contract),
+            format!("{:.3}", self.total_funding),
         ]
     }
 }
@@ -47,6 +47,12 @@ impl PositionSummariser for TradingSummary {
     }
 }
 
+impl AsRef<PnLReturnSummary> for TradingSummary {
+    fn as_ref(&self) -> &PnLReturnSummary {
+        &self.pnl_returns
+    }
+}
+
 impl TableBuilder for TradingSummary {
     fn titles(&self) -> Row {
         let mut titles = Vec::<Cell>::new();
@@ -0,0 +1,512 @@
+use super::{Decision, MultiMarketSignalGenerator, Signal, SignalStrength, TargetPosition};
+use crate::{
+    data::MarketMeta,
+    portfolio::{
+        position::determine_position_id,
+        repository::{error::RepositoryError, PositionHandler},
+    },
+};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    exchange::ExchangeId,
+    subscription::book::OrderBookL1,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use chrono::Utc;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc};
+use tracing::warn;
+use uuid::Uuid;
+
+/// Configuration for constructing a [`CrossExchangeArbitrage`] via the new() constructor method.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Taker fee of each exchange traded in decimal form (eg/ 0.001 for 0.1%). [`OrderBookL1`]s
+    /// from exchanges without a configured fee are ignored.
+    pub taker_fees: HashMap<ExchangeId, f64>,
+    /// Minimum net-of-fee spread in decimal form (eg/ 0.002 for 0.2%) between buying on one
+    /// exchange & selling on another required to enter an arbitrage.
+    pub entry_spread: f64,
+    /// Net-of-fee spread of unwinding an open arbitrage at or above which it is exited. Typically
+    /// slightly negative, since the round-trip fees have been paid by the time spreads converge.
+    pub exit_spread: f64,
+    /// Quantity of the [`Instrument`] entered on each leg, capped by the best ask & best bid
+    /// amounts available to the legs.
+    pub quantity: f64,
+}
+
+/// Exchanges an open [`CrossExchangeArbitrage`] is long & short the same [`Instrument`] on.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct ArbitrageLegs {
+    pub buy: Exchange,
+    pub sell: Exchange,
+}
+
+/// Reference cross-exchange arbitrage strategy that implements [`MultiMarketSignalGenerator`].
+///
+/// Consumes [`OrderBookL1`]s of the same [`Instrument`] from many exchanges. When the best bid
+/// on one exchange exceeds the best ask on another by more than the entry spread net of both
+/// taker fees, it emits simultaneous opposing [`Signal`]s: long on the cheap exchange & short on
+/// the expensive one. Both legs are exited together once the spread converges.
+///
+/// Both legs target the same [`TargetPosition::Quantity`], so an open arbitrage carries no net
+/// delta. Open arbitrages are derived from the Portfolio's open Positions, so a leg that is
+/// rejected (eg/ by the risk manager) is flattened rather than left unhedged. The short leg
+/// assumes inventory or margin is available on the expensive exchange.
+#[derive(Debug)]
+pub struct CrossExchangeArbitrage<Portfolio>
+where
+    Portfolio: PositionHandler,
+{
+    engine_id: Uuid,
+    portfolio: Arc<Mutex<Portfolio>>,
+    taker_fees: HashMap<Exchange, f64>,
+    entry_spread: f64,
+    exit_spread: f64,
+    quantity: f64,
+    books: HashMap<Instrument, HashMap<Exchange, OrderBookL1>>,
+}
+
+impl<Portfolio> MultiMarketSignalGenerator for CrossExchangeArbitrage<Portfolio>
+where
+    Portfolio: PositionHandler,
+{
+    fn generate_signals(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Vec<Signal> {
+        // Check if it's a MarketEvent with an OrderBookL1 from an exchange being traded
+        let DataKind::OrderBookL1(book_l1) = &market.kind else {
+            return vec![];
+        };
+        if !self.taker_fees.contains_key(&market.exchange) {
+            return vec![];
+        }
+
+        // Update the latest OrderBookL1 of the exchange
+        let books = self.books.entry(market.instrument.clone()).or_default();
+        books.insert(market.exchange.clone(), *book_l1);
+
+        // Determine the open legs of any arbitrage from the Portfolio's open Positions
+        let legs = match self.open_legs(&market.instrument) {
+            Ok(legs) => legs,
+            Err(error) => {
+                warn!(
+                    ?error,
+                    action = "generating no Signals",
+                    "failed to fetch open arbitrage Positions"
+                );
+                return vec![];
+            }
+        };
+
+        match legs.as_slice() {
+            [] => self.generate_entry_signals(market),
+            [(buy, Side::Buy), (sell, Side::Sell)] | [(sell, Side::Sell), (buy, Side::Buy)] => {
+                let legs = ArbitrageLegs {
+                    buy: buy.clone(),
+                    sell: sell.clone(),
+                };
+                self.generate_exit_signals(market, legs)
+            }
+            // Flatten any leg left unhedged (eg/ it's opposing leg was rejected), once it's
+            // exchange has an OrderBookL1 to price the exit
+            unhedged => unhedged
+                .iter()
+                .filter_map(|(exchange, side)| {
+                    let book = self.books[&market.instrument].get(exchange)?;
+                    let (decision, price) = match side {
+                        Side::Buy => (Decision::CloseLong, book.best_bid.price),
+                        Side::Sell => (Decision::CloseShort, book.best_ask.price),
+                    };
+                    Some(generate_signal(market, exchange, decision, price, 0.0))
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<Portfolio> CrossExchangeArbitrage<Portfolio>
+where
+    Portfolio: PositionHandler,
+{
+    /// Constructs a new [`CrossExchangeArbitrage`] component using the provided configuration
+    /// struct, deriving it's open arbitrages from the shared Portfolio's open Positions.
+    pub fn new(config: Config, engine_id: Uuid, portfolio: Arc<Mutex<Portfolio>>) -> Self {
+        Self {
+            engine_id,
+            portfolio,
+            taker_fees: config
+                .taker_fees
+                .into_iter()
+                .map(|(exchange, fee)| (Exchange::from(exchange), fee))
+                .collect(),
+            entry_spread: config.entry_spread,
+            exit_spread: config.exit_spread,
+            quantity: config.quantity,
+            books: HashMap::new(),
+        }
+    }
+
+    /// Returns the [`ArbitrageLegs`] of the open arbitrage in the provided [`Instrument`], if
+    /// both legs are open in the Portfolio.
+    pub fn open_arbitrage(
+        &self,
+        instrument: &Instrument,
+    ) -> Result<Option<ArbitrageLegs>, RepositoryError> {
+        let legs = self.open_legs(instrument)?;
+        let leg = |side| {
+            legs.iter()
+                .find(|(_, leg_side)| *leg_side == side)
+                .map(|(exchange, _)| exchange.clone())
+        };
+
+        Ok(match (legs.len(), leg(Side::Buy), leg(Side::Sell)) {
+            (2, Some(buy), Some(sell)) => Some(ArbitrageLegs { buy, sell }),
+            _ => None,
+        })
+    }
+
+    /// Determines the exchange & [`Side`] of every open Portfolio Position in the provided
+    /// [`Instrument`] on an exchange being traded.
+    fn open_legs(&self, instrument: &Instrument) -> Result<Vec<(Exchange, Side)>, RepositoryError> {
+        let mut portfolio = self.portfolio.lock();
+
+        let mut legs = Vec::new();
+        for exchange in self.taker_fees.keys() {
+            let position_id = determine_position_id(self.engine_id, exchange, instrument);
+            if let Some(position) = portfolio.get_open_position(&position_id)? {
+                legs.push((exchange.clone(), position.side));
+            }
+        }
+
+        Ok(legs)
+    }
+
+    /// Enters an arbitrage between the pair of exchanges with the widest net-of-fee spread, if
+    /// it exceeds the entry spread.
+    fn generate_entry_signals(&self, market: &MarketEvent<Instrument, DataKind>) -> Vec<Signal> {
+        let Some(books) = self.books.get(&market.instrument) else {
+            return vec![];
+        };
+
+        let best = books
+            .iter()
+            .flat_map(|buy| books.iter().map(move |sell| (buy, sell)))
+            .filter(|((buy, _), (sell, _))| buy != sell)
+            .map(|((buy, buy_book), (sell, sell_book))| {
+                let spread =
+                    self.net_spread(buy, buy_book.best_ask.price, sell, sell_book.best_bid.price);
+                (spread, buy, buy_book, sell, sell_book)
+            })
+            .max_by(|(a, ..), (b, ..)| a.total_cmp(b));
+
+        let Some((spread, buy, buy_book, sell, sell_book)) = best else {
+            return vec![];
+        };
+        if spread < self.entry_spread {
+            return vec![];
+        }
+
+        // Both legs enter the same quantity, so the arbitrage carries no net delta
+        let quantity = self
+            .quantity
+            .min(buy_book.best_ask.amount)
+            .min(sell_book.best_bid.amount);
+        if quantity <= 0.0 {
+            return vec![];
+        }
+
+        vec![
+            generate_signal(
+                market,
+                buy,
+                Decision::Long,
+                buy_book.best_ask.price,
+                quantity,
+            ),
+            generate_signal(
+                market,
+                sell,
+                Decision::Short,
+                sell_book.best_bid.price,
+                -quantity,
+            ),
+        ]
+    }
+
+    /// Exits both legs of an open arbitrage once the net-of-fee spread of unwinding it (selling
+    /// on the buy exchange & buying back on the sell exchange) reaches the exit spread.
+    fn generate_exit_signals(
+        &self,
+        market: &MarketEvent<Instrument, DataKind>,
+        legs: ArbitrageLegs,
+    ) -> Vec<Signal> {
+        let books = &self.books[&market.instrument];
+        let (Some(buy_book), Some(sell_book)) = (books.get(&legs.buy), books.get(&legs.sell))
+        else {
+            return vec![];
+        };
+
+        let unwind_spread = self.net_spread(
+            &legs.sell,
+            sell_book.best_ask.price,
+            &legs.buy,
+            buy_book.best_bid.price,
+        );
+        if unwind_spread < self.exit_spread {
+            return vec![];
+        }
+
+        vec![
+            generate_signal(
+                market,
+                &legs.buy,
+                Decision::CloseLong,
+                buy_book.best_bid.price,
+                0.0,
+            ),
+            generate_signal(
+                market,
+                &legs.sell,
+                Decision::CloseShort,
+                sell_book.best_ask.price,
+                0.0,
+            ),
+        ]
+    }
+
+    /// Calculates the spread in decimal form of buying at the provided price on one exchange &
+    /// selling at the provided price on another, net of both taker fees.
+    fn net_spread(&self, buy: &Exchange, buy_price: f64, sell: &Exchange, sell_price: f64) -> f64 {
+        let cost = buy_price * (1.0 + self.taker_fees[buy]);
+        let proceeds = sell_price * (1.0 - self.taker_fees[sell]);
+        (proceeds - cost) / cost
+    }
+}
+
+/// Generates a [`Signal`] for one leg of an arbitrage targeting the provided signed quantity,
+/// using the price it is expected to execute at as the [`MarketMeta`] close.
+fn generate_signal(
+    market: &MarketEvent<Instrument, DataKind>,
+    exchange: &Exchange,
+    decision: Decision,
+    price: f64,
+    quantity: f64,
+) -> Signal {
+    Signal {
+        time: Utc::now(),
+        exchange: exchange.clone(),
+        instrument: market.instrument.clone(),
+        signals: HashMap::from([(decision, SignalStrength(1.0))]),
+        market_meta: MarketMeta {
+            close: price,
+            time: market.exchange_time,
+        },
+        exit_rules: None,
+        target: Some(TargetPosition::Quantity(quantity)),
+        attribution: vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::repository::in_memory::InMemoryRepository,
+        statistic::summary::trading::TradingSummary, test_util::position,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::DateTime;
+
+    fn market_event_book_l1(
+        time: DateTime<Utc>,
+        exchange: ExchangeId,
+        instrument: Instrument,
+        best_bid: (f64, f64),
+        best_ask: (f64, f64),
+    ) -> MarketEvent<Instrument, DataKind> {
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from(exchange),
+            instrument,
+            kind: DataKind::OrderBookL1(OrderBookL1 {
+                last_update_time: time,
+                best_bid: best_bid.into(),
+                best_ask: best_ask.into(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_cross_exchange_arbitrage_generate_signals() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = Arc::new(Mutex::new(InMemoryRepository::<TradingSummary>::new()));
+        let mut strategy = CrossExchangeArbitrage::new(
+            Config {
+                taker_fees: HashMap::from([
+                    (ExchangeId::BinanceSpot, 0.001),
+                    (ExchangeId::Kraken, 0.001),
+                ]),
+                entry_spread: 0.002,
+                exit_spread: -0.005,
+                quantity: 2.0,
+            },
+            engine_id,
+            Arc::clone(&portfolio),
+        );
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let book = |exchange, bid, ask| {
+            market_event_book_l1(
+                Utc::now(),
+                exchange,
+                instrument.clone(),
+                (bid, 1.0),
+                (ask, 1.0),
+            )
+        };
+        let decisions = |signals: Vec<Signal>| {
+            signals
+                .into_iter()
+                .map(|signal| {
+                    let decision = *signal.signals.keys().next().unwrap();
+                    (
+                        signal.exchange,
+                        decision,
+                        signal.market_meta.close,
+                        signal.target,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Replaces the Portfolio's open Positions with the provided arbitrage legs
+        let open_legs = |legs: &[(ExchangeId, Side)]| {
+            let mut portfolio = portfolio.lock();
+            for exchange in [ExchangeId::BinanceSpot, ExchangeId::Kraken] {
+                let position_id =
+                    determine_position_id(engine_id, &Exchange::from(exchange), &instrument);
+                portfolio.remove_position(&position_id).unwrap();
+            }
+            for (exchange, side) in legs {
+                let exchange = Exchange::from(*exchange);
+                let mut position = position();
+                position.position_id = determine_position_id(engine_id, &exchange, &instrument);
+                position.exchange = exchange;
+                position.instrument = instrument.clone();
+                position.side = *side;
+                portfolio.set_open_position(position).unwrap();
+            }
+        };
+
+        struct TestCase {
+            legs: Vec<(ExchangeId, Side)>,
+            input: MarketEvent<Instrument, DataKind>,
+            expected: Vec<(Exchange, Decision, f64, Option<TargetPosition>)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: single exchange book cannot be arbitraged
+                legs: vec![],
+                input: book(ExchangeId::BinanceSpot, 100.0, 100.1),
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: gross spread of 0.2% is consumed by fees
+                legs: vec![],
+                input: book(ExchangeId::Kraken, 100.3, 100.4),
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: books from an exchange without a configured fee are ignored
+                legs: vec![],
+                input: book(ExchangeId::Okx, 110.0, 110.1),
+                expected: vec![],
+            },
+            TestCase {
+                // TC3: net spread exceeds entry spread, so buy Binance & sell Kraken the same
+                // quantity, capped by the top of book amounts
+                legs: vec![],
+                input: book(ExchangeId::Kraken, 101.0, 101.1),
+                expected: vec![
+                    (
+                        Exchange::from("binance_spot"),
+                        Decision::Long,
+                        100.1,
+                        Some(TargetPosition::Quantity(1.0)),
+                    ),
+                    (
+                        Exchange::from("kraken"),
+                        Decision::Short,
+                        101.0,
+                        Some(TargetPosition::Quantity(-1.0)),
+                    ),
+                ],
+            },
+            TestCase {
+                // TC4: open arbitrage does not re-enter while the spread persists
+                legs: vec![
+                    (ExchangeId::BinanceSpot, Side::Buy),
+                    (ExchangeId::Kraken, Side::Sell),
+                ],
+                input: book(ExchangeId::Kraken, 101.5, 101.6),
+                expected: vec![],
+            },
+            TestCase {
+                // TC5: spread converges, so both legs are exited together
+                legs: vec![
+                    (ExchangeId::BinanceSpot, Side::Buy),
+                    (ExchangeId::Kraken, Side::Sell),
+                ],
+                input: book(ExchangeId::Kraken, 100.1, 100.2),
+                expected: vec![
+                    (
+                        Exchange::from("binance_spot"),
+                        Decision::CloseLong,
+                        100.0,
+                        Some(TargetPosition::Quantity(0.0)),
+                    ),
+                    (
+                        Exchange::from("kraken"),
+                        Decision::CloseShort,
+                        100.2,
+                        Some(TargetPosition::Quantity(0.0)),
+                    ),
+                ],
+            },
+            TestCase {
+                // TC6: leg left unhedged by a rejected opposing leg is flattened
+                legs: vec![(ExchangeId::BinanceSpot, Side::Buy)],
+                input: book(ExchangeId::Kraken, 101.5, 101.6),
+                expected: vec![(
+                    Exchange::from("binance_spot"),
+                    Decision::CloseLong,
+                    100.0,
+                    Some(TargetPosition::Quantity(0.0)),
+                )],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            open_legs(&test.legs);
+            let actual = decisions(strategy.generate_signals(&test.input));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+
+        // Open arbitrage requires both legs to be open in the Portfolio
+        assert_eq!(strategy.open_arbitrage(&instrument).unwrap(), None);
+
+        open_legs(&[
+            (ExchangeId::BinanceSpot, Side::Buy),
+            (ExchangeId::Kraken, Side::Sell),
+        ]);
+        assert_eq!(
+            strategy.open_arbitrage(&instrument).unwrap(),
+            Some(ArbitrageLegs {
+                buy: Exchange::from("binance_spot"),
+                sell: Exchange::from("kraken"),
+            })
+        );
+    }
+}
@@ -0,0 +1,751 @@
+use super::{Attribution, Decision, Signal, SignalGenerator, SignalStrength, TargetPosition};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+};
+
+/// Rule used by an [`Ensemble`] to combine the [`Signal`]s of it's voting members.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum CombineRule {
+    /// Each [`Decision`] is advised with the weighted average [`SignalStrength`] of every voting
+    /// member, where members not advising it contribute zero. Only [`Decision`]s with an average
+    /// [`SignalStrength`] of at least the threshold are emitted.
+    WeightedAverage { threshold: f64 },
+    /// Each [`Decision`] advised by voting members holding a majority of the total voting weight
+    /// is emitted, with the weighted average [`SignalStrength`] of the members advising it.
+    MajorityVote,
+    /// The [`Signal`] of the first voting member (in the order added) to generate one is emitted.
+    Priority,
+}
+
+/// Role of an [`Ensemble`] member.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum MemberRole {
+    /// [`Signal`]s are combined using the [`Ensemble`] [`CombineRule`].
+    Vote,
+    /// [`Signal`]s never originate a [`Decision`], but block opposing entries: advising
+    /// [`Decision::Short`] or [`Decision::CloseLong`] vetoes [`Decision::Long`], and advising
+    /// [`Decision::Long`] or [`Decision::CloseShort`] vetoes [`Decision::Short`]. Exits reduce
+    /// risk, so are never vetoed.
+    ///
+    /// A [`TargetPosition`] vetoes the opposite entry, or both entries if flat. A [`Signal`] with a
+    /// long or short [`TargetPosition`] that is vetoed is not emitted, since it may enter or
+    /// increase a Position.
+    Veto,
+}
+
+/// Configuration for constructing an [`Ensemble`] via the new() constructor method.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Config {
+    pub rule: CombineRule,
+}
+
+/// [`SignalGenerator`] combinator that feeds every [`MarketEvent`] to each of it's named member
+/// strategies, and merges the [`Signal`]s they generate using a [`CombineRule`].
+///
+/// Every emitted [`Signal`] includes the [`Attribution`] of each member that advised it's
+/// [`Decision`]s, so performance can be attributed to individual models.
+///
+/// Member [`TargetPosition`]s cannot be meaningfully averaged or voted on, so are only
+/// propagated by the [`CombineRule::Priority`] rule.
+pub struct Ensemble {
+    rule: CombineRule,
+    members: Vec<Member>,
+}
+
+/// Named member strategy of an [`Ensemble`].
+struct Member {
+    name: String,
+    weight: f64,
+    role: MemberRole,
+    strategy: Box<dyn SignalGenerator + Send>,
+}
+
+impl Debug for Ensemble {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Ensemble")
+            .field("rule", &self.rule)
+            .field(
+                "members",
+                &self
+                    .members
+                    .iter()
+                    .map(|member| (&member.name, member.weight, member.role))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl SignalGenerator for Ensemble {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        // Feed the MarketEvent to every member, so each keeps it's state up to date
+        let signals = self
+            .members
+            .iter_mut()
+            .map(|member| member.strategy.generate_signal(market))
+            .collect::<Vec<_>>();
+
+        let mut signal = match self.rule {
+            CombineRule::Priority => self.priority(&signals)?,
+            CombineRule::WeightedAverage { threshold } => self.combine(&signals, |votes| {
+                let strength = votes.strength / votes.total_weight;
+                (strength >= threshold).then_some(strength)
+            })?,
+            CombineRule::MajorityVote => self.combine(&signals, |votes| {
+                (votes.weight > votes.total_weight / 2.0).then_some(votes.strength / votes.weight)
+            })?,
+        };
+
+        // Remove any entry Decisions opposed by a veto member
+        let vetoed = self.vetoed(&signals);
+        for vetoed in &vetoed {
+            signal.signals.remove(vetoed);
+            signal
+                .attribution
+                .retain(|attribution| attribution.decision != *vetoed);
+        }
+
+        // Suppress the Signal if it's TargetPosition enters in a direction opposed by a veto member
+        if signal
+            .target
+            .and_then(target_entry)
+            .is_some_and(|entry| vetoed.contains(&entry))
+        {
+            return None;
+        }
+
+        if signal.signals.is_empty() && signal.target.is_none() {
+            return None;
+        }
+
+        Some(Signal {
+            time: Utc::now(),
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            ..signal
+        })
+    }
+}
+
+impl Ensemble {
+    /// Constructs a new [`Ensemble`] component without any members, using the provided
+    /// configuration struct.
+    pub fn new(config: Config) -> Self {
+        Self {
+            rule: config.rule,
+            members: vec![],
+        }
+    }
+
+    /// Adds a named voting member strategy with the provided weight.
+    pub fn member<Strategy>(self, name: &str, weight: f64, strategy: Strategy) -> Self
+    where
+        Strategy: SignalGenerator + Send + 'static,
+    {
+        self.with_member(name, weight, MemberRole::Vote, strategy)
+    }
+
+    /// Adds a named veto member strategy. See [`MemberRole::Veto`].
+    pub fn veto<Strategy>(self, name: &str, strategy: Strategy) -> Self
+    where
+        Strategy: SignalGenerator + Send + 'static,
+    {
+        self.with_member(name, 0.0, MemberRole::Veto, strategy)
+    }
+
+    fn with_member<Strategy>(
+        mut self,
+        name: &str,
+        weight: f64,
+        role: MemberRole,
+        strategy: Strategy,
+    ) -> Self
+    where
+        Strategy: SignalGenerator + Send + 'static,
+    {
+        self.members.push(Member {
+            name: name.to_owned(),
+            weight,
+            role,
+            strategy: Box::new(strategy),
+        });
+        self
+    }
+
+    /// Returns each voting member alongside the [`Signal`] it generated, if any.
+    fn votes<'a>(
+        &'a self,
+        signals: &'a [Option<Signal>],
+    ) -> impl Iterator<Item = (&'a Member, Option<&'a Signal>)> {
+        self.members
+            .iter()
+            .zip(signals)
+            .filter(|(member, _)| member.role == MemberRole::Vote)
+            .map(|(member, signal)| (member, signal.as_ref()))
+    }
+
+    /// Emits the [`Signal`] of the first voting member to generate one.
+    fn priority(&self, signals: &[Option<Signal>]) -> Option<Signal> {
+        let (member, signal) = self
+            .votes(signals)
+            .find_map(|(member, signal)| Some((member, signal?)))?;
+
+        Some(Signal {
+            attribution: attribute(member, signal)
+                .chain(attribute_target(member, signal))
+                .collect(),
+            ..signal.clone()
+        })
+    }
+
+    /// Combines the [`Signal`]s of every voting member, emitting each [`Decision`] for which
+    /// the provided rule determines a [`SignalStrength`] from it's [`Votes`].
+    fn combine<Rule>(&self, signals: &[Option<Signal>], rule: Rule) -> Option<Signal>
+    where
+        Rule: Fn(&Votes) -> Option<f64>,
+    {
+        let total_weight = self.votes(signals).map(|(member, _)| member.weight).sum();
+        if total_weight <= 0.0 {
+            return None;
+        }
+
+        // Tally the weight & weighted SignalStrength advising each Decision
+        let mut votes = HashMap::<Decision, Votes>::new();
+        let mut attribution = vec![];
+        let mut advised = vec![];
+
+        for (member, signal) in self.votes(signals) {
+            let Some(signal) = signal else {
+                continue;
+            };
+            advised.push(signal);
+
+            for (decision, strength) in &signal.signals {
+                let tally = votes.entry(*decision).or_insert(Votes {
+                    weight: 0.0,
+                    strength: 0.0,
+                    total_weight,
+                });
+                tally.weight += member.weight;
+                tally.strength += member.weight * strength.0;
+            }
+            attribution.extend(attribute(member, signal));
+        }
+
+        let signals = votes
+            .iter()
+            .filter_map(|(decision, votes)| Some((*decision, SignalStrength(rule(votes)?))))
+            .collect::<HashMap<_, _>>();
+
+        attribution.retain(|attribution: &Attribution| signals.contains_key(&attribution.decision));
+
+        // ExitRules apply to the Position entered, so are taken from a member advising an emitted
+        // entry Decision
+        let exit_rules = advised
+            .iter()
+            .find(|signal| {
+                signal
+                    .signals
+                    .keys()
+                    .any(|decision| decision.is_entry() && signals.contains_key(decision))
+            })
+            .and_then(|signal| signal.exit_rules);
+
+        let first = advised.first()?;
+        Some(Signal {
+            time: first.time,
+            exchange: first.exchange.clone(),
+            instrument: first.instrument.clone(),
+            signals,
+            market_meta: first.market_meta,
+            exit_rules,
+            target: None,
+            attribution,
+        })
+    }
+
+    /// Determines the entry [`Decision`]s opposed by any veto member.
+    fn vetoed(&self, signals: &[Option<Signal>]) -> Vec<Decision> {
+        let mut vetoed = self
+            .members
+            .iter()
+            .zip(signals)
+            .filter(|(member, _)| member.role == MemberRole::Veto)
+            .filter_map(|(_, signal)| signal.as_ref())
+            .flat_map(|signal| {
+                let decisions = signal.signals.keys().map(|decision| match decision {
+                    Decision::Short | Decision::CloseLong => Decision::Long,
+                    Decision::Long | Decision::CloseShort => Decision::Short,
+                });
+
+                let targets = signal.target.map(|target| match target_entry(target) {
+                    Some(Decision::Long) => vec![Decision::Short],
+                    Some(_) => vec![Decision::Long],
+                    None => vec![Decision::Long, Decision::Short],
+                });
+
+                decisions.chain(targets.into_iter().flatten())
+            })
+            .collect::<Vec<_>>();
+
+        vetoed.sort();
+        vetoed.dedup();
+        vetoed
+    }
+}
+
+/// Weight & weighted [`SignalStrength`] of the voting members advising a [`Decision`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Votes {
+    /// Sum of the weights of the members advising the [`Decision`].
+    weight: f64,
+    /// Sum of the weighted [`SignalStrength`]s of the members advising the [`Decision`].
+    strength: f64,
+    /// Sum of the weights of every voting member.
+    total_weight: f64,
+}
+
+/// Entry [`Decision`] in the direction of the provided [`TargetPosition`], or `None` if flat.
+fn target_entry(target: TargetPosition) -> Option<Decision> {
+    let (TargetPosition::Quantity(value) | TargetPosition::Weight(value)) = target;
+    if value > 0.0 {
+        Some(Decision::Long)
+    } else if value < 0.0 {
+        Some(Decision::Short)
+    } else {
+        None
+    }
+}
+
+/// [`Attribution`] of every [`Decision`] advised by the provided member's [`Signal`].
+fn attribute<'a>(member: &'a Member, signal: &'a Signal) -> impl Iterator<Item = Attribution> + 'a {
+    signal
+        .signals
+        .iter()
+        .map(|(decision, strength)| Attribution {
+            model: member.name.clone(),
+            decision: *decision,
+            strength: *strength,
+            weight: member.weight,
+        })
+}
+
+/// [`Attribution`] of the [`Decision`]s the provided member's [`TargetPosition`] (if any) may
+/// action: the entry in it's direction, or both exits if flat. [`Decision`]s already advised by
+/// the member's [`Signal`] are not attributed twice.
+fn attribute_target<'a>(
+    member: &'a Member,
+    signal: &'a Signal,
+) -> impl Iterator<Item = Attribution> + 'a {
+    signal
+        .target
+        .map(|target| match target_entry(target) {
+            Some(entry) => vec![entry],
+            None => vec![Decision::CloseLong, Decision::CloseShort],
+        })
+        .into_iter()
+        .flatten()
+        .filter(|decision| !signal.signals.contains_key(decision))
+        .map(|decision| Attribution {
+            model: member.name.clone(),
+            decision,
+            strength: SignalStrength(1.0),
+            weight: member.weight,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::exit::ExitRules,
+        test_util::{market_event_trade, signal},
+    };
+    use barter_integration::model::Side;
+    use std::collections::VecDeque;
+
+    /// Strategy that generates a pre-determined sequence of [`Signal`]s.
+    struct Scripted(VecDeque<Option<Vec<(Decision, f64)>>>);
+
+    impl SignalGenerator for Scripted {
+        fn generate_signal(&mut self, _: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+            let decisions = self.0.pop_front()??;
+            Some(Signal {
+                signals: decisions
+                    .into_iter()
+                    .map(|(decision, strength)| (decision, SignalStrength(strength)))
+                    .collect(),
+                ..signal()
+            })
+        }
+    }
+
+    fn scripted(signals: Vec<Option<Vec<(Decision, f64)>>>) -> Scripted {
+        Scripted(signals.into())
+    }
+
+    /// Strategy that generates a pre-determined sequence of [`TargetPosition`] [`Signal`]s.
+    struct Targeted(VecDeque<Option<TargetPosition>>);
+
+    impl SignalGenerator for Targeted {
+        fn generate_signal(&mut self, _: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+            Some(Signal {
+                target: Some(self.0.pop_front()??),
+                ..signal()
+            })
+        }
+    }
+
+    fn targeted(targets: Vec<Option<TargetPosition>>) -> Targeted {
+        Targeted(targets.into())
+    }
+
+    fn decisions(signal: Option<Signal>) -> Option<Vec<(Decision, f64)>> {
+        signal.map(|signal| {
+            let mut decisions = signal
+                .signals
+                .into_iter()
+                .map(|(decision, strength)| (decision, strength.0))
+                .collect::<Vec<_>>();
+            decisions.sort_by_key(|(decision, _)| *decision);
+            decisions
+        })
+    }
+
+    #[test]
+    fn test_ensemble_generate_signal() {
+        let market = market_event_trade(Side::Buy);
+
+        struct TestCase {
+            ensemble: Ensemble,
+            expected: Vec<Option<Vec<(Decision, f64)>>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: WeightedAverage emits Decisions with an average strength above threshold
+                ensemble: Ensemble::new(Config {
+                    rule: CombineRule::WeightedAverage { threshold: 0.5 },
+                })
+                .member(
+                    "a",
+                    3.0,
+                    scripted(vec![Some(vec![(Decision::Long, 1.0)]), None]),
+                )
+                .member(
+                    "b",
+                    1.0,
+                    scripted(vec![
+                        Some(vec![(Decision::Short, 1.0)]),
+                        Some(vec![(Decision::Long, 1.0)]),
+                    ]),
+                ),
+                expected: vec![Some(vec![(Decision::Long, 0.75)]), None],
+            },
+            TestCase {
+                // TC1: MajorityVote emits Decisions advised by a majority of the voting weight
+                ensemble: Ensemble::new(Config {
+                    rule: CombineRule::MajorityVote,
+                })
+                .member(
+                    "a",
+                    1.0,
+                    scripted(vec![Some(vec![(Decision::Long, 1.0)]), None]),
+                )
+                .member(
+                    "b",
+                    1.0,
+                    scripted(vec![
+                        Some(vec![(Decision::Long, 0.5)]),
+                        Some(vec![(Decision::Long, 1.0)]),
+                    ]),
+                )
+                .member("c", 1.0, scripted(vec![None, None])),
+                expected: vec![Some(vec![(Decision::Long, 0.75)]), None],
+            },
+            TestCase {
+                // TC2: Priority emits the Signal of the first voting member to generate one
+                ensemble: Ensemble::new(Config {
+                    rule: CombineRule::Priority,
+                })
+                .member(
+                    "a",
+                    1.0,
+                    scripted(vec![None, Some(vec![(Decision::Short, 0.5)])]),
+                )
+                .member(
+                    "b",
+                    1.0,
+                    scripted(vec![
+                        Some(vec![(Decision::Long, 1.0)]),
+                        Some(vec![(Decision::Long, 1.0)]),
+                    ]),
+                ),
+                expected: vec![
+                    Some(vec![(Decision::Long, 1.0)]),
+                    Some(vec![(Decision::Short, 0.5)]),
+                ],
+            },
+            TestCase {
+                // TC3: Veto member blocks opposing entries, but never exits
+                ensemble: Ensemble::new(Config {
+                    rule: CombineRule::Priority,
+                })
+                .member(
+                    "a",
+                    1.0,
+                    scripted(vec![
+                        Some(vec![(Decision::Long, 1.0)]),
+                        Some(vec![(Decision::Long, 1.0), (Decision::CloseShort, 1.0)]),
+                        Some(vec![(Decision::Long, 1.0)]),
+                    ]),
+                )
+                .veto(
+                    "risk",
+                    scripted(vec![
+                        Some(vec![(Decision::CloseLong, 1.0)]),
+                        Some(vec![(Decision::Short, 1.0)]),
+                        Some(vec![(Decision::Long, 1.0)]),
+                    ]),
+                ),
+                expected: vec![
+                    None,
+                    Some(vec![(Decision::CloseShort, 1.0)]),
+                    Some(vec![(Decision::Long, 1.0)]),
+                ],
+            },
+        ];
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            for (event, expected) in test.expected.into_iter().enumerate() {
+                let actual = decisions(test.ensemble.generate_signal(&market));
+                assert_eq!(actual, expected, "TC{} event {} failed", index, event);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ensemble_generate_target_signal() {
+        let market = market_event_trade(Side::Buy);
+
+        struct TestCase {
+            ensemble: Ensemble,
+            expected: Vec<Option<TargetPosition>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Priority propagates the TargetPosition of the first member to generate one
+                ensemble: Ensemble::new(Config {
+                    rule: CombineRule::Priority,
+                })
+                .member(
+                    "a",
+                    1.0,
+                    targeted(vec![None, Some(TargetPosition::Weight(-0.5))]),
+                )
+                .member(
+                    "b",
+                    1.0,
+                    targeted(vec![
+                        Some(TargetPosition::Quantity(2.0)),
+                        Some(TargetPosition::Quantity(2.0)),
+                    ]),
+                ),
+                expected: vec![
+                    Some(TargetPosition::Quantity(2.0)),
+                    Some(TargetPosition::Weight(-0.5)),
+                ],
+            },
+            TestCase {
+                // TC1: Veto member blocks TargetPositions entering in the opposing direction
+                ensemble: Ensemble::new(Config {
+                    rule: CombineRule::Priority,
+                })
+                .member(
+                    "a",
+                    1.0,
+                    targeted(vec![
+                        Some(TargetPosition::Quantity(2.0)),
+                        Some(TargetPosition::Quantity(0.0)),
+                        Some(TargetPosition::Quantity(-2.0)),
+                        Some(TargetPosition::Quantity(2.0)),
+                    ]),
+                )
+                .veto(
+                    "risk",
+                    scripted(vec![
+                        Some(vec![(Decision::CloseLong, 1.0)]),
+                        Some(vec![(Decision::Short, 1.0)]),
+                        Some(vec![(Decision::Short, 1.0)]),
+                        None,
+                    ]),
+                ),
+                expected: vec![
+                    None,
+                    Some(TargetPosition::Quantity(0.0)),
+                    Some(TargetPosition::Quantity(-2.0)),
+                    Some(TargetPosition::Quantity(2.0)),
+                ],
+            },
+            TestCase {
+                // TC2: Veto member TargetPosition blocks opposing entries, or both if flat
+                ensemble: Ensemble::new(Config {
+                    rule: CombineRule::Priority,
+                })
+                .member(
+                    "a",
+                    1.0,
+                    targeted(vec![
+                        Some(TargetPosition::Weight(0.5)),
+                        Some(TargetPosition::Weight(0.5)),
+                        Some(TargetPosition::Weight(-0.5)),
+                    ]),
+                )
+                .veto(
+                    "risk",
+                    targeted(vec![
+                        Some(TargetPosition::Weight(-0.1)),
+                        Some(TargetPosition::Weight(0.1)),
+                        Some(TargetPosition::Weight(0.0)),
+                    ]),
+                ),
+                expected: vec![None, Some(TargetPosition::Weight(0.5)), None],
+            },
+        ];
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            for (event, expected) in test.expected.into_iter().enumerate() {
+                let actual = test
+                    .ensemble
+                    .generate_signal(&market)
+                    .and_then(|signal| signal.target);
+                assert_eq!(actual, expected, "TC{} event {} failed", index, event);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ensemble_combine_exit_rules() {
+        let exit_rules = |stop_loss| ExitRules {
+            stop_loss: Some(stop_loss),
+            ..Default::default()
+        };
+
+        /// Strategy advising a single [`Decision`] with the provided [`ExitRules`].
+        struct WithExitRules(Decision, ExitRules);
+
+        impl SignalGenerator for WithExitRules {
+            fn generate_signal(&mut self, _: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+                Some(Signal {
+                    signals: HashMap::from([(self.0, SignalStrength(1.0))]),
+                    exit_rules: Some(self.1),
+                    ..signal()
+                })
+            }
+        }
+
+        // First member advises an exit that is not emitted, so it's ExitRules are not used
+        let mut ensemble = Ensemble::new(Config {
+            rule: CombineRule::MajorityVote,
+        })
+        .member(
+            "a",
+            1.0,
+            WithExitRules(Decision::CloseShort, exit_rules(0.1)),
+        )
+        .member("b", 2.0, WithExitRules(Decision::Long, exit_rules(0.2)));
+
+        let signal = ensemble
+            .generate_signal(&market_event_trade(Side::Buy))
+            .unwrap();
+
+        assert_eq!(
+            decisions(Some(signal.clone())),
+            Some(vec![(Decision::Long, 1.0)])
+        );
+        assert_eq!(signal.exit_rules, Some(exit_rules(0.2)));
+    }
+
+    #[test]
+    fn test_ensemble_priority_attributes_target() {
+        let mut ensemble = Ensemble::new(Config {
+            rule: CombineRule::Priority,
+        })
+        .member(
+            "a",
+            2.0,
+            targeted(vec![
+                Some(TargetPosition::Weight(0.5)),
+                Some(TargetPosition::Quantity(0.0)),
+            ]),
+        );
+
+        let mut attribution = || {
+            let mut attribution = ensemble
+                .generate_signal(&market_event_trade(Side::Buy))
+                .unwrap()
+                .attribution
+                .into_iter()
+                .map(|attribution| attribution.decision)
+                .collect::<Vec<_>>();
+            attribution.sort();
+            attribution
+        };
+
+        // Long TargetPosition is attributed to the entry it may action
+        assert_eq!(attribution(), vec![Decision::Long]);
+
+        // Flat TargetPosition is attributed to either exit it may action
+        assert_eq!(
+            attribution(),
+            vec![Decision::CloseLong, Decision::CloseShort]
+        );
+    }
+
+    #[test]
+    fn test_ensemble_attribution() {
+        let mut ensemble = Ensemble::new(Config {
+            rule: CombineRule::MajorityVote,
+        })
+        .member(
+            "a",
+            2.0,
+            scripted(vec![Some(vec![
+                (Decision::Long, 1.0),
+                (Decision::CloseShort, 1.0),
+            ])]),
+        )
+        .member("b", 1.0, scripted(vec![Some(vec![(Decision::Short, 1.0)])]));
+
+        let signal = ensemble
+            .generate_signal(&market_event_trade(Side::Buy))
+            .unwrap();
+
+        // Only members advising an emitted Decision are attributed
+        let mut attribution = signal.attribution;
+        attribution.sort_by_key(|attribution| attribution.decision);
+        assert_eq!(
+            attribution,
+            vec![
+                Attribution {
+                    model: "a".to_owned(),
+                    decision: Decision::Long,
+                    strength: SignalStrength(1.0),
+                    weight: 2.0,
+                },
+                Attribution {
+                    model: "a".to_owned(),
+                    decision: Decision::CloseShort,
+                    strength: SignalStrength(1.0),
+                    weight: 2.0,
+                },
+            ]
+        );
+    }
+}
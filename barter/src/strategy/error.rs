@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+/// All errors generated in the barter::strategy module.
+#[derive(Error, Debug)]
+pub enum StrategyError {
+    #[error("Invalid indicator configuration: {0}")]
+    InvalidIndicator(String),
+
+    #[error("Failed to load strategy script: {0}")]
+    Script(String),
+}
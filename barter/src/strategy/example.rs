@@ -1,4 +1,4 @@
-use super::{Decision, Signal, SignalGenerator, SignalStrength};
+use super::{Decision, Signal, SignalGenerator, SignalStrength, StrategyState};
 use crate::data::MarketMeta;
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::instrument::Instrument;
@@ -47,10 +47,25 @@ impl SignalGenerator for RSIStrategy {
                 time: market.exchange_time,
             },
             signals,
+            exit_rules: None,
+            target: None,
+            attribution: vec![],
         })
     }
 }
 
+impl StrategyState for RSIStrategy {
+    type State = RelativeStrengthIndex;
+
+    fn snapshot(&self) -> Self::State {
+        self.rsi.clone()
+    }
+
+    fn restore(&mut self, state: Self::State) {
+        self.rsi = state;
+    }
+}
+
 impl RSIStrategy {
     /// Constructs a new [`RSIStrategy`] component using the provided configuration struct.
     pub fn new(config: Config) -> Self {
@@ -0,0 +1,493 @@
+use crate::strategy::error::StrategyError;
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use resample::{Bar, Resampler};
+use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, time::Duration};
+use ta::{
+    indicators::{
+        AverageTrueRange, BollingerBands, ExponentialMovingAverage,
+        MovingAverageConvergenceDivergence, RelativeStrengthIndex, SimpleMovingAverage,
+    },
+    Next,
+};
+
+/// [`Bar`] normalisation of [`MarketEvent`]s & multi-timeframe [`Resampler`].
+pub mod resample;
+
+/// Technical indicator calculated by an [`IndicatorPipeline`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndicatorKind {
+    /// Simple moving average of close prices.
+    Sma { period: usize },
+    /// Exponential moving average of close prices.
+    Ema { period: usize },
+    /// Relative strength index of close prices.
+    Rsi { period: usize },
+    /// Average true range of bars.
+    Atr { period: usize },
+    /// Bollinger bands of close prices, `multiplier` standard deviations from the average.
+    Bollinger { period: usize, multiplier: f64 },
+    /// Moving average convergence divergence of close prices.
+    Macd {
+        fast: usize,
+        slow: usize,
+        signal: usize,
+    },
+    /// Volume weighted average typical price of the last `period` bars.
+    Vwap { period: usize },
+}
+
+impl IndicatorKind {
+    /// Number of [`Bar`]s required before the indicator produces meaningful values.
+    pub fn warm_up(&self) -> usize {
+        match *self {
+            Self::Sma { period }
+            | Self::Ema { period }
+            | Self::Rsi { period }
+            | Self::Atr { period }
+            | Self::Bollinger { period, .. }
+            | Self::Vwap { period } => period,
+            Self::Macd { slow, signal, .. } => slow + signal - 1,
+        }
+    }
+}
+
+/// Kind of [`MarketEvent`] [`DataKind`] an indicator is calculated from. See [`Bar::from_market`]
+/// for how each is normalised.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndicatorInput {
+    Trade,
+    Candle,
+    OrderBookL1,
+    OrderBook,
+}
+
+impl IndicatorInput {
+    /// Determines if the provided [`DataKind`] is this [`IndicatorInput`].
+    pub fn matches(&self, kind: &DataKind) -> bool {
+        matches!(
+            (self, kind),
+            (Self::Trade, DataKind::Trade(_))
+                | (Self::Candle, DataKind::Candle(_))
+                | (Self::OrderBookL1, DataKind::OrderBookL1(_))
+                | (Self::OrderBook, DataKind::OrderBook(_))
+        )
+    }
+}
+
+/// Declaration of an indicator calculated by an [`IndicatorPipeline`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct IndicatorConfig {
+    /// Unique name used to retrieve the indicator value (eg/ "rsi_14_1h").
+    pub name: String,
+    pub kind: IndicatorKind,
+    pub input: IndicatorInput,
+    /// Timeframe of the [`Bar`]s the indicator is calculated over. If `None`, every input
+    /// [`MarketEvent`] is a [`Bar`] (eg/ candles at their native interval).
+    #[serde(default)]
+    pub timeframe: Option<Duration>,
+}
+
+/// Value output by an indicator.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum IndicatorValue {
+    Value(f64),
+    Bollinger {
+        average: f64,
+        upper: f64,
+        lower: f64,
+    },
+    Macd {
+        macd: f64,
+        signal: f64,
+        histogram: f64,
+    },
+}
+
+impl IndicatorValue {
+    /// Primary value of the indicator (ie/ the Bollinger average or MACD line).
+    pub fn value(&self) -> f64 {
+        match *self {
+            Self::Value(value) => value,
+            Self::Bollinger { average, .. } => average,
+            Self::Macd { macd, .. } => macd,
+        }
+    }
+}
+
+/// Composable pipeline of indicators calculated from a single market's [`MarketEvent`]s, so
+/// strategies declare the indicators they need rather than hand-rolling the plumbing.
+///
+/// Each indicator is calculated from it's chosen [`IndicatorInput`], resampled to it's own
+/// timeframe, and suppressed until it has seen enough [`Bar`]s to warm up. The pipeline is
+/// serialisable, so it can be included in a [`StrategyState`](super::StrategyState) snapshot.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IndicatorPipeline {
+    indicators: Vec<Indicator>,
+}
+
+impl IndicatorPipeline {
+    /// Constructs a new [`IndicatorPipeline`] calculating every declared indicator.
+    pub fn new<Configs>(configs: Configs) -> Result<Self, StrategyError>
+    where
+        Configs: IntoIterator<Item = IndicatorConfig>,
+    {
+        let mut indicators = Vec::<Indicator>::new();
+
+        for config in configs {
+            if indicators
+                .iter()
+                .any(|indicator| indicator.config.name == config.name)
+            {
+                return Err(StrategyError::InvalidIndicator(format!(
+                    "{} is declared more than once",
+                    config.name
+                )));
+            }
+
+            indicators.push(Indicator::new(config)?);
+        }
+
+        Ok(Self { indicators })
+    }
+
+    /// Updates every indicator calculated from the input [`MarketEvent`], returning true if any
+    /// indicator was updated with a new [`Bar`].
+    pub fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) -> bool {
+        let Some(bar) = Bar::from_market(market) else {
+            return false;
+        };
+
+        // Update every matching indicator, without short-circuiting on the first update
+        let mut updated = false;
+        for indicator in self
+            .indicators
+            .iter_mut()
+            .filter(|indicator| indicator.config.input.matches(&market.kind))
+        {
+            updated |= indicator.update(bar);
+        }
+
+        updated
+    }
+
+    /// Determines if every indicator has warmed up. Strategies should suppress [`Signal`]s
+    /// until the pipeline is ready.
+    ///
+    /// [`Signal`]: crate::strategy::Signal
+    pub fn is_ready(&self) -> bool {
+        self.indicators.iter().all(Indicator::is_ready)
+    }
+
+    /// Returns the latest [`IndicatorValue`] of the named indicator, or `None` if it is unknown
+    /// or still warming up.
+    pub fn get(&self, name: &str) -> Option<IndicatorValue> {
+        self.indicators
+            .iter()
+            .find(|indicator| indicator.config.name == name)
+            .filter(|indicator| indicator.is_ready())
+            .and_then(|indicator| indicator.value)
+    }
+
+    /// Returns the name & latest [`IndicatorValue`] of every indicator that has warmed up.
+    pub fn values(&self) -> impl Iterator<Item = (&str, IndicatorValue)> {
+        self.indicators
+            .iter()
+            .filter(|indicator| indicator.is_ready())
+            .filter_map(|indicator| Some((indicator.config.name.as_str(), indicator.value?)))
+    }
+
+    /// Returns the latest primary value of the named indicator, or `None` if it is unknown or
+    /// still warming up. See [`IndicatorValue::value`].
+    pub fn value(&self, name: &str) -> Option<f64> {
+        self.get(name).map(|value| value.value())
+    }
+}
+
+/// Indicator of an [`IndicatorPipeline`], with it's own [`Resampler`] & warm-up state.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct Indicator {
+    config: IndicatorConfig,
+    calculator: Calculator,
+    resampler: Option<Resampler>,
+    bars: usize,
+    value: Option<IndicatorValue>,
+}
+
+impl Indicator {
+    fn new(config: IndicatorConfig) -> Result<Self, StrategyError> {
+        Ok(Self {
+            calculator: Calculator::new(config.kind).ok_or_else(|| {
+                StrategyError::InvalidIndicator(format!("{} parameters are invalid", config.name))
+            })?,
+            resampler: config.timeframe.map(Resampler::new),
+            bars: 0,
+            value: None,
+            config,
+        })
+    }
+
+    /// Updates the indicator with the input [`Bar`], returning true if it completed a [`Bar`]
+    /// of the indicator's timeframe.
+    fn update(&mut self, bar: Bar) -> bool {
+        let bar = match &mut self.resampler {
+            Some(resampler) => match resampler.update(bar) {
+                Some(completed) => completed,
+                None => return false,
+            },
+            None => bar,
+        };
+
+        self.bars = self.bars.saturating_add(1);
+        self.value = Some(self.calculator.next(&bar));
+        true
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars >= self.config.kind.warm_up()
+    }
+}
+
+/// Calculation state of an [`IndicatorKind`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+enum Calculator {
+    Sma(SimpleMovingAverage),
+    Ema(ExponentialMovingAverage),
+    Rsi(RelativeStrengthIndex),
+    Atr(AverageTrueRange),
+    Bollinger(BollingerBands),
+    Macd(MovingAverageConvergenceDivergence),
+    Vwap(Vwap),
+}
+
+impl Calculator {
+    /// Constructs the [`Calculator`] of an [`IndicatorKind`], or `None` if it's parameters are
+    /// invalid.
+    fn new(kind: IndicatorKind) -> Option<Self> {
+        Some(match kind {
+            IndicatorKind::Sma { period } => Self::Sma(SimpleMovingAverage::new(period).ok()?),
+            IndicatorKind::Ema { period } => Self::Ema(ExponentialMovingAverage::new(period).ok()?),
+            IndicatorKind::Rsi { period } => Self::Rsi(RelativeStrengthIndex::new(period).ok()?),
+            IndicatorKind::Atr { period } => Self::Atr(AverageTrueRange::new(period).ok()?),
+            IndicatorKind::Bollinger { period, multiplier } => {
+                Self::Bollinger(BollingerBands::new(period, multiplier).ok()?)
+            }
+            IndicatorKind::Macd { fast, slow, signal } => {
+                Self::Macd(MovingAverageConvergenceDivergence::new(fast, slow, signal).ok()?)
+            }
+            IndicatorKind::Vwap { period } => Self::Vwap(Vwap::new(period)?),
+        })
+    }
+
+    fn next(&mut self, bar: &Bar) -> IndicatorValue {
+        match self {
+            Self::Sma(sma) => IndicatorValue::Value(sma.next(bar.close)),
+            Self::Ema(ema) => IndicatorValue::Value(ema.next(bar.close)),
+            Self::Rsi(rsi) => IndicatorValue::Value(rsi.next(bar.close)),
+            Self::Atr(atr) => IndicatorValue::Value(atr.next(bar)),
+            Self::Bollinger(bollinger) => {
+                let output = bollinger.next(bar.close);
+                IndicatorValue::Bollinger {
+                    average: output.average,
+                    upper: output.upper,
+                    lower: output.lower,
+                }
+            }
+            Self::Macd(macd) => {
+                let output = macd.next(bar.close);
+                IndicatorValue::Macd {
+                    macd: output.macd,
+                    signal: output.signal,
+                    histogram: output.histogram,
+                }
+            }
+            Self::Vwap(vwap) => IndicatorValue::Value(vwap.next(bar)),
+        }
+    }
+}
+
+/// Rolling volume weighted average typical price ((high + low + close) / 3).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct Vwap {
+    period: usize,
+    window: VecDeque<(f64, f64)>,
+}
+
+impl Vwap {
+    fn new(period: usize) -> Option<Self> {
+        if period == 0 {
+            return None;
+        }
+
+        Some(Self {
+            period,
+            window: VecDeque::with_capacity(period),
+        })
+    }
+
+    /// Returns the VWAP after the input [`Bar`], falling back to it's typical price whilst the
+    /// window has no volume (eg/ order book mid prices).
+    fn next(&mut self, bar: &Bar) -> f64 {
+        let typical_price = (bar.high + bar.low + bar.close) / 3.0;
+
+        if self.window.len() == self.period {
+            self.window.pop_front();
+        }
+        self.window
+            .push_back((typical_price * bar.volume, bar.volume));
+
+        let (price_volume, volume) = self
+            .window
+            .iter()
+            .fold((0.0, 0.0), |(pv_total, v_total), (pv, v)| {
+                (pv_total + pv, v_total + v)
+            });
+
+        if volume > 0.0 {
+            price_volume / volume
+        } else {
+            typical_price
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_data::subscription::candle::Candle;
+    use barter_integration::model::Side;
+    use chrono::{DateTime, Utc};
+
+    fn trade(secs: i64, price: f64, amount: f64) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.exchange_time = DateTime::from_timestamp(secs, 0).unwrap();
+        if let DataKind::Trade(trade) = &mut market.kind {
+            trade.price = price;
+            trade.amount = amount;
+        }
+        market
+    }
+
+    #[test]
+    fn test_indicator_pipeline_warm_up_and_resampling() {
+        let mut pipeline = IndicatorPipeline::new([
+            IndicatorConfig {
+                name: "vwap_2".to_owned(),
+                kind: IndicatorKind::Vwap { period: 2 },
+                input: IndicatorInput::Trade,
+                timeframe: None,
+            },
+            IndicatorConfig {
+                name: "vwap_1_1m".to_owned(),
+                kind: IndicatorKind::Vwap { period: 1 },
+                input: IndicatorInput::Trade,
+                timeframe: Some(Duration::from_secs(60)),
+            },
+        ])
+        .unwrap();
+
+        struct TestCase {
+            input: MarketEvent<Instrument, DataKind>,
+            expected_updated: bool,
+            expected_vwap_2: Option<f64>,
+            expected_vwap_1_1m: Option<f64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: every indicator still warming up
+                input: trade(0, 10.0, 1.0),
+                expected_updated: true,
+                expected_vwap_2: None,
+                expected_vwap_1_1m: None,
+            },
+            TestCase {
+                // TC1: tick indicator warmed up, 1m Bar still open
+                input: trade(30, 20.0, 3.0),
+                expected_updated: true,
+                expected_vwap_2: Some(17.5),
+                expected_vwap_1_1m: None,
+            },
+            TestCase {
+                // TC2: 1m Bar completed with typical price (20 + 10 + 20) / 3 & volume 4
+                input: trade(60, 40.0, 1.0),
+                expected_updated: true,
+                expected_vwap_2: Some(25.0),
+                expected_vwap_1_1m: Some(50.0 / 3.0),
+            },
+            TestCase {
+                // TC3: non-input DataKind does not update any indicator
+                input: MarketEvent {
+                    kind: DataKind::Candle(Candle {
+                        close_time: Utc::now(),
+                        open: 1.0,
+                        high: 1.0,
+                        low: 1.0,
+                        close: 1.0,
+                        volume: 1.0,
+                        trade_count: 1,
+                    }),
+                    ..trade(61, 0.0, 0.0)
+                },
+                expected_updated: false,
+                expected_vwap_2: Some(25.0),
+                expected_vwap_1_1m: Some(50.0 / 3.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let updated = pipeline.update(&test.input);
+            assert_eq!(updated, test.expected_updated, "TC{} updated failed", index);
+            assert_eq!(
+                pipeline.value("vwap_2"),
+                test.expected_vwap_2,
+                "TC{} vwap_2 failed",
+                index
+            );
+            assert_eq!(
+                pipeline.value("vwap_1_1m"),
+                test.expected_vwap_1_1m,
+                "TC{} vwap_1_1m failed",
+                index
+            );
+            assert_eq!(
+                pipeline.is_ready(),
+                test.expected_vwap_1_1m.is_some(),
+                "TC{} is_ready failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_indicator_pipeline_new_with_invalid_configs() {
+        let config = |name: &str, period| IndicatorConfig {
+            name: name.to_owned(),
+            kind: IndicatorKind::Vwap { period },
+            input: IndicatorInput::Trade,
+            timeframe: None,
+        };
+
+        // Duplicate names
+        assert!(IndicatorPipeline::new([config("vwap", 2), config("vwap", 3)]).is_err());
+
+        // Invalid parameters
+        assert!(IndicatorPipeline::new([config("vwap", 0)]).is_err());
+
+        // Warm-up of each IndicatorKind
+        assert_eq!(
+            IndicatorKind::Macd {
+                fast: 12,
+                slow: 26,
+                signal: 9
+            }
+            .warm_up(),
+            34
+        );
+        assert_eq!(IndicatorKind::Rsi { period: 14 }.warm_up(), 14);
+    }
+}
@@ -0,0 +1,196 @@
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// OHLCV bar an indicator is updated with, normalised from any [`DataKind`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Bar {
+    /// Time the bar opened.
+    pub time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+impl Bar {
+    /// Constructs a single-price [`Bar`].
+    pub fn from_price(time: DateTime<Utc>, price: f64, volume: f64) -> Self {
+        Self {
+            time,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume,
+        }
+    }
+
+    /// Normalises the input [`MarketEvent`] into a [`Bar`] at it's exchange time:
+    /// - Trade: traded price & amount.
+    /// - Candle: candle OHLCV.
+    /// - Order books: mid price, without volume.
+    pub fn from_market(market: &MarketEvent<Instrument, DataKind>) -> Option<Self> {
+        let time = market.exchange_time;
+
+        match &market.kind {
+            DataKind::Trade(trade) => Some(Self::from_price(time, trade.price, trade.amount)),
+            DataKind::Candle(candle) => Some(Self {
+                time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+            }),
+            DataKind::OrderBookL1(book_l1) => {
+                Some(Self::from_price(time, book_l1.mid_price(), 0.0))
+            }
+            DataKind::OrderBook(book) => book
+                .mid_price()
+                .map(|mid_price| Self::from_price(time, mid_price, 0.0)),
+            DataKind::Liquidation(_) => None,
+        }
+    }
+
+    /// Merges a subsequent [`Bar`] of the same period into this [`Bar`].
+    fn merge(&mut self, next: &Bar) {
+        self.high = self.high.max(next.high);
+        self.low = self.low.min(next.low);
+        self.close = next.close;
+        self.volume += next.volume;
+    }
+}
+
+impl ta::Open for Bar {
+    fn open(&self) -> f64 {
+        self.open
+    }
+}
+
+impl ta::High for Bar {
+    fn high(&self) -> f64 {
+        self.high
+    }
+}
+
+impl ta::Low for Bar {
+    fn low(&self) -> f64 {
+        self.low
+    }
+}
+
+impl ta::Close for Bar {
+    fn close(&self) -> f64 {
+        self.close
+    }
+}
+
+impl ta::Volume for Bar {
+    fn volume(&self) -> f64 {
+        self.volume
+    }
+}
+
+/// Resamples [`Bar`]s into [`Bar`]s of a fixed timeframe aligned to the unix epoch (eg/ 5m bars
+/// open at :00, :05, etc.), enabling indicators of many timeframes to be calculated from the same
+/// [`MarketEvent`] feed.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Resampler {
+    timeframe: Duration,
+    current: Option<Bar>,
+}
+
+impl Resampler {
+    /// Constructs a new [`Resampler`] producing [`Bar`]s of the provided timeframe.
+    pub fn new(timeframe: Duration) -> Self {
+        Self {
+            timeframe,
+            current: None,
+        }
+    }
+
+    /// Updates the current [`Bar`] with the input [`Bar`], returning the completed [`Bar`] of the
+    /// previous period if the input [`Bar`] opens a new one.
+    pub fn update(&mut self, bar: Bar) -> Option<Bar> {
+        let time = self.period_start(bar.time);
+
+        match &mut self.current {
+            Some(current) if time <= current.time => {
+                current.merge(&bar);
+                None
+            }
+            current => current.replace(Bar { time, ..bar }),
+        }
+    }
+
+    /// Determines the open time of the period containing the provided time.
+    fn period_start(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let timeframe = i64::try_from(self.timeframe.as_millis())
+            .unwrap_or(i64::MAX)
+            .max(1);
+        let millis = time.timestamp_millis();
+
+        DateTime::from_timestamp_millis(millis - millis.rem_euclid(timeframe)).unwrap_or(time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resampler_update() {
+        let mut resampler = Resampler::new(Duration::from_secs(60));
+
+        let time = |secs| DateTime::from_timestamp(secs, 0).unwrap();
+
+        struct TestCase {
+            input: Bar,
+            expected: Option<Bar>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first Bar opens the first period
+                input: Bar::from_price(time(61), 10.0, 1.0),
+                expected: None,
+            },
+            TestCase {
+                // TC1: Bar within the same period is merged
+                input: Bar::from_price(time(90), 12.0, 2.0),
+                expected: None,
+            },
+            TestCase {
+                // TC2: Bar within the same period is merged
+                input: Bar::from_price(time(119), 9.0, 1.0),
+                expected: None,
+            },
+            TestCase {
+                // TC3: Bar opening the next period completes the previous Bar
+                input: Bar::from_price(time(120), 11.0, 1.0),
+                expected: Some(Bar {
+                    time: time(60),
+                    open: 10.0,
+                    high: 12.0,
+                    low: 9.0,
+                    close: 9.0,
+                    volume: 4.0,
+                }),
+            },
+            TestCase {
+                // TC4: Bar skipping periods completes the previous Bar
+                input: Bar::from_price(time(300), 13.0, 1.0),
+                expected: Some(Bar::from_price(time(120), 11.0, 1.0)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = resampler.update(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
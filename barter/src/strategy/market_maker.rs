@@ -0,0 +1,149 @@
+use barter_data::event::{DataKind, MarketEvent};
+use barter_execution::model::quote::{Quote, Quotes};
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+
+/// May generate desired two-sided [`Quotes`] as a result of analysing an input [`MarketEvent`].
+///
+/// Unlike a [`SignalGenerator`](super::SignalGenerator), whose advisory
+/// [`Decision`](super::Decision) can only enter or exit a single directional position, a
+/// [`QuoteGenerator`] expresses resting liquidity on both sides of the book at many levels. The
+/// [`Quotes`] are intended to be maintained by a barter-execution
+/// [`OrderManager`](barter_execution::order_manager::OrderManager), which diffs them against the
+/// resting orders into place, cancel & amend requests.
+pub trait QuoteGenerator {
+    /// Optionally return the desired [`Quotes`] given the input [`MarketEvent`]. Returning None
+    /// leaves the currently desired [`Quotes`] unchanged.
+    fn generate_quotes(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Quotes>;
+}
+
+/// Configuration for constructing a [`SymmetricQuoter`] via the new() constructor method.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Distance of the best bid & ask from the mid price in decimal form (eg/ 0.001 for 0.1%).
+    pub half_spread: f64,
+    /// Distance between consecutive levels on each side in decimal form of the mid price.
+    pub level_spacing: f64,
+    /// Number of levels quoted on each side.
+    pub levels: usize,
+    /// Quantity quoted at every level.
+    pub quantity: f64,
+}
+
+/// Reference market-making strategy that implements [`QuoteGenerator`].
+///
+/// Quotes the configured number of equally sized levels symmetrically around the mid price of
+/// every [`OrderBookL1`](barter_data::subscription::book::OrderBookL1) or
+/// [`OrderBook`](barter_data::subscription::book::OrderBook) it consumes. It does not skew
+/// quotes for inventory, so is intended as a starting point for more sophisticated quoters.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct SymmetricQuoter {
+    half_spread: f64,
+    level_spacing: f64,
+    levels: usize,
+    quantity: f64,
+}
+
+impl QuoteGenerator for SymmetricQuoter {
+    fn generate_quotes(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Quotes> {
+        // Determine the mid price of the order book
+        let mid_price = match &market.kind {
+            DataKind::OrderBookL1(book_l1) => book_l1.mid_price(),
+            DataKind::OrderBook(book) => book.mid_price()?,
+            _ => return None,
+        };
+
+        let offset =
+            |level: usize| mid_price * (self.half_spread + level as f64 * self.level_spacing);
+
+        Some(Quotes {
+            bids: (0..self.levels)
+                .map(|level| Quote::new(mid_price - offset(level), self.quantity))
+                .collect(),
+            asks: (0..self.levels)
+                .map(|level| Quote::new(mid_price + offset(level), self.quantity))
+                .collect(),
+            ..Quotes::empty(market.exchange.clone(), market.instrument.clone())
+        })
+    }
+}
+
+impl SymmetricQuoter {
+    /// Constructs a new [`SymmetricQuoter`] component using the provided configuration struct.
+    pub fn new(config: Config) -> Self {
+        Self {
+            half_spread: config.half_spread,
+            level_spacing: config.level_spacing,
+            levels: config.levels,
+            quantity: config.quantity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_data::subscription::{
+        book::{Level, OrderBookL1},
+        trade::PublicTrade,
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Exchange, Side};
+    use chrono::Utc;
+
+    fn market_event(kind: DataKind) -> MarketEvent<Instrument, DataKind> {
+        MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from("binance"),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_symmetric_quoter_generate_quotes() {
+        let mut quoter = SymmetricQuoter::new(Config {
+            half_spread: 0.01,
+            level_spacing: 0.005,
+            levels: 2,
+            quantity: 1.0,
+        });
+
+        struct TestCase {
+            input: MarketEvent<Instrument, DataKind>,
+            expected: Option<(Vec<Quote>, Vec<Quote>)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: OrderBookL1 is quoted symmetrically around it's mid price
+                input: market_event(DataKind::OrderBookL1(OrderBookL1 {
+                    last_update_time: Utc::now(),
+                    best_bid: Level::new(199.0, 1.0),
+                    best_ask: Level::new(201.0, 1.0),
+                })),
+                expected: Some((
+                    vec![Quote::new(198.0, 1.0), Quote::new(197.0, 1.0)],
+                    vec![Quote::new(202.0, 1.0), Quote::new(203.0, 1.0)],
+                )),
+            },
+            TestCase {
+                // TC1: PublicTrade does not generate Quotes
+                input: market_event(DataKind::Trade(PublicTrade {
+                    id: "id".to_string(),
+                    price: 200.0,
+                    amount: 1.0,
+                    side: Side::Buy,
+                })),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = quoter
+                .generate_quotes(&test.input)
+                .map(|quotes| (quotes.bids, quotes.asks));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,21 +1,84 @@
-use crate::data::MarketMeta;
+use crate::{data::MarketMeta, portfolio::exit::ExitRules};
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Exchange, Market};
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Barter example RSI strategy [`SignalGenerator`] implementation.
 pub mod example;
 
+/// Reference cross-exchange arbitrage [`MultiMarketSignalGenerator`] implementation.
+pub mod arbitrage;
+
+/// Market-making [`QuoteGenerator`](market_maker::QuoteGenerator) interface producing desired
+/// two-sided quotes, with a reference symmetric quoting implementation.
+pub mod market_maker;
+
+/// [`SignalGenerator`] combinator merging the [`Signal`]s of many child strategies by
+/// configurable rules, with per-model [`Attribution`].
+pub mod ensemble;
+
+/// Composable technical indicator pipeline with warm-up tracking & multi-timeframe resampling.
+pub mod indicator;
+
+/// [`SignalGenerator`] evaluating a hot-reloadable Rhai script, enabled by the `scripting` feature.
+#[cfg(feature = "scripting")]
+pub mod script;
+
+/// Scheduled [`Timer`](timer::Timer) callbacks for time-based exits, rebalances & end-of-day
+/// flattening, driven by the market data clock.
+pub mod timer;
+
+/// Errors generated in the strategy module.
+pub mod error;
+
 /// May generate an advisory [`Signal`] as a result of analysing an input [`MarketEvent`].
 pub trait SignalGenerator {
     /// Optionally return a [`Signal`] given input [`MarketEvent`].
     fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal>;
 }
 
+/// Portfolio-level strategy fed by a merged stream of [`MarketEvent`]s from many markets. May
+/// generate advisory [`Signal`]s for any subset of the markets it observes (eg/ both legs of a
+/// pairs trade), which are routed to the relevant execution handler by the
+/// [`Trader`](crate::engine::trader::Trader).
+///
+/// Every single-market [`SignalGenerator`] is also a [`MultiMarketSignalGenerator`].
+pub trait MultiMarketSignalGenerator {
+    /// Return every [`Signal`] generated given the input [`MarketEvent`] of any market observed.
+    fn generate_signals(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Vec<Signal>;
+}
+
+impl<Strategy> MultiMarketSignalGenerator for Strategy
+where
+    Strategy: SignalGenerator,
+{
+    fn generate_signals(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Vec<Signal> {
+        self.generate_signal(market).into_iter().collect()
+    }
+}
+
+/// Optional extension for strategies with in-memory state (eg/ indicators) that can be snapshot &
+/// restored, so it survives restarts alongside the Portfolio's persisted Positions.
+///
+/// A [`Trader`](crate::engine::trader::Trader) configured via
+/// [`TraderBuilder::strategy_state`](crate::engine::trader::TraderBuilder::strategy_state)
+/// restores the latest snapshot at startup, and persists snapshots periodically & on shutdown.
+pub trait StrategyState {
+    /// Serialisable snapshot of the strategy state.
+    type State: Serialize + DeserializeOwned;
+
+    /// Return a snapshot of the current strategy state.
+    fn snapshot(&self) -> Self::State;
+
+    /// Restore the strategy state from a previously taken snapshot.
+    fn restore(&mut self, state: Self::State);
+}
+
 /// Advisory [`Signal`] for a [`Market`] detailing the [`SignalStrength`] associated with each
-/// possible [`Decision`]. Interpreted by an [`OrderGenerator`](crate::portfolio::OrderGenerator).
+/// possible [`Decision`], or the [`TargetPosition`] to rebalance to. Interpreted by an
+/// [`OrderGenerator`](crate::portfolio::OrderGenerator).
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
 pub struct Signal {
     pub time: DateTime<Utc>,
@@ -24,6 +87,57 @@ pub struct Signal {
     pub signals: HashMap<Decision, SignalStrength>,
     /// Metadata propagated from the [`MarketEvent`] that yielded this [`Signal`].
     pub market_meta: MarketMeta,
+    /// Optional [`ExitRules`] attached to the [`Position`](crate::portfolio::position::Position)
+    /// entered by this [`Signal`], overriding the Portfolio's [`ExitRules`] for the market.
+    #[serde(default)]
+    pub exit_rules: Option<ExitRules>,
+    /// Optional [`TargetPosition`] of the market. If provided, the Portfolio orders the delta
+    /// between the current [`Position`](crate::portfolio::position::Position) & the target, and
+    /// the signals map is ignored.
+    #[serde(default)]
+    pub target: Option<TargetPosition>,
+    /// Contribution of each model to the [`Decision`]s of this [`Signal`], if it was combined
+    /// from many (eg/ by an [`Ensemble`](ensemble::Ensemble)). The [`Attribution`] of the entry
+    /// [`Decision`] is recorded on the [`Position`](crate::portfolio::position::Position) it
+    /// enters.
+    #[serde(default)]
+    pub attribution: Vec<Attribution>,
+}
+
+/// Contribution of a named model to a [`Decision`] of a combined [`Signal`], enabling
+/// performance to be attributed to individual models.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Attribution {
+    /// Name of the contributing model.
+    pub model: String,
+    pub decision: Decision,
+    /// [`SignalStrength`] the model advised the [`Decision`] with.
+    pub strength: SignalStrength,
+    /// Weight of the model when combining [`Signal`]s.
+    pub weight: f64,
+}
+
+/// Target signed exposure to a market, enabling continuous rebalancing strategies to express
+/// where they want to be rather than the discrete [`Decision`] required to get there.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum TargetPosition {
+    /// Signed quantity of the instrument (+ve long, -ve short & zero flat).
+    Quantity(f64),
+    /// Signed fraction of Portfolio equity (eg/ 0.5 for long 50%, -0.25 for short 25%).
+    Weight(f64),
+}
+
+impl TargetPosition {
+    /// Determines the signed target quantity of the instrument given the Portfolio equity &
+    /// instrument price, both denominated in the same currency. A [`TargetPosition::Weight`]
+    /// without a positive price is flat.
+    pub fn quantity(&self, equity: f64, price: f64) -> f64 {
+        match *self {
+            Self::Quantity(quantity) => quantity,
+            Self::Weight(weight) if price > 0.0 => weight * equity / price,
+            Self::Weight(_) => 0.0,
+        }
+    }
 }
 
 /// Describes the type of advisory signal the strategy is endorsing.
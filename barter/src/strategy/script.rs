@@ -0,0 +1,527 @@
+use super::{
+    error::StrategyError,
+    indicator::{resample::Bar, IndicatorConfig, IndicatorPipeline, IndicatorValue},
+    Decision, Signal, SignalGenerator, SignalStrength,
+};
+use crate::{
+    data::MarketMeta,
+    portfolio::{
+        position::{determine_position_id, Position},
+        repository::PositionHandler,
+    },
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use chrono::Utc;
+use parking_lot::Mutex;
+use rhai::{Dynamic, Engine, Map, Scope, AST};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Name of the function every strategy script must define.
+///
+/// ```rhai
+/// fn generate_signal(market, indicators, position) {
+///     if position == () && indicators.rsi < 30.0 {
+///         return #{ long: 1.0 };
+///     }
+///     if position != () && indicators.rsi > 70.0 {
+///         return #{ close_long: 1.0 };
+///     }
+/// }
+/// ```
+pub const SCRIPT_ENTRY_POINT: &str = "generate_signal";
+
+/// Maximum number of operations a single script evaluation may perform, guarding the engine
+/// against a hot-reloaded script that never terminates.
+const SCRIPT_MAX_OPERATIONS: u64 = 1_000_000;
+
+/// Looks up the open [`Position`] of a market, if any, on behalf of a [`ScriptedStrategy`].
+type PositionLookup = Box<dyn FnMut(&Exchange, &Instrument) -> Option<Position> + Send>;
+
+/// Configuration for constructing a [`ScriptedStrategy`] via the new() constructor method.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Path of the Rhai script defining the [`SCRIPT_ENTRY_POINT`] function.
+    pub path: PathBuf,
+    /// Indicators calculated from the [`MarketEvent`]s & exposed to the script by name.
+    #[serde(default)]
+    pub indicators: Vec<IndicatorConfig>,
+    /// Minimum interval between checks of whether the script file has been modified.
+    pub reload_interval: Duration,
+}
+
+/// [`SignalGenerator`] that delegates to a Rhai script, enabling strategies to be prototyped
+/// without recompiling the engine.
+///
+/// For every [`MarketEvent`] the script's [`SCRIPT_ENTRY_POINT`] function is called with:
+/// - `market`: map of the exchange, instrument, `kind` (eg/ "trade", "candle"), exchange `time`
+///   in epoch milliseconds, and the `open`, `high`, `low`, `close` & `volume` of the event.
+/// - `indicators`: map of the name & value of every warmed up indicator. Bollinger & MACD values
+///   are themselves maps of their components.
+/// - `position`: map of the open [`Position`] of the market (`side`, `quantity`, `entry_price`,
+///   `current_price` & `unrealised_pnl`), or `()` if there is none or no Portfolio is attached.
+///
+/// The script returns `()` for no [`Signal`], or a map of [`Decision`] (`long`, `close_long`,
+/// `short`, `close_short`) to [`SignalStrength`].
+///
+/// The script file is recompiled whenever it is modified. A script that fails to compile or
+/// evaluate is logged & yields no [`Signal`] rather than halting the engine, and the previously
+/// compiled version remains in use until the file is fixed.
+pub struct ScriptedStrategy {
+    engine: Engine,
+    path: PathBuf,
+    ast: AST,
+    modified: Option<SystemTime>,
+    reload_interval: Duration,
+    reload_checked: Instant,
+    indicators: IndicatorPipeline,
+    positions: Option<PositionLookup>,
+}
+
+impl Debug for ScriptedStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScriptedStrategy")
+            .field("path", &self.path)
+            .field("modified", &self.modified)
+            .field("reload_interval", &self.reload_interval)
+            .field("indicators", &self.indicators)
+            .field("positions", &self.positions.is_some())
+            .finish()
+    }
+}
+
+impl SignalGenerator for ScriptedStrategy {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        self.reload_if_modified();
+        self.indicators.update(market);
+
+        let bar = Bar::from_market(market)?;
+        let position = self
+            .positions
+            .as_mut()
+            .and_then(|lookup| lookup(&market.exchange, &market.instrument))
+            .map_or(Dynamic::UNIT, |position| position_map(&position).into());
+
+        let output = self
+            .engine
+            .call_fn::<Dynamic>(
+                &mut Scope::new(),
+                &self.ast,
+                SCRIPT_ENTRY_POINT,
+                (market_map(market, &bar), self.indicator_map(), position),
+            )
+            .map_err(|error| {
+                warn!(path = ?self.path, %error, "failed to evaluate strategy script");
+            })
+            .ok()?;
+
+        let signals = parse_signals(output)
+            .map_err(|error| {
+                warn!(path = ?self.path, %error, "strategy script returned an invalid signal");
+            })
+            .ok()?;
+
+        if signals.is_empty() {
+            return None;
+        }
+
+        Some(Signal {
+            time: Utc::now(),
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            signals,
+            market_meta: MarketMeta {
+                close: bar.close,
+                time: market.exchange_time,
+            },
+            exit_rules: None,
+            target: None,
+            attribution: vec![],
+        })
+    }
+}
+
+impl ScriptedStrategy {
+    /// Constructs a new [`ScriptedStrategy`] component using the provided configuration struct,
+    /// failing if the script cannot be compiled or an indicator is invalid.
+    pub fn new(config: Config) -> Result<Self, StrategyError> {
+        let mut engine = Engine::new();
+        engine.set_max_operations(SCRIPT_MAX_OPERATIONS);
+
+        let ast = engine
+            .compile_file(config.path.clone())
+            .map_err(|error| StrategyError::Script(error.to_string()))?;
+
+        Ok(Self {
+            engine,
+            modified: modified_time(&config.path),
+            path: config.path,
+            ast,
+            reload_interval: config.reload_interval,
+            reload_checked: Instant::now(),
+            indicators: IndicatorPipeline::new(config.indicators)?,
+            positions: None,
+        })
+    }
+
+    /// Exposes the open [`Position`]s of the provided Portfolio to the script, looked up using
+    /// the engine_id of the [`Trader`](crate::engine::trader::Trader) running this strategy.
+    pub fn with_positions<Portfolio>(
+        self,
+        engine_id: Uuid,
+        portfolio: Arc<Mutex<Portfolio>>,
+    ) -> Self
+    where
+        Portfolio: PositionHandler + Send + 'static,
+    {
+        Self {
+            positions: Some(Box::new(move |exchange, instrument| {
+                portfolio
+                    .lock()
+                    .get_open_position(&determine_position_id(engine_id, exchange, instrument))
+                    .map_err(|error| warn!(%error, "failed to get open Position for script"))
+                    .ok()
+                    .flatten()
+            })),
+            ..self
+        }
+    }
+
+    /// Recompiles the script if it's file has been modified since it was last compiled. Checks
+    /// are throttled to the configured reload interval.
+    fn reload_if_modified(&mut self) {
+        if self.reload_checked.elapsed() < self.reload_interval {
+            return;
+        }
+        self.reload_checked = Instant::now();
+
+        let modified = modified_time(&self.path);
+        if modified == self.modified {
+            return;
+        }
+
+        // Record the modification even if compilation fails, so the broken file is not
+        // recompiled on every check
+        self.modified = modified;
+
+        match self.engine.compile_file(self.path.clone()) {
+            Ok(ast) => {
+                self.ast = ast;
+                info!(path = ?self.path, "reloaded modified strategy script");
+            }
+            Err(error) => {
+                warn!(
+                    path = ?self.path,
+                    %error,
+                    action = "continuing with previously compiled script",
+                    "failed to reload modified strategy script"
+                );
+            }
+        }
+    }
+
+    /// Map of the name & value of every warmed up indicator.
+    fn indicator_map(&self) -> Map {
+        self.indicators
+            .values()
+            .map(|(name, value)| {
+                let value = match value {
+                    IndicatorValue::Value(value) => Dynamic::from_float(value),
+                    IndicatorValue::Bollinger {
+                        average,
+                        upper,
+                        lower,
+                    } => {
+                        float_map([("average", average), ("upper", upper), ("lower", lower)]).into()
+                    }
+                    IndicatorValue::Macd {
+                        macd,
+                        signal,
+                        histogram,
+                    } => float_map([("macd", macd), ("signal", signal), ("histogram", histogram)])
+                        .into(),
+                };
+
+                (name.into(), value)
+            })
+            .collect()
+    }
+}
+
+/// Determines the last modification time of the file at the provided path, if accessible.
+fn modified_time(path: &PathBuf) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Constructs a script [`Map`] of the provided float values.
+fn float_map<const N: usize>(values: [(&str, f64); N]) -> Map {
+    values
+        .into_iter()
+        .map(|(key, value)| (key.into(), Dynamic::from_float(value)))
+        .collect()
+}
+
+/// Script `market` map of the input [`MarketEvent`] & the [`Bar`] normalised from it.
+fn market_map(market: &MarketEvent<Instrument, DataKind>, bar: &Bar) -> Map {
+    let kind = match &market.kind {
+        DataKind::Trade(_) => "trade",
+        DataKind::OrderBookL1(_) => "order_book_l1",
+        DataKind::OrderBook(_) => "order_book",
+        DataKind::Candle(_) => "candle",
+        DataKind::Liquidation(_) => "liquidation",
+    };
+
+    let mut map = float_map([
+        ("open", bar.open),
+        ("high", bar.high),
+        ("low", bar.low),
+        ("close", bar.close),
+        ("volume", bar.volume),
+    ]);
+    map.insert("exchange".into(), market.exchange.to_string().into());
+    map.insert("instrument".into(), market.instrument.to_string().into());
+    map.insert("kind".into(), kind.into());
+    map.insert(
+        "time".into(),
+        Dynamic::from_int(market.exchange_time.timestamp_millis()),
+    );
+    map
+}
+
+/// Script `position` map of an open [`Position`].
+fn position_map(position: &Position) -> Map {
+    let side = match position.side {
+        Side::Buy => "buy",
+        Side::Sell => "sell",
+    };
+
+    let mut map = float_map([
+        ("quantity", position.quantity),
+        ("entry_price", position.enter_avg_price_gross),
+        ("current_price", position.current_symbol_price),
+        ("unrealised_pnl", position.unrealised_profit_loss),
+    ]);
+    map.insert("side".into(), side.into());
+    map
+}
+
+/// Parses the value returned by a script into the [`SignalStrength`] of each [`Decision`].
+fn parse_signals(output: Dynamic) -> Result<HashMap<Decision, SignalStrength>, String> {
+    if output.is_unit() {
+        return Ok(HashMap::new());
+    }
+
+    let map = output
+        .try_cast::<Map>()
+        .ok_or_else(|| "expected a map of decision to strength, or ()".to_string())?;
+
+    map.into_iter()
+        .map(|(key, strength)| {
+            let decision = match key.as_str() {
+                "long" => Decision::Long,
+                "close_long" => Decision::CloseLong,
+                "short" => Decision::Short,
+                "close_short" => Decision::CloseShort,
+                other => return Err(format!("unknown decision: {other}")),
+            };
+
+            let strength = strength
+                .as_float()
+                .or_else(|_| strength.as_int().map(|strength| strength as f64))
+                .map_err(|type_name| format!("{key} strength is a {type_name}, not a number"))?;
+
+            Ok((decision, SignalStrength(strength)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::repository::in_memory::InMemoryRepository,
+        statistic::summary::pnl::PnLReturnSummary,
+        strategy::indicator::{IndicatorInput, IndicatorKind},
+        test_util::position,
+    };
+    use barter_data::subscription::trade::PublicTrade;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn trade(price: f64) -> MarketEvent<Instrument, DataKind> {
+        MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            exchange: Exchange::from("binance"),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: DataKind::Trade(PublicTrade {
+                id: "id".to_string(),
+                price,
+                amount: 1.0,
+                side: Side::Buy,
+            }),
+        }
+    }
+
+    fn script_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("barter_{name}_{}.rhai", Uuid::new_v4()))
+    }
+
+    #[test]
+    fn test_scripted_strategy_generate_signal() {
+        let path = script_path("generate_signal");
+        std::fs::write(
+            &path,
+            r#"
+            fn generate_signal(market, indicators, position) {
+                if !("sma" in indicators) {
+                    return;
+                }
+                if market.close == 0.0 {
+                    return #{ hodl: 1.0 };
+                }
+                if position == () && market.close > 105.0 {
+                    return #{ long: 1.0 };
+                }
+                if position != () && position.side == "buy" && market.close < 95.0 {
+                    return #{ close_long: 1 };
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let mut strategy = ScriptedStrategy::new(Config {
+            path: path.clone(),
+            indicators: vec![IndicatorConfig {
+                name: "sma".to_string(),
+                kind: IndicatorKind::Sma { period: 2 },
+                input: IndicatorInput::Trade,
+                timeframe: None,
+            }],
+            reload_interval: Duration::ZERO,
+        })
+        .unwrap();
+
+        struct TestCase {
+            input: MarketEvent<Instrument, DataKind>,
+            open_position: bool,
+            expected: Option<HashMap<Decision, SignalStrength>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no Signal while the indicator warms up
+                input: trade(110.0),
+                open_position: false,
+                expected: None,
+            },
+            TestCase {
+                // TC1: close above 105 without a Position enters long
+                input: trade(110.0),
+                open_position: false,
+                expected: Some(HashMap::from([(Decision::Long, SignalStrength(1.0))])),
+            },
+            TestCase {
+                // TC2: close above 105 with an open Position yields no Signal
+                input: trade(120.0),
+                open_position: true,
+                expected: None,
+            },
+            TestCase {
+                // TC3: close below 95 with an open long Position closes it
+                input: trade(90.0),
+                open_position: true,
+                expected: Some(HashMap::from([(Decision::CloseLong, SignalStrength(1.0))])),
+            },
+            TestCase {
+                // TC4: unknown Decision returned by the script yields no Signal
+                input: trade(0.0),
+                open_position: true,
+                expected: None,
+            },
+        ];
+
+        let engine_id = Uuid::new_v4();
+        let portfolio = Arc::new(Mutex::new(InMemoryRepository::<PnLReturnSummary>::new()));
+        strategy = strategy.with_positions(engine_id, Arc::clone(&portfolio));
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let position_id =
+                determine_position_id(engine_id, &test.input.exchange, &test.input.instrument);
+            if test.open_position {
+                portfolio
+                    .lock()
+                    .set_open_position(Position {
+                        position_id,
+                        exchange: test.input.exchange.clone(),
+                        instrument: test.input.instrument.clone(),
+                        ..position()
+                    })
+                    .unwrap();
+            } else {
+                portfolio.lock().remove_position(&position_id).unwrap();
+            }
+
+            let actual = strategy
+                .generate_signal(&test.input)
+                .map(|signal| signal.signals);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_scripted_strategy_reload_if_modified() {
+        let path = script_path("reload");
+        let write_script = |decision: &str| {
+            std::fs::write(
+                &path,
+                format!(
+                    "fn generate_signal(market, indicators, position) {{ #{{ {decision}: 1.0 }} }}"
+                ),
+            )
+            .unwrap()
+        };
+
+        write_script("long");
+        let mut strategy = ScriptedStrategy::new(Config {
+            path: path.clone(),
+            indicators: vec![],
+            reload_interval: Duration::ZERO,
+        })
+        .unwrap();
+
+        let decisions = |strategy: &mut ScriptedStrategy| {
+            strategy
+                .generate_signal(&trade(100.0))
+                .map(|signal| signal.signals.into_keys().collect::<Vec<_>>())
+        };
+
+        // Initially compiled script
+        assert_eq!(decisions(&mut strategy), Some(vec![Decision::Long]));
+
+        // Modified script is reloaded
+        write_script("short");
+        strategy.modified = None;
+        assert_eq!(decisions(&mut strategy), Some(vec![Decision::Short]));
+
+        // Script that fails to compile leaves the previously compiled script in use
+        std::fs::write(&path, "fn generate_signal(").unwrap();
+        strategy.modified = None;
+        assert_eq!(decisions(&mut strategy), Some(vec![Decision::Short]));
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
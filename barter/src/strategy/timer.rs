@@ -0,0 +1,394 @@
+use super::Signal;
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use chrono::{DateTime, Datelike, Days, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Strategy callback for scheduled [`TimerEvent`]s, enabling time-based exits, rebalances &
+/// end-of-day flattening.
+///
+/// A [`Trader`](crate::engine::trader::Trader) configured via
+/// [`TraderBuilder::timers`](crate::engine::trader::TraderBuilder::timers) fires each [`Timer`]
+/// on the [`MarketEvent`](barter_data::event::MarketEvent) exchange time clock, so timers behave
+/// identically when backtesting & trading live.
+pub trait TimerHandler {
+    /// Return every [`Signal`] generated given the input [`TimerEvent`], and the [`MarketEvent`]
+    /// that advanced the clock to fire it (eg/ to price the [`Signal`] market meta).
+    fn on_timer(
+        &mut self,
+        timer: &TimerEvent,
+        market: &MarketEvent<Instrument, DataKind>,
+    ) -> Vec<Signal>;
+}
+
+/// Named [`Schedule`] that fires [`TimerEvent`]s.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Timer {
+    pub name: String,
+    pub schedule: Schedule,
+}
+
+impl Timer {
+    /// Constructs a new [`Timer`] using the configuration provided.
+    pub fn new<S>(name: S, schedule: Schedule) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            schedule,
+        }
+    }
+}
+
+/// Occurrences of a [`Timer`]. Wall-clock times are UTC.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum Schedule {
+    /// Every interval, aligned to the unix epoch (eg/ every 15 minutes fires at :00, :15, etc.).
+    Interval(Duration),
+    /// Every day at a time, on the provided weekdays (every day if empty).
+    Daily {
+        time: NaiveTime,
+        weekdays: Vec<Weekday>,
+    },
+    /// Open of every trading [`Session`].
+    SessionOpen(Session),
+    /// Close of every trading [`Session`].
+    SessionClose(Session),
+}
+
+/// Daily trading session opening on the provided weekdays (every day if empty). A session with
+/// a close time no later than it's open time closes the following day.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Session {
+    pub open: NaiveTime,
+    pub close: NaiveTime,
+    pub weekdays: Vec<Weekday>,
+}
+
+impl Schedule {
+    /// Determines the first occurrence strictly after the provided time, if any.
+    pub fn next_after(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Interval(interval) => {
+                let interval = i64::try_from(interval.as_millis()).ok()?;
+                if interval == 0 {
+                    return None;
+                }
+                let next = (time.timestamp_millis().div_euclid(interval) + 1) * interval;
+                DateTime::from_timestamp_millis(next)
+            }
+            Self::Daily { time: at, weekdays } => next_daily(time, *at, 0, weekdays),
+            Self::SessionOpen(session) => next_daily(time, session.open, 0, &session.weekdays),
+            Self::SessionClose(session) => {
+                let overnight = u64::from(session.close <= session.open);
+                next_daily(time, session.close, overnight, &session.weekdays)
+            }
+        }
+    }
+
+    /// Counts the occurrences from the provided first occurrence up to & including the provided
+    /// time, alongside the first occurrence strictly after the time (if any).
+    fn occurrences_until(
+        &self,
+        first: DateTime<Utc>,
+        time: DateTime<Utc>,
+    ) -> (u64, Option<DateTime<Utc>>) {
+        match self {
+            // Interval occurrences are evenly spaced, so are counted without visiting each one
+            Self::Interval(interval) => {
+                let interval = i64::try_from(interval.as_millis())
+                    .unwrap_or(i64::MAX)
+                    .max(1);
+                let elapsed = (time - first).num_milliseconds();
+                (
+                    elapsed.div_euclid(interval) as u64 + 1,
+                    self.next_after(time),
+                )
+            }
+            _ => {
+                let mut occurrences = 0;
+                let mut next = Some(first);
+                while let Some(occurrence) = next.filter(|occurrence| *occurrence <= time) {
+                    occurrences += 1;
+                    next = self.next_after(occurrence);
+                }
+                (occurrences, next)
+            }
+        }
+    }
+}
+
+/// Determines the first daily occurrence at a time strictly after the provided time, where the
+/// occurrence is offset a number of days after a weekday it applies to.
+fn next_daily(
+    time: DateTime<Utc>,
+    at: NaiveTime,
+    offset: u64,
+    weekdays: &[Weekday],
+) -> Option<DateTime<Utc>> {
+    (0..=7 + offset)
+        .filter_map(|days| time.date_naive().checked_add_days(Days::new(days)))
+        .filter(|date| {
+            date.checked_sub_days(Days::new(offset))
+                .is_some_and(|day| weekdays.is_empty() || weekdays.contains(&day.weekday()))
+        })
+        .map(|date| date.and_time(at).and_utc())
+        .find(|next| *next > time)
+}
+
+/// Occurrence of a [`Timer`], delivered on the first clock time at or after it was scheduled.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct TimerEvent {
+    /// Name of the [`Timer`] that fired.
+    pub name: String,
+    /// Time of the [`Timer`] occurrence. If occurrences were missed (eg/ during a gap in market
+    /// data), this is the earliest one.
+    pub scheduled: DateTime<Utc>,
+    /// Clock time the [`TimerEvent`] was delivered.
+    pub time: DateTime<Utc>,
+    /// Number of occurrences coalesced into this [`TimerEvent`].
+    pub occurrences: u64,
+}
+
+/// Set of [`Timer`]s driven by an externally advanced clock (eg/ market data exchange time).
+///
+/// The first clock time starts every [`Timer`], so only occurrences after it fire.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Timers {
+    clock: Option<DateTime<Utc>>,
+    timers: Vec<(Timer, Option<DateTime<Utc>>)>,
+}
+
+impl Timers {
+    /// Constructs a new [`Timers`] from the provided [`Timer`]s.
+    pub fn new(timers: Vec<Timer>) -> Self {
+        Self {
+            clock: None,
+            timers: timers.into_iter().map(|timer| (timer, None)).collect(),
+        }
+    }
+
+    /// Advances the clock to the provided time, returning a [`TimerEvent`] (in scheduled time
+    /// order) for every [`Timer`] due. The clock never moves backwards, so out of order times
+    /// (eg/ from a merged multi-market stream) are ignored.
+    pub fn advance(&mut self, time: DateTime<Utc>) -> Vec<TimerEvent> {
+        match self.clock {
+            Some(clock) if time <= clock => return vec![],
+            Some(_) => {}
+            None => {
+                self.clock = Some(time);
+                for (timer, next) in self.timers.iter_mut() {
+                    *next = timer.schedule.next_after(time);
+                }
+                return vec![];
+            }
+        }
+        self.clock = Some(time);
+
+        let mut fired = Vec::new();
+        for (timer, next) in self.timers.iter_mut() {
+            let Some(scheduled) = next.filter(|scheduled| *scheduled <= time) else {
+                continue;
+            };
+
+            // Coalesce every occurrence missed since the last clock time into one TimerEvent
+            let (occurrences, next_after) = timer.schedule.occurrences_until(scheduled, time);
+            *next = next_after;
+
+            fired.push(TimerEvent {
+                name: timer.name.clone(),
+                scheduled,
+                time,
+                occurrences,
+            });
+        }
+
+        fired.sort_by_key(|timer| timer.scheduled);
+        fired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn time(day: u32, hour: u32, min: u32) -> DateTime<Utc> {
+        // 2024-01-01 is a Monday
+        Utc.with_ymd_and_hms(2024, 1, day, hour, min, 0).unwrap()
+    }
+
+    fn hm(hour: u32, min: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_schedule_next_after() {
+        let weekday_session = Session {
+            open: hm(9, 30),
+            close: hm(16, 0),
+            weekdays: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+        };
+
+        struct TestCase {
+            schedule: Schedule,
+            time: DateTime<Utc>,
+            expected: Option<DateTime<Utc>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: interval aligned to the unix epoch
+                schedule: Schedule::Interval(Duration::from_secs(15 * 60)),
+                time: time(1, 10, 7),
+                expected: Some(time(1, 10, 15)),
+            },
+            TestCase {
+                // TC1: interval occurrence is strictly after the time
+                schedule: Schedule::Interval(Duration::from_secs(15 * 60)),
+                time: time(1, 10, 15),
+                expected: Some(time(1, 10, 30)),
+            },
+            TestCase {
+                // TC2: zero interval never fires
+                schedule: Schedule::Interval(Duration::ZERO),
+                time: time(1, 10, 15),
+                expected: None,
+            },
+            TestCase {
+                // TC3: daily later the same day
+                schedule: Schedule::Daily {
+                    time: hm(23, 55),
+                    weekdays: vec![],
+                },
+                time: time(1, 10, 0),
+                expected: Some(time(1, 23, 55)),
+            },
+            TestCase {
+                // TC4: daily already passed today, so fires tomorrow
+                schedule: Schedule::Daily {
+                    time: hm(8, 0),
+                    weekdays: vec![],
+                },
+                time: time(1, 10, 0),
+                expected: Some(time(2, 8, 0)),
+            },
+            TestCase {
+                // TC5: session open skips the weekend
+                schedule: Schedule::SessionOpen(weekday_session.clone()),
+                time: time(5, 10, 0),
+                expected: Some(time(8, 9, 30)),
+            },
+            TestCase {
+                // TC6: session close the same day
+                schedule: Schedule::SessionClose(weekday_session),
+                time: time(5, 10, 0),
+                expected: Some(time(5, 16, 0)),
+            },
+            TestCase {
+                // TC7: overnight session opening Sunday closes on Monday
+                schedule: Schedule::SessionClose(Session {
+                    open: hm(22, 0),
+                    close: hm(21, 0),
+                    weekdays: vec![Weekday::Sun],
+                }),
+                time: time(1, 22, 0),
+                expected: Some(time(8, 21, 0)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.schedule.next_after(test.time);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_timers_advance() {
+        let mut timers = Timers::new(vec![
+            Timer::new("hourly", Schedule::Interval(Duration::from_secs(3600))),
+            Timer::new(
+                "end_of_day",
+                Schedule::Daily {
+                    time: hm(23, 0),
+                    weekdays: vec![],
+                },
+            ),
+        ]);
+
+        let event = |name: &str, scheduled, time, occurrences| TimerEvent {
+            name: name.to_string(),
+            scheduled,
+            time,
+            occurrences,
+        };
+
+        struct TestCase {
+            time: DateTime<Utc>,
+            expected: Vec<TimerEvent>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first clock time starts the Timers without firing
+                time: time(1, 10, 0),
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: no Timer due
+                time: time(1, 10, 59),
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: hourly Timer due
+                time: time(1, 11, 0),
+                expected: vec![event("hourly", time(1, 11, 0), time(1, 11, 0), 1)],
+            },
+            TestCase {
+                // TC3: clock moving backwards is ignored
+                time: time(1, 9, 0),
+                expected: vec![],
+            },
+            TestCase {
+                // TC4: missed occurrences are coalesced, in scheduled time order
+                time: time(2, 0, 30),
+                expected: vec![
+                    event("hourly", time(1, 12, 0), time(2, 0, 30), 13),
+                    event("end_of_day", time(1, 23, 0), time(2, 0, 30), 1),
+                ],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = timers.advance(test.time);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_timers_advance_counts_interval_occurrences() {
+        let mut timers = Timers::new(vec![Timer::new(
+            "millis",
+            Schedule::Interval(Duration::from_millis(1)),
+        )]);
+
+        // Every millisecond of a year long gap is coalesced into one TimerEvent
+        timers.advance(time(1, 0, 0));
+        let fired = timers.advance(time(1, 0, 0) + chrono::Duration::days(365));
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].occurrences, 365 * 24 * 60 * 60 * 1000);
+
+        // Next occurrence is the millisecond after the clock time
+        let fired =
+            timers.advance(time(1, 0, 0) + chrono::Duration::days(365) + Duration::from_millis(1));
+        assert_eq!(fired[0].occurrences, 1);
+    }
+}
@@ -1,25 +1,49 @@
 use barter::{
-    data::historical,
-    engine::{trader::Trader, Engine},
-    event::EventTx,
+    data::{historical, MarketMeta},
+    engine::{
+        circuit_breaker::{CircuitBreaker, CircuitBreakerConfig},
+        error::EngineError,
+        trader::Trader,
+        Command, Engine,
+    },
+    event::{Event, EventTx},
     execution::{
+        router::ExecutionRouter,
         simulated::{Config as ExecutionConfig, SimulatedExecution},
         Fees,
     },
     portfolio::{
-        allocator::DefaultAllocator, portfolio::MetaPortfolio,
-        repository::in_memory::InMemoryRepository, risk::DefaultRisk,
+        allocator::DefaultAllocator,
+        portfolio::MetaPortfolio,
+        repository::{
+            determine_strategy_state_id, in_memory::InMemoryRepository, StrategyStateHandler,
+        },
+        risk::DefaultRisk,
+        Balance,
     },
     statistic::summary::{
         trading::{Config as StatisticConfig, TradingSummary},
         Initialiser,
     },
-    strategy::example::{Config as StrategyConfig, RSIStrategy},
+    strategy::{
+        arbitrage::{Config as ArbitrageConfig, CrossExchangeArbitrage},
+        example::{Config as StrategyConfig, RSIStrategy},
+        timer::{Schedule, Timer, TimerEvent, TimerHandler},
+        Decision, Signal, SignalGenerator, SignalStrength, StrategyState,
+    },
     test_util::market_event_trade,
 };
-use barter_integration::model::{instrument::kind::InstrumentKind, Market, Side};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    exchange::ExchangeId,
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Exchange, Market, Side,
+};
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{collections::HashMap, fs, sync::Arc, time::Duration};
 use tokio::sync::mpsc;
 use uuid::Uuid;
 
@@ -116,3 +140,509 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
         "failed because Engine's command_rx.await is blocking the Engine from stopping"
     )
 }
+
+#[test]
+fn trader_with_cross_exchange_arbitrage_backtest_is_profitable() {
+    const TAKER_FEE: f64 = 0.001;
+
+    // Create Event channel to collect every Event produced during the backtest
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+
+    let engine_id = Uuid::new_v4();
+
+    // Arbitrage the same instrument on Binance & Kraken
+    let exchanges = [ExchangeId::BinanceSpot, ExchangeId::Kraken];
+    let markets = exchanges
+        .iter()
+        .map(|exchange| {
+            Market::new(
+                Exchange::from(*exchange),
+                ("btc", "usdt", InstrumentKind::Spot),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(markets.clone())
+            .starting_cash(10_000.0)
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 1000.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let execution = markets
+        .iter()
+        .fold(ExecutionRouter::new(), |router, market| {
+            router.route(
+                &market.exchange,
+                &market.instrument,
+                SimulatedExecution::new(ExecutionConfig {
+                    simulated_fees_pct: Fees {
+                        exchange: TAKER_FEE,
+                        slippage: 0.0,
+                        network: 0.0,
+                    },
+                }),
+            )
+        });
+
+    // Replay hand-written synthetic OrderBookL1 data from both exchanges as a single merged feed
+    let books = fs::read_to_string("examples/data/order_books_l1_synthetic.json")
+        .expect("failed to read synthetic order books");
+    let books = serde_json::from_str::<Vec<MarketEvent<Instrument, DataKind>>>(&books)
+        .expect("failed to parse synthetic order books");
+
+    let (_trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+        .engine_id(engine_id)
+        .market(markets[0].clone())
+        .markets(markets)
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx)
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new(books))
+        .strategy(CrossExchangeArbitrage::new(
+            ArbitrageConfig {
+                taker_fees: exchanges
+                    .into_iter()
+                    .map(|exchange| (exchange, TAKER_FEE))
+                    .collect(),
+                entry_spread: 0.002,
+                exit_spread: -0.003,
+                quantity: 0.03,
+            },
+            engine_id,
+            Arc::clone(&portfolio),
+        ))
+        .execution(execution)
+        .build()
+        .expect("failed to build trader");
+
+    // Run the backtest to completion on the current thread
+    trader.run();
+
+    let mut fills = Vec::new();
+    let mut exits = Vec::new();
+    while let Ok(event) = event_rx.try_recv() {
+        match event {
+            Event::Fill(fill) => fills.push(fill),
+            Event::PositionExit(exit) => exits.push(exit),
+            _ => {}
+        }
+    }
+
+    // Synthetic data contains two arbitrage windows, each entered & exited on both exchanges
+    assert_eq!(fills.len(), 8);
+    assert_eq!(exits.len(), 4);
+
+    // Both legs of every entry & exit trade the same quantity, so the arbitrage is delta neutral
+    for legs in fills.chunks(2) {
+        assert_eq!(legs[0].quantity.abs(), legs[1].quantity.abs());
+        assert_eq!(legs[0].quantity.abs(), 0.03);
+    }
+
+    // Every opposing pair of legs is profitable net of fees
+    for legs in exits.chunks(2) {
+        let profit_loss = legs[0].realised_profit_loss + legs[1].realised_profit_loss;
+        assert!(profit_loss > 0.0, "arbitrage lost {profit_loss}");
+    }
+}
+
+/// Portfolio type shared by the single [`Market`] Trader tests.
+type TestPortfolio = MetaPortfolio<
+    InMemoryRepository<TradingSummary>,
+    DefaultAllocator,
+    DefaultRisk,
+    TradingSummary,
+>;
+
+/// Spot [`Market`] traded by the single [`Market`] Trader tests.
+fn market() -> Market {
+    Market::new(
+        Exchange::from(ExchangeId::BinanceSpot),
+        ("btc", "usdt", InstrumentKind::Spot),
+    )
+}
+
+/// Build a shared [`MetaPortfolio`] with 10,000 starting cash, trading the provided [`Market`].
+fn portfolio(engine_id: Uuid, market: &Market) -> Arc<Mutex<TestPortfolio>> {
+    Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(10_000.0)
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ))
+}
+
+/// [`SimulatedExecution`] that fills without fees or slippage.
+fn zero_fee_execution() -> SimulatedExecution {
+    SimulatedExecution::new(ExecutionConfig {
+        simulated_fees_pct: Fees {
+            exchange: 0.0,
+            slippage: 0.0,
+            network: 0.0,
+        },
+    })
+}
+
+/// Trade [`MarketEvent`] with an exchange time of the provided seconds since the epoch, priced
+/// 1000 plus the seconds so fills can be traced back to their trade.
+fn trade(secs: i64) -> MarketEvent<Instrument, DataKind> {
+    let mut trade = market_event_trade(Side::Buy);
+    trade.exchange_time = DateTime::<Utc>::from_timestamp(secs, 0).unwrap();
+    if let DataKind::Trade(public_trade) = &mut trade.kind {
+        public_trade.price = 1000.0 + secs as f64;
+    }
+    trade
+}
+
+/// [`MarketMeta`] of the provided trade [`MarketEvent`].
+fn market_meta(market: &MarketEvent<Instrument, DataKind>) -> MarketMeta {
+    let DataKind::Trade(trade) = &market.kind else {
+        panic!("expected trade MarketEvent");
+    };
+
+    MarketMeta {
+        close: trade.price,
+        time: market.exchange_time,
+    }
+}
+
+/// Strategy generating a long [`Signal`] from every [`MarketEvent`] it is fed.
+struct LongEveryMarketEvent;
+
+impl SignalGenerator for LongEveryMarketEvent {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        Some(Signal {
+            time: market.exchange_time,
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            signals: HashMap::from([(Decision::Long, SignalStrength(1.0))]),
+            market_meta: market_meta(market),
+            exit_rules: None,
+            target: None,
+            attribution: vec![],
+        })
+    }
+}
+
+#[test]
+fn trader_with_warm_up_suppresses_orders_and_skips_overlapping_market_events() {
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+
+    let engine_id = Uuid::new_v4();
+    let market = market();
+
+    let portfolio = portfolio(engine_id, &market);
+
+    let (_trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    // Warm-up covers t0..=t2, and the live feed re-delivers t1 & t2 before t3 & t4
+    let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+        .engine_id(engine_id)
+        .market(market)
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx)
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new((1..=4).map(trade)))
+        .strategy(LongEveryMarketEvent)
+        .execution(zero_fee_execution())
+        .warm_up((0..=2).map(trade).collect())
+        .build()
+        .expect("failed to build trader");
+
+    trader.run();
+
+    let mut markets = 0;
+    let mut signal_times = Vec::new();
+    let mut orders = 0;
+    while let Ok(event) = event_rx.try_recv() {
+        match event {
+            Event::Market(_) => markets += 1,
+            Event::Signal(signal) => signal_times.push(signal.time.timestamp()),
+            Event::OrderNew(_) => orders += 1,
+            _ => {}
+        }
+    }
+
+    // Every live MarketEvent is processed, but only those newer than the warm-up reach the
+    // Strategy, and only the first live Signal enters a Position
+    assert_eq!(markets, 4);
+    assert_eq!(signal_times, vec![3, 4]);
+    assert_eq!(orders, 1);
+}
+
+#[test]
+fn trader_with_tripped_circuit_breaker_resumes_entries_after_reset_command() {
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+
+    let engine_id = Uuid::new_v4();
+    let market = market();
+
+    let portfolio = portfolio(engine_id, &market);
+
+    // Trip the CircuitBreaker with a 10% intraday loss before the Trader runs
+    let circuit_breaker = Arc::new(Mutex::new(CircuitBreaker::new(
+        CircuitBreakerConfig {
+            max_intraday_loss: Some(0.05),
+            ..Default::default()
+        },
+        10_000.0,
+        trade(0).exchange_time,
+    )));
+    let tripped = circuit_breaker.lock().update(&Event::Balance(Balance::new(
+        trade(0).exchange_time,
+        9_000.0,
+        9_000.0,
+    )));
+    assert!(tripped.is_some());
+
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+    trader_command_tx
+        .try_send(Command::ResetCircuitBreaker)
+        .expect("failed to send Command::ResetCircuitBreaker");
+
+    let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+        .engine_id(engine_id)
+        .market(market)
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx)
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new((1..=2).map(trade)))
+        .strategy(LongEveryMarketEvent)
+        .execution(zero_fee_execution())
+        .circuit_breaker(Arc::clone(&circuit_breaker))
+        .build()
+        .expect("failed to build trader");
+
+    trader.run();
+
+    let orders = std::iter::from_fn(|| event_rx.try_recv().ok())
+        .filter(|event| matches!(event, Event::OrderNew(_)))
+        .count();
+
+    // Command::ResetCircuitBreaker is actioned before the first MarketEvent, so the first
+    // Signal enters a Position
+    assert!(!circuit_breaker.lock().is_tripped());
+    assert_eq!(orders, 1);
+}
+
+#[test]
+fn trader_with_market_not_routed_by_execution_fails_to_build() {
+    let (event_tx, _event_rx) = mpsc::unbounded_channel();
+
+    let engine_id = Uuid::new_v4();
+    let market = market();
+    let unrouted = Market::new(
+        Exchange::from(ExchangeId::Kraken),
+        ("btc", "usdt", InstrumentKind::Spot),
+    );
+
+    let (_trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    let trader: Result<Trader<_, TradingSummary, _, _, _, _>, _> = Trader::builder()
+        .engine_id(engine_id)
+        .market(market.clone())
+        .markets(vec![unrouted])
+        .command_rx(trader_command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .portfolio(portfolio(engine_id, &market))
+        .data(historical::MarketFeed::new((1..=2).map(trade)))
+        .strategy(LongEveryMarketEvent)
+        .execution(ExecutionRouter::new().route(
+            &market.exchange,
+            &market.instrument,
+            zero_fee_execution(),
+        ))
+        .build();
+
+    assert!(matches!(trader, Err(EngineError::MarketNotRouted(_))));
+}
+
+/// Strategy counting every [`MarketEvent`] it is fed, without generating [`Signal`]s.
+#[derive(Default)]
+struct CountMarketEvents {
+    count: u64,
+}
+
+impl SignalGenerator for CountMarketEvents {
+    fn generate_signal(&mut self, _: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        self.count += 1;
+        None
+    }
+}
+
+impl StrategyState for CountMarketEvents {
+    type State = u64;
+
+    fn snapshot(&self) -> Self::State {
+        self.count
+    }
+
+    fn restore(&mut self, state: Self::State) {
+        self.count = state;
+    }
+}
+
+#[test]
+fn trader_with_strategy_state_restores_snapshot_persisted_by_previous_run() {
+    let engine_id = Uuid::new_v4();
+    let market = market();
+
+    // Portfolio repository outlives each Trader run, as a RedisRepository would a restart
+    let portfolio = portfolio(engine_id, &market);
+
+    let run_trader = |events: usize| {
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+        let (_trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+        let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+            .engine_id(engine_id)
+            .market(market.clone())
+            .command_rx(trader_command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(Arc::clone(&portfolio))
+            .data(historical::MarketFeed::new(
+                (0..events).map(|_| market_event_trade(Side::Buy)),
+            ))
+            .strategy(CountMarketEvents::default())
+            .execution(zero_fee_execution())
+            .warm_up(vec![market_event_trade(Side::Buy)])
+            .strategy_state(Duration::from_secs(3600))
+            .build()
+            .expect("failed to build trader");
+
+        trader.run();
+    };
+
+    let strategy_state_id = determine_strategy_state_id(engine_id, &market);
+    let persisted_count = || {
+        portfolio
+            .lock()
+            .get_strategy_state(&strategy_state_id)
+            .unwrap()
+            .map(|state| serde_json::from_value::<u64>(state).unwrap())
+    };
+
+    // First run has no snapshot to restore, so warms up (1) & trades (3) before persisting
+    run_trader(3);
+    assert_eq!(persisted_count(), Some(4));
+
+    // Second run restores the snapshot instead of warming up, then trades (2) more
+    run_trader(2);
+    assert_eq!(persisted_count(), Some(6));
+}
+
+/// Strategy generating a long [`Signal`] from every [`MarketEvent`] it is fed, and closing any
+/// long Position whenever a [`Timer`] fires.
+struct LongUntilTimer {
+    market: Market,
+}
+
+impl SignalGenerator for LongUntilTimer {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        LongEveryMarketEvent.generate_signal(market)
+    }
+}
+
+impl TimerHandler for LongUntilTimer {
+    fn on_timer(
+        &mut self,
+        timer: &TimerEvent,
+        market: &MarketEvent<Instrument, DataKind>,
+    ) -> Vec<Signal> {
+        vec![Signal {
+            time: timer.time,
+            exchange: self.market.exchange.clone(),
+            instrument: self.market.instrument.clone(),
+            signals: HashMap::from([(Decision::CloseLong, SignalStrength(1.0))]),
+            market_meta: market_meta(market),
+            exit_rules: None,
+            target: None,
+            attribution: vec![],
+        }]
+    }
+}
+
+#[test]
+fn trader_with_timers_fires_strategy_on_market_event_clock() {
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+
+    let engine_id = Uuid::new_v4();
+    let market = market();
+
+    let portfolio = portfolio(engine_id, &market);
+
+    let (_trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    // Minute Timer fires at t70 (scheduled t60), then once at t200 for missed t120 & t180
+    let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+        .engine_id(engine_id)
+        .market(market.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx)
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new(
+            [30, 50, 70, 200, 210].into_iter().map(trade),
+        ))
+        .strategy(LongUntilTimer { market })
+        .execution(zero_fee_execution())
+        .timers(vec![Timer::new(
+            "minutely",
+            Schedule::Interval(Duration::from_secs(60)),
+        )])
+        .build()
+        .expect("failed to build trader");
+
+    trader.run();
+
+    let mut timers = Vec::new();
+    let mut orders = 0;
+    let mut exits = Vec::new();
+    while let Ok(event) = event_rx.try_recv() {
+        match event {
+            Event::Timer(timer) => timers.push((
+                timer.scheduled.timestamp(),
+                timer.time.timestamp(),
+                timer.occurrences,
+            )),
+            Event::OrderNew(_) => orders += 1,
+            Event::PositionExit(exit) => exits.push(exit.exit_avg_price_gross),
+            _ => {}
+        }
+    }
+
+    // Position entered at t30 is exited by the t70 Timer at the t70 trade price, then re-entered
+    // at t200 since the Timer fires before the MarketEvent is analysed
+    assert_eq!(timers, vec![(60, 70, 1), (120, 200, 2)]);
+    assert_eq!(orders, 3);
+    assert_eq!(exits, vec![1070.0]);
+}